@@ -101,9 +101,9 @@ impl fmt::Display for BinaryOperation {
             Self::Mod => "mod",
             Self::Mul => "*",
             Self::MulWrapped => "mul_wrapped",
-            Self::Nand => "NAND",
+            Self::Nand => "nand",
             Self::Neq => "!=",
-            Self::Nor => "NOR",
+            Self::Nor => "nor",
             Self::Or => "||",
             Self::BitwiseOr => "|",
             Self::Pow => "**",
@@ -122,6 +122,44 @@ impl fmt::Display for BinaryOperation {
 }
 
 impl BinaryOperation {
+    /// Returns `true` if the operator can only be written as a method call, e.g. `a.add_wrapped(b)`.
+    pub fn is_method_only(self) -> bool {
+        matches!(
+            self,
+            Self::AddWrapped
+                | Self::DivWrapped
+                | Self::Mod
+                | Self::MulWrapped
+                | Self::Nand
+                | Self::Nor
+                | Self::PowWrapped
+                | Self::RemWrapped
+                | Self::ShlWrapped
+                | Self::ShrWrapped
+                | Self::SubWrapped
+        )
+    }
+
+    /// Returns `true` if the order of the operands does not change the result, e.g. `a + b` and `b + a`.
+    pub fn is_commutative(self) -> bool {
+        matches!(
+            self,
+            Self::Add
+                | Self::AddWrapped
+                | Self::And
+                | Self::BitwiseAnd
+                | Self::BitwiseOr
+                | Self::Eq
+                | Self::Mul
+                | Self::MulWrapped
+                | Self::Nand
+                | Self::Neq
+                | Self::Nor
+                | Self::Or
+                | Self::Xor
+        )
+    }
+
     /// Returns a `BinaryOperation` from the given `Symbol`.
     /// This is used to resolve native operators invoked as method calls, e.g. `a.add_wrapped(b)`.
     pub fn from_symbol(symbol: Symbol) -> Option<Self> {
@@ -177,7 +215,14 @@ pub struct BinaryExpression {
 
 impl fmt::Display for BinaryExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} {} {}", self.left, self.op, self.right)
+        fmt_operand(&self.left, f)?;
+        match self.op.is_method_only() {
+            true => write!(f, ".{}({})", self.op, self.right),
+            false => {
+                write!(f, " {} ", self.op)?;
+                fmt_operand(&self.right, f)
+            }
+        }
     }
 }
 
@@ -17,6 +17,7 @@
 use crate::{GroupLiteral, IntegerType};
 
 use super::*;
+use std::borrow::Cow;
 
 /// A literal.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -46,10 +47,10 @@ impl fmt::Display for Literal {
         match &self {
             Self::Address(address, _, _) => write!(f, "{address}"),
             Self::Boolean(boolean, _, _) => write!(f, "{boolean}"),
-            Self::Field(field, _, _) => write!(f, "{field}field"),
+            Self::Field(field, _, _) => write!(f, "{}field", Self::decimal_digits(field)),
             Self::Group(group) => write!(f, "{group}group"),
-            Self::Integer(type_, value, _, _) => write!(f, "{value}{type_}"),
-            Self::Scalar(scalar, _, _) => write!(f, "{scalar}scalar"),
+            Self::Integer(type_, value, _, _) => write!(f, "{}{type_}", Self::decimal_digits(value)),
+            Self::Scalar(scalar, _, _) => write!(f, "{}scalar", Self::decimal_digits(scalar)),
             Self::String(string, _, _) => write!(f, "\"{string}\""),
         }
     }
@@ -104,3 +105,53 @@ impl Node for Literal {
         }
     }
 }
+
+impl Literal {
+    /// Returns the digits of a numeric literal in decimal, without `_` separators.
+    /// The digits may be negated, and hexadecimal, octal, or binary with a `0x`, `0o`, or `0b` prefix.
+    /// Returns `None` if a digit is not a digit of the radix.
+    pub fn to_decimal(digits: &str) -> Option<String> {
+        let (sign, digits) = match digits.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", digits),
+        };
+        let digits = digits.replace('_', "");
+        let (radix, digits) = match digits.get(..2) {
+            Some("0x") => (16, &digits[2..]),
+            Some("0o") => (8, &digits[2..]),
+            Some("0b") => (2, &digits[2..]),
+            _ => return Some(format!("{sign}{digits}")),
+        };
+        if digits.is_empty() {
+            return None;
+        }
+
+        // The value in base 10^9, with the least significant limb first.
+        const BASE: u64 = 1_000_000_000;
+        let mut limbs = vec![0u64];
+        for c in digits.chars() {
+            let mut carry = c.to_digit(radix)? as u64;
+            for limb in limbs.iter_mut() {
+                let value = *limb * radix as u64 + carry;
+                *limb = value % BASE;
+                carry = value / BASE;
+            }
+            if carry > 0 {
+                limbs.push(carry);
+            }
+        }
+
+        let mut decimal = format!("{sign}{}", limbs.pop().unwrap_or_default());
+        limbs.iter().rev().for_each(|limb| decimal.push_str(&format!("{limb:09}")));
+        Some(decimal)
+    }
+
+    /// Returns the digits of a numeric literal as they are written in Aleo instructions:
+    /// decimal digits are kept as they are written, and other digits are converted to decimal.
+    pub fn decimal_digits(digits: &str) -> Cow<'_, str> {
+        match digits.trim_start_matches('-').get(..2) {
+            Some("0x" | "0o" | "0b") => Self::to_decimal(digits).map_or(Cow::Borrowed(digits), Cow::Owned),
+            _ => Cow::Borrowed(digits),
+        }
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Identifier, Node, NodeID};
+use crate::{GroupLiteral, Identifier, Node, NodeID};
 use leo_span::Span;
 
 use serde::{Deserialize, Serialize};
@@ -166,6 +166,40 @@ impl Node for Expression {
     }
 }
 
+impl Expression {
+    /// Returns `self` formatted as the operand of a unary or binary operator, with parentheses where needed.
+    pub fn as_operand(&self) -> impl fmt::Display + '_ {
+        struct Operand<'a>(&'a Expression);
+
+        impl fmt::Display for Operand<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt_operand(self.0, f)
+            }
+        }
+
+        Operand(self)
+    }
+}
+
+/// Writes `expression` as the operand of a unary or binary operator, adding parentheses where needed.
+fn fmt_operand(expression: &Expression, f: &mut fmt::Formatter) -> fmt::Result {
+    let needs_parens = match expression {
+        Expression::Binary(_) => true,
+        Expression::Unary(unary) => matches!(unary.op, UnaryOperation::Negate | UnaryOperation::Not),
+        Expression::Literal(Literal::Integer(_, value, ..))
+        | Expression::Literal(Literal::Field(value, ..))
+        | Expression::Literal(Literal::Scalar(value, ..)) => value.starts_with('-'),
+        Expression::Literal(Literal::Group(group)) => {
+            matches!(&**group, GroupLiteral::Single(value, ..) if value.starts_with('-'))
+        }
+        _ => false,
+    };
+    match needs_parens {
+        true => write!(f, "({expression})"),
+        false => write!(f, "{expression}"),
+    }
+}
+
 impl fmt::Display for Expression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use Expression::*;
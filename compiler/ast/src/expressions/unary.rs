@@ -60,7 +60,7 @@ impl UnaryOperation {
         })
     }
 
-    /// Represents the opera.tor as a string.
+    /// Represents the operator as a string.
     fn as_str(self) -> &'static str {
         match self {
             Self::Abs => "abs",
@@ -92,7 +92,20 @@ pub struct UnaryExpression {
 
 impl fmt::Display for UnaryExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}{}", self.op.as_str(), self.receiver)
+        match self.op {
+            UnaryOperation::Negate => {
+                write!(f, "-")?;
+                fmt_operand(&self.receiver, f)
+            }
+            UnaryOperation::Not => {
+                write!(f, "!")?;
+                fmt_operand(&self.receiver, f)
+            }
+            _ => {
+                fmt_operand(&self.receiver, f)?;
+                write!(f, ".{}()", self.op.as_str())
+            }
+        }
     }
 }
 
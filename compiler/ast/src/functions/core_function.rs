@@ -291,6 +291,77 @@ pub enum CoreFunction {
 }
 
 impl CoreFunction {
+    /// The names of the functions defined by the core library modules.
+    const FUNCTIONS: &'static [Symbol] = &[
+        sym::commit_to_address,
+        sym::commit_to_field,
+        sym::commit_to_group,
+        sym::hash_to_address,
+        sym::hash_to_field,
+        sym::hash_to_group,
+        sym::hash_to_i8,
+        sym::hash_to_i16,
+        sym::hash_to_i32,
+        sym::hash_to_i64,
+        sym::hash_to_i128,
+        sym::hash_to_u8,
+        sym::hash_to_u16,
+        sym::hash_to_u32,
+        sym::hash_to_u64,
+        sym::hash_to_u128,
+        sym::hash_to_scalar,
+        sym::rand_address,
+        sym::rand_bool,
+        sym::rand_field,
+        sym::rand_group,
+        sym::rand_i8,
+        sym::rand_i16,
+        sym::rand_i32,
+        sym::rand_i64,
+        sym::rand_i128,
+        sym::rand_scalar,
+        sym::rand_u8,
+        sym::rand_u16,
+        sym::rand_u32,
+        sym::rand_u64,
+        sym::rand_u128,
+        sym::get,
+        sym::get_or_use,
+        sym::set,
+        sym::remove,
+        sym::contains,
+        sym::to_x_coordinate,
+        sym::to_y_coordinate,
+        sym::verify,
+    ];
+    /// The core library modules that define functions, e.g., `BHP256` in `BHP256::hash_to_field`.
+    pub const MODULES: &'static [Symbol] = &[
+        sym::BHP256,
+        sym::BHP512,
+        sym::BHP768,
+        sym::BHP1024,
+        sym::ChaCha,
+        sym::Keccak256,
+        sym::Keccak384,
+        sym::Keccak512,
+        sym::Pedersen64,
+        sym::Pedersen128,
+        sym::Poseidon2,
+        sym::Poseidon4,
+        sym::Poseidon8,
+        sym::SHA3_256,
+        sym::SHA3_384,
+        sym::SHA3_512,
+        sym::Mapping,
+        sym::group,
+        sym::signature,
+    ];
+
+    /// Returns the names of the functions defined by the core library module `module`.
+    pub fn functions_of(module: Symbol) -> impl Iterator<Item = Symbol> {
+        Self::FUNCTIONS.iter().copied().filter(move |function| Self::from_symbols(module, *function).is_some())
+    }
+
     /// Returns a `CoreFunction` from the given module and method symbols.
     pub fn from_symbols(module: Symbol, function: Symbol) -> Option<Self> {
         Some(match (module, function) {
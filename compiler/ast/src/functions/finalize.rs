@@ -62,13 +62,13 @@ impl Finalize {
 
 impl fmt::Display for Finalize {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
+        let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
         let returns = match self.output.len() {
-            0 => "()".to_string(),
-            1 => self.output[0].to_string(),
-            _ => format!("({})", self.output.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",")),
+            0 => String::new(),
+            1 => format!(" -> {}", self.output[0]),
+            _ => format!(" -> ({})", self.output.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ")),
         };
-        write!(f, " finalize {}({parameters}) -> {returns} {}", self.identifier, self.block)
+        write!(f, "finalize {}({parameters}){returns} {}", self.identifier, self.block)
     }
 }
 
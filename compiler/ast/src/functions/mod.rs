@@ -63,6 +63,9 @@ pub struct Function {
     pub block: Block,
     /// An optional finalize block
     pub finalize: Option<Finalize>,
+    /// The doc comment of the function, from the `///` comments before it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
     /// The entire span of the function definition.
     pub span: Span,
     /// The ID of the node.
@@ -103,7 +106,7 @@ impl Function {
             _ => Type::Tuple(TupleType::new(output.iter().map(get_output_type).collect())),
         };
 
-        Function { annotations, variant, identifier, input, output, output_type, block, finalize, span, id }
+        Function { annotations, variant, identifier, input, output, output_type, block, finalize, doc: None, span, id }
     }
 
     /// Returns function name.
@@ -127,19 +130,17 @@ impl Function {
         }
         write!(f, "{}", self.identifier)?;
 
-        let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
+        let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
         let returns = match self.output.len() {
-            0 => "()".to_string(),
-            1 => self.output[0].to_string(),
-            _ => self.output.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(","),
+            0 => String::new(),
+            1 => format!(" -> {}", self.output[0]),
+            _ => format!(" -> ({})", self.output.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ")),
         };
-        write!(f, "({parameters}) -> {returns} {}", self.block)?;
+        write!(f, "({parameters}){returns} {}", self.block)?;
 
-        if let Some(finalize) = &self.finalize {
-            let parameters = finalize.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
-            write!(f, " finalize ({parameters}) {}", finalize.block)
-        } else {
-            Ok(())
+        match &self.finalize {
+            Some(finalize) => write!(f, "\n    {finalize}"),
+            None => Ok(()),
         }
     }
 }
@@ -14,10 +14,22 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{normalize_json_value, remove_key_from_json, Expression, Struct, Type};
+use crate::{
+    normalize_json_value,
+    remove_key_from_json,
+    Expression,
+    Input,
+    Literal,
+    Member,
+    Mode,
+    Node,
+    Struct,
+    Type,
+    UnaryOperation,
+};
 
 use super::*;
-use leo_errors::{AstError, Result};
+use leo_errors::{emitter::Handler, AstError, InputError, Result};
 
 /// Input data which includes [`ProgramInput`].
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -40,35 +52,61 @@ pub struct InputAst {
 }
 
 impl InputAst {
-    /// Returns all values of the input AST for execution with `leo run`.
-    pub fn program_inputs(&self, program_name: &str, structs: IndexMap<Symbol, Struct>) -> Vec<String> {
-        self.sections
+    /// Returns the values of an input case of `function` for execution with `leo run`,
+    /// after checking them against the modes and types of the function's inputs.
+    /// The case is the one named `case`, or else the section without a case, or else the first case.
+    /// Every definition that does not match its input is reported to the `handler`.
+    pub fn program_inputs(
+        &self,
+        handler: &Handler,
+        function: &str,
+        case: Option<&str>,
+        signature: &[Input],
+        structs: &IndexMap<Symbol, Struct>,
+    ) -> Result<Vec<String>> {
+        let sections = self
+            .sections
             .iter()
-            .filter(|section| section.name() == program_name)
-            .flat_map(|section| {
-                section.definitions.iter().map(|definition| match &definition.type_ {
-                    // Handle case where the input may be record.
-                    Type::Identifier(identifier) => {
-                        match structs.get(&identifier.name) {
-                            // TODO: Better error handling.
-                            None => panic!(
-                                "Input error: A struct or record declaration does not exist for {}.",
-                                identifier.name
-                            ),
-                            Some(struct_) => match struct_.is_record {
-                                false => definition.value.to_string(),
-                                true => match &definition.value {
-                                    // Print out the record interface with visibility.
-                                    Expression::Struct(struct_expression) => struct_expression.to_record_string(),
-                                    _ => panic!("Input error: Expected a struct expression."),
-                                },
-                            },
-                        }
-                    }
-                    _ => definition.value.to_string(),
-                })
+            .filter(|section| section.name() == function)
+            .map(|section| (section.case.map(|case| case.to_string()), section))
+            .collect();
+        let Some(section) = select_case(function, case, sections)? else {
+            return Ok(Vec::new());
+        };
+
+        if section.definitions.len() != signature.len() {
+            return Err(InputError::unexpected_number_of_inputs(
+                function,
+                signature.len(),
+                section.definitions.len(),
+                section.span,
+            )
+            .into());
+        }
+
+        let values = section
+            .definitions
+            .iter()
+            .zip(signature)
+            .filter_map(|(definition, input)| {
+                let expected = input.type_();
+                if visibility(definition.mode) != visibility(input.mode()) {
+                    handler.emit_err(InputError::unexpected_mode(
+                        definition.name,
+                        visibility(input.mode()),
+                        visibility(definition.mode),
+                        definition.name.span,
+                    ));
+                }
+                if !definition.type_.eq_flat(&expected) {
+                    handler.emit_err(InputError::unexpected_type(&expected, &definition.type_, definition.span));
+                    return None;
+                }
+                handler.extend_if_error(encode_expression(&expected, &definition.value, structs, None)).ok()
             })
-            .collect::<Vec<_>>()
+            .collect();
+        handler.last_err().map_err(|err| *err)?;
+        Ok(values)
     }
 
     /// Serializes the `Input` into a JSON Value.
@@ -106,3 +144,200 @@ impl InputAst {
             .map_err(|e| AstError::failed_to_write_ast_to_json_file(&path, &e))?)
     }
 }
+
+/// Selects the section of the inputs to `function` for `case`, from the sections of `function` and their cases.
+/// The section is the one named `case`, or else the section without a case, or else the first case.
+pub(crate) fn select_case<T>(
+    function: &str,
+    case: Option<&str>,
+    sections: Vec<(Option<String>, T)>,
+) -> Result<Option<T>> {
+    let index = match case {
+        Some(case) => match sections.iter().position(|(name, _)| name.as_deref() == Some(case)) {
+            Some(index) => index,
+            None => {
+                let cases = sections.iter().filter_map(|(name, _)| name.as_deref()).collect::<Vec<_>>();
+                return Err(InputError::unknown_input_case(function, case, cases.join(", ")).into());
+            }
+        },
+        None => sections.iter().position(|(name, _)| name.is_none()).unwrap_or_default(),
+    };
+    Ok(sections.into_iter().nth(index).map(|(_, section)| section))
+}
+
+/// Formats the members of a struct as a snarkVM value, where `members` holds the value of each member in order.
+/// The members of a record are followed by the record's nonce, which defaults to `0group.public`.
+pub(crate) fn struct_value(struct_: &Struct, members: Vec<String>, nonce: Option<String>) -> String {
+    let mut members = struct_
+        .members
+        .iter()
+        .zip(members)
+        .map(|(member, value)| format!("{}: {value}", member.identifier))
+        .collect::<Vec<_>>();
+    if struct_.is_record {
+        members.push(format!("_nonce: {}", nonce.unwrap_or_else(|| "0group.public".to_string())));
+    }
+    format!("{{ {} }}", members.join(", "))
+}
+
+/// Returns the visibility of the values within `member` of `struct_`, where `mode` is that of the struct's values.
+/// Each member of a record has its own visibility, which snarkVM expects on every literal within the member,
+/// e.g., `{ owner: aleo1....private, amounts: [1u64.private, 2u64.private], _nonce: 0group.public }`.
+pub(crate) fn member_visibility(struct_: &Struct, member: &Member, mode: Option<Mode>) -> Option<Mode> {
+    match (struct_.is_record, member.mode) {
+        (false, _) => mode,
+        (true, Mode::Public) => Some(Mode::Public),
+        (true, _) => Some(Mode::Private),
+    }
+}
+
+/// Appends the visibility `mode` to a literal, if it is within a record.
+pub(crate) fn with_visibility(literal: String, mode: Option<Mode>) -> String {
+    match mode {
+        Some(mode) => format!("{literal}.{mode}"),
+        None => literal,
+    }
+}
+
+/// Checks that the value of an input has the `expected` type, and formats it as a snarkVM value.
+/// The literals within the value are given the visibility `mode`, if the value is within a record.
+fn encode_expression(
+    expected: &Type,
+    value: &Expression,
+    structs: &IndexMap<Symbol, Struct>,
+    mode: Option<Mode>,
+) -> Result<String> {
+    match (expected, value) {
+        (_, Expression::Unary(unary)) if unary.op == UnaryOperation::Negate => {
+            encode_expression(expected, &unary.receiver, structs, None)?;
+            Ok(with_visibility(value.to_string(), mode))
+        }
+        (_, Expression::Literal(literal)) => {
+            let received = literal_type(literal);
+            match received.eq_flat(expected) {
+                true => Ok(with_visibility(value.to_string(), mode)),
+                false => Err(InputError::unexpected_type(expected, received, literal.span()).into()),
+            }
+        }
+        (Type::Array(array), Expression::Array(elements)) => {
+            if elements.elements.len() != array.length() {
+                return Err(InputError::unexpected_type(expected, value, elements.span).into());
+            }
+            let elements = elements
+                .elements
+                .iter()
+                .map(|element| encode_expression(array.element_type(), element, structs, mode))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(format!("[{}]", elements.join(", ")))
+        }
+        (Type::Identifier(identifier), Expression::Struct(struct_expression)) => {
+            if identifier.name != struct_expression.name.name {
+                return Err(
+                    InputError::unexpected_type(expected, struct_expression.name, struct_expression.span).into()
+                );
+            }
+            let struct_ = structs
+                .get(&identifier.name)
+                .ok_or_else(|| InputError::undefined_struct(identifier, struct_expression.name.span))?;
+
+            // Every initializer must be a member, or the nonce of a record.
+            let mut nonce = None;
+            for initializer in struct_expression.members.iter() {
+                let name = initializer.identifier.name;
+                if struct_.is_record && name == sym::_nonce {
+                    let value = initializer
+                        .expression
+                        .as_ref()
+                        .ok_or_else(|| InputError::illegal_expression(initializer.identifier, initializer.span))?;
+                    nonce = Some(encode_expression(&Type::Group, value, structs, Some(Mode::Public))?);
+                } else if !struct_.members.iter().any(|member| member.name() == name) {
+                    return Err(InputError::unknown_struct_member(identifier, name, initializer.span).into());
+                }
+            }
+
+            let members = struct_
+                .members
+                .iter()
+                .map(|member| {
+                    let initializer = struct_expression
+                        .members
+                        .iter()
+                        .find(|initializer| initializer.identifier.name == member.name())
+                        .ok_or_else(|| {
+                            InputError::missing_struct_member(identifier, member.name(), struct_expression.span)
+                        })?;
+                    match &initializer.expression {
+                        Some(value) => {
+                            encode_expression(&member.type_, value, structs, member_visibility(struct_, member, mode))
+                        }
+                        None => Err(InputError::illegal_expression(initializer.identifier, initializer.span).into()),
+                    }
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(struct_value(struct_, members, nonce))
+        }
+        (_, value) => match value {
+            Expression::Array(_) | Expression::Struct(_) => {
+                Err(InputError::unexpected_type(expected, value, value.span()).into())
+            }
+            _ => Err(InputError::illegal_expression(value, value.span()).into()),
+        },
+    }
+}
+
+/// Returns the visibility of an input with the given mode, where inputs without a mode are private.
+fn visibility(mode: Mode) -> Mode {
+    match mode {
+        Mode::None => Mode::Private,
+        mode => mode,
+    }
+}
+
+/// Returns the type of a literal.
+fn literal_type(literal: &Literal) -> Type {
+    match literal {
+        Literal::Address(..) => Type::Address,
+        Literal::Boolean(..) => Type::Boolean,
+        Literal::Field(..) => Type::Field,
+        Literal::Group(..) => Type::Group,
+        Literal::Integer(type_, ..) => Type::Integer(*type_),
+        Literal::Scalar(..) => Type::Scalar,
+        Literal::String(..) => Type::String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Returns the sections of `main` with the given cases.
+    fn sections(cases: &[Option<&str>]) -> Vec<(Option<String>, usize)> {
+        cases.iter().enumerate().map(|(index, case)| (case.map(|case| case.to_string()), index)).collect()
+    }
+
+    #[test]
+    fn cases_are_selected_by_name() {
+        create_session_if_not_set_then(|_| {
+            let cases = [Some("small"), None, Some("large")];
+            assert_eq!(select_case("main", Some("large"), sections(&cases)).unwrap(), Some(2));
+            assert_eq!(select_case("main", Some("small"), sections(&cases)).unwrap(), Some(0));
+
+            let error = select_case("main", Some("huge"), sections(&cases)).unwrap_err().to_string();
+            assert!(error.contains("There is no input case `huge` for `main`."), "{error}");
+            assert!(error.contains("small, large"), "{error}");
+        })
+    }
+
+    #[test]
+    fn the_section_without_a_case_is_the_default() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(select_case("main", None, sections(&[Some("small"), None, Some("large")])).unwrap(), Some(1));
+
+            // Without such a section, the first case is run, and without any section, no inputs are given.
+            assert_eq!(select_case("main", None, sections(&[Some("small"), Some("large")])).unwrap(), Some(0));
+            assert_eq!(select_case("main", None, sections(&[])).unwrap(), None);
+        })
+    }
+}
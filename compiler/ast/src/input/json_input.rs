@@ -0,0 +1,317 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Input, IntegerType, Mode, Struct, Type};
+
+use super::*;
+use serde_json::Value;
+
+/// An input file in JSON, which maps the name of each section, such as `main` or `main.large`,
+/// to the values of the inputs by name:
+///
+/// `{ "main": { "a": 1, "b": 2 }, "main.large": { "a": "4294967295u32", "b": 0 } }`
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct JsonInput {
+    pub sections: IndexMap<String, IndexMap<String, Value>>,
+}
+
+impl JsonInput {
+    /// Parses a JSON input file.
+    pub fn parse(source: &str) -> Result<Self> {
+        Ok(serde_json::from_str(source)
+            .map(|sections| Self { sections })
+            .map_err(InputError::failed_to_parse_json_input)?)
+    }
+
+    /// Returns the values of an input case of `function` for execution with `leo run`,
+    /// converted to the types of the function's inputs.
+    /// The case is the one named `case`, or else the section without a case, or else the first case.
+    pub fn program_inputs(
+        &self,
+        function: &str,
+        case: Option<&str>,
+        signature: &[Input],
+        structs: &IndexMap<Symbol, Struct>,
+    ) -> Result<Vec<String>> {
+        let sections = self
+            .sections
+            .iter()
+            .filter_map(|(name, values)| match name.split_once('.') {
+                Some((name, case)) => (name == function).then(|| (Some(case.to_string()), values)),
+                None => (name == function).then_some((None, values)),
+            })
+            .collect();
+        let Some(values) = select_case(function, case, sections)? else {
+            return Ok(Vec::new());
+        };
+
+        if let Some(name) =
+            values.keys().find(|name| !signature.iter().any(|input| input.identifier().name.to_string() == **name))
+        {
+            return Err(InputError::unknown_json_input(function, name).into());
+        }
+
+        signature
+            .iter()
+            .map(|input| {
+                let name = input.identifier().name.to_string();
+                let value = values.get(&name).ok_or_else(|| InputError::missing_json_input(function, &name))?;
+                encode_json(&name, &input.type_(), value, structs, None)
+            })
+            .collect()
+    }
+}
+
+/// Converts the JSON value of the input `name` to a snarkVM value of the `expected` type.
+/// The literals within the value are given the visibility `mode`, if the value is within a record.
+fn encode_json(
+    name: &str,
+    expected: &Type,
+    value: &Value,
+    structs: &IndexMap<Symbol, Struct>,
+    mode: Option<Mode>,
+) -> Result<String> {
+    let unexpected = || InputError::unexpected_json_value(name, expected, value);
+    match (expected, value) {
+        (Type::Address, Value::String(address)) => Ok(with_visibility(address.clone(), mode)),
+        (Type::Boolean, Value::Bool(boolean)) => Ok(with_visibility(boolean.to_string(), mode)),
+        (Type::Integer(type_), Value::Number(_) | Value::String(_)) => {
+            let number = number(value, &type_.to_string());
+            match integer_in_range(*type_, &number) {
+                true => Ok(with_visibility(format!("{number}{type_}"), mode)),
+                false => Err(unexpected().into()),
+            }
+        }
+        (Type::Field | Type::Scalar, Value::Number(_) | Value::String(_)) => {
+            let number = number(value, &expected.to_string());
+            let digits = number.strip_prefix('-').unwrap_or(&number);
+            match !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+                true => Ok(with_visibility(format!("{number}{expected}"), mode)),
+                false => Err(unexpected().into()),
+            }
+        }
+        (Type::Group, Value::Number(_) | Value::String(_)) => {
+            let group = number(value, "group");
+            match group.is_empty() {
+                true => Err(unexpected().into()),
+                false => Ok(with_visibility(format!("{group}group"), mode)),
+            }
+        }
+        (Type::Array(array), Value::Array(elements)) if elements.len() == array.length() => {
+            let elements = elements
+                .iter()
+                .enumerate()
+                .map(|(index, element)| {
+                    encode_json(&format!("{name}[{index}]"), array.element_type(), element, structs, mode)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(format!("[{}]", elements.join(", ")))
+        }
+        (Type::Identifier(identifier), Value::Object(object)) => {
+            let struct_ = structs.get(&identifier.name).ok_or_else(unexpected)?;
+
+            // Every key must be a member, or the nonce of a record.
+            let is_member = |key: &str| {
+                struct_.members.iter().any(|member| member.name().to_string() == key)
+                    || struct_.is_record && key == "_nonce"
+            };
+            if let Some(key) = object.keys().find(|key| !is_member(key)) {
+                return Err(InputError::unknown_json_input(identifier, format!("{name}.{key}")).into());
+            }
+
+            let members = struct_
+                .members
+                .iter()
+                .map(|member| {
+                    let path = format!("{name}.{}", member.name());
+                    let value = object
+                        .get(&member.name().to_string())
+                        .ok_or_else(|| InputError::missing_json_input(identifier, &path))?;
+                    encode_json(&path, &member.type_, value, structs, member_visibility(struct_, member, mode))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let nonce = match object.get("_nonce") {
+                Some(nonce) => {
+                    Some(encode_json(&format!("{name}._nonce"), &Type::Group, nonce, structs, Some(Mode::Public))?)
+                }
+                None => None,
+            };
+            Ok(struct_value(struct_, members, nonce))
+        }
+        _ => Err(unexpected().into()),
+    }
+}
+
+/// Returns the digits of a JSON number, or of a JSON string without the type `suffix`.
+fn number(value: &Value, suffix: &str) -> String {
+    match value {
+        Value::String(string) => string.strip_suffix(suffix).unwrap_or(string).to_string(),
+        value => value.to_string(),
+    }
+}
+
+/// Returns `true` if `number` is a decimal integer that the integer type can represent.
+fn integer_in_range(type_: IntegerType, number: &str) -> bool {
+    match type_ {
+        IntegerType::U8 => number.parse::<u8>().is_ok(),
+        IntegerType::U16 => number.parse::<u16>().is_ok(),
+        IntegerType::U32 => number.parse::<u32>().is_ok(),
+        IntegerType::U64 => number.parse::<u64>().is_ok(),
+        IntegerType::U128 => number.parse::<u128>().is_ok(),
+        IntegerType::I8 => number.parse::<i8>().is_ok(),
+        IntegerType::I16 => number.parse::<i16>().is_ok(),
+        IntegerType::I32 => number.parse::<i32>().is_ok(),
+        IntegerType::I64 => number.parse::<i64>().is_ok(),
+        IntegerType::I128 => number.parse::<i128>().is_ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ArrayType, FunctionInput, Identifier, Member, NonNegativeNumber};
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const ADDRESS: &str = "aleo1rhgdu77hgyqd3xjj8ucu3jj9r2krwz6mnzyd80gncr5fxcwlh5rsvzp9px";
+
+    /// Declares a struct, or a record, with members of the given names, modes, and types.
+    fn declare(
+        structs: &mut IndexMap<Symbol, Struct>,
+        name: &str,
+        is_record: bool,
+        members: &[(&str, Mode, Type)],
+    ) -> Type {
+        let identifier = Identifier::new(Symbol::intern(name), Default::default());
+        let members = members
+            .iter()
+            .map(|(name, mode, type_)| Member {
+                mode: *mode,
+                identifier: Identifier::new(Symbol::intern(name), Default::default()),
+                type_: type_.clone(),
+                span: Default::default(),
+                id: Default::default(),
+            })
+            .collect();
+        structs.insert(identifier.name, Struct {
+            identifier,
+            members,
+            is_record,
+            doc: None,
+            span: Default::default(),
+            id: Default::default(),
+        });
+        Type::Identifier(identifier)
+    }
+
+    /// Returns the inputs of a function with the given names and types.
+    fn signature(inputs: &[(&str, Type)]) -> Vec<Input> {
+        inputs
+            .iter()
+            .map(|(name, type_)| {
+                Input::Internal(FunctionInput {
+                    identifier: Identifier::new(Symbol::intern(name), Default::default()),
+                    mode: Mode::None,
+                    type_: type_.clone(),
+                    span: Default::default(),
+                    id: Default::default(),
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn values_are_converted_to_the_input_types() {
+        create_session_if_not_set_then(|_| {
+            let signature = signature(&[
+                ("a", Type::Integer(IntegerType::U8)),
+                ("b", Type::Integer(IntegerType::I64)),
+                ("c", Type::Field),
+                ("d", Type::Array(ArrayType::new(Type::Boolean, NonNegativeNumber::from("2".to_string())))),
+            ]);
+            let input = JsonInput::parse(r#"{ "main": { "d": [true, false], "c": "-3field", "b": -2, "a": 1 } }"#);
+            let values = input.unwrap().program_inputs("main", None, &signature, &IndexMap::new()).unwrap();
+            assert_eq!(values, ["1u8", "-2i64", "-3field", "[true, false]"]);
+
+            // Every input must be given, with a value of its type.
+            let error = |source: &str| {
+                let input = JsonInput::parse(source).unwrap();
+                input.program_inputs("main", None, &signature, &IndexMap::new()).unwrap_err().to_string()
+            };
+            let out_of_range = error(r#"{ "main": { "a": 256, "b": 0, "c": 0, "d": [true, true] } }"#);
+            assert!(out_of_range.contains("The input `a` must be a `u8`, but `256` is given."), "{out_of_range}");
+            let missing = error(r#"{ "main": { "a": 1, "b": 0, "c": 0 } }"#);
+            assert!(missing.contains("does not give a value for the input `d` of `main`"), "{missing}");
+            let unknown = error(r#"{ "main": { "a": 1, "b": 0, "c": 0, "d": [true, true], "e": 0 } }"#);
+            assert!(unknown.contains("gives a value for `e`, but `main` has no such input"), "{unknown}");
+        })
+    }
+
+    #[test]
+    fn cases_are_selected_by_name() {
+        create_session_if_not_set_then(|_| {
+            let signature = signature(&[("a", Type::Integer(IntegerType::U32))]);
+            let input = JsonInput::parse(
+                r#"{ "other": { "b": 0 }, "main.small": { "a": 1 }, "main": { "a": 2 }, "main.large": { "a": "4294967295u32" } }"#,
+            )
+            .unwrap();
+            let inputs = |case| input.program_inputs("main", case, &signature, &IndexMap::new());
+
+            assert_eq!(inputs(None).unwrap(), ["2u32"]);
+            assert_eq!(inputs(Some("small")).unwrap(), ["1u32"]);
+            assert_eq!(inputs(Some("large")).unwrap(), ["4294967295u32"]);
+            let error = inputs(Some("huge")).unwrap_err().to_string();
+            assert!(error.contains("There is no input case `huge` for `main`."), "{error}");
+
+            // A function without inputs in the file is run without any.
+            assert!(input.program_inputs("none", None, &signature, &IndexMap::new()).unwrap().is_empty());
+        })
+    }
+
+    #[test]
+    fn records_give_every_literal_their_visibility() {
+        create_session_if_not_set_then(|_| {
+            let mut structs = IndexMap::new();
+            let point = declare(&mut structs, "Point", false, &[
+                ("x", Mode::None, Type::Integer(IntegerType::U8)),
+                ("y", Mode::None, Type::Integer(IntegerType::U8)),
+            ]);
+            let amounts =
+                Type::Array(ArrayType::new(Type::Integer(IntegerType::U64), NonNegativeNumber::from("2".to_string())));
+            let token = declare(&mut structs, "Token", true, &[
+                ("owner", Mode::None, Type::Address),
+                ("amounts", Mode::Public, amounts),
+                ("origin", Mode::Private, point.clone()),
+            ]);
+            let signature = signature(&[("token", token), ("point", point)]);
+
+            let source = format!(
+                r#"{{ "main": {{
+                    "token": {{ "owner": "{ADDRESS}", "amounts": [1, 2], "origin": {{ "x": 3, "y": 4 }}, "_nonce": 5 }},
+                    "point": {{ "x": 6, "y": 7 }}
+                }} }}"#
+            );
+            let values = JsonInput::parse(&source).unwrap().program_inputs("main", None, &signature, &structs).unwrap();
+            assert_eq!(values, [
+                format!(
+                    "{{ owner: {ADDRESS}.private, amounts: [1u64.public, 2u64.public], \
+                     origin: {{ x: 3u8.private, y: 4u8.private }}, _nonce: 5group.public }}"
+                ),
+                "{ x: 6u8, y: 7u8 }".to_string(),
+            ]);
+        })
+    }
+}
@@ -20,12 +20,18 @@ pub use definition::*;
 pub mod input_ast;
 pub use input_ast::*;
 
+pub mod json_input;
+pub use json_input::*;
+
 pub mod input_value;
 pub use input_value::*;
 
 pub mod program_input;
 pub use program_input::*;
 
+pub mod program_output;
+pub use program_output::*;
+
 pub mod section;
 pub use section::*;
 
@@ -0,0 +1,357 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Mode, Struct, Type};
+
+use super::*;
+use serde_json::{Map, Value};
+use std::{fmt, iter::Peekable, str::Chars};
+
+/// An output of a transition, decoded from its snarkVM value with the Leo type of the output.
+/// It is the inverse of the encoding of program inputs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProgramOutput {
+    /// A literal such as `1u32`, or a record that is still encrypted.
+    Literal(String),
+    /// An array of outputs.
+    Array(Vec<ProgramOutput>),
+    /// A struct or a record, with the members in the order of the declaration.
+    /// The members of a record are followed by its nonce.
+    Struct { name: Symbol, members: Vec<(Symbol, Mode, ProgramOutput)> },
+}
+
+impl ProgramOutput {
+    /// Decodes the output `index` of a transition from its snarkVM value, which must have the `expected` type.
+    pub fn decode(index: usize, value: &str, expected: &Type, structs: &IndexMap<Symbol, Struct>) -> Result<Self> {
+        let unexpected = || InputError::unexpected_output(index, expected, value);
+        let mut chars = value.chars().peekable();
+        let raw = parse_value(&mut chars).ok_or_else(unexpected)?;
+        match chars.all(char::is_whitespace) {
+            true => Ok(decode_value(raw, expected, structs).ok_or_else(unexpected)?.0),
+            false => Err(unexpected().into()),
+        }
+    }
+
+    /// Returns the output in the format of JSON input files, so that it can be given back as an input.
+    pub fn to_json(&self) -> Value {
+        match self {
+            Self::Literal(literal) => match literal.parse::<bool>() {
+                Ok(boolean) => Value::Bool(boolean),
+                Err(_) => Value::String(literal.clone()),
+            },
+            Self::Array(elements) => Value::Array(elements.iter().map(Self::to_json).collect()),
+            Self::Struct { members, .. } => Value::Object(
+                members.iter().map(|(name, _, value)| (name.to_string(), value.to_json())).collect::<Map<_, _>>(),
+            ),
+        }
+    }
+
+    /// Writes the output, indenting the members of structs by `indent` levels.
+    fn fmt_indented(&self, f: &mut fmt::Formatter, indent: usize) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => write!(f, "{literal}"),
+            Self::Array(elements) => {
+                write!(f, "[")?;
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    element.fmt_indented(f, indent)?;
+                }
+                write!(f, "]")
+            }
+            Self::Struct { name, members } => {
+                writeln!(f, "{name} {{")?;
+                for (i, (member, mode, value)) in members.iter().enumerate() {
+                    write!(f, "{:width$}", "", width = 4 * (indent + 1))?;
+                    match mode {
+                        Mode::None => write!(f, "{member}: ")?,
+                        _ => write!(f, "{mode} {member}: ")?,
+                    }
+                    value.fmt_indented(f, indent + 1)?;
+                    writeln!(f, "{}", if i + 1 < members.len() { "," } else { "" })?;
+                }
+                write!(f, "{:width$}}}", "", width = 4 * indent)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ProgramOutput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+/// A snarkVM value before it is matched against a Leo type.
+enum RawValue {
+    Literal(String),
+    Array(Vec<RawValue>),
+    Struct(Vec<(String, RawValue)>),
+}
+
+/// Parses a snarkVM value, such as `{ owner: aleo1....private, amounts: [1u64.private, 2u64.private] }`.
+fn parse_value(chars: &mut Peekable<Chars>) -> Option<RawValue> {
+    skip_whitespace(chars);
+    match chars.peek()? {
+        '[' => {
+            chars.next();
+            parse_sequence(chars, ']', parse_value).map(RawValue::Array)
+        }
+        '{' => {
+            chars.next();
+            let member = |chars: &mut Peekable<Chars>| {
+                let name = parse_literal(chars)?;
+                skip_whitespace(chars);
+                (chars.next()? == ':').then_some(())?;
+                Some((name, parse_value(chars)?))
+            };
+            parse_sequence(chars, '}', member).map(RawValue::Struct)
+        }
+        _ => parse_literal(chars).map(RawValue::Literal),
+    }
+}
+
+/// Parses the elements of an array or the members of a struct up to the closing delimiter `end`.
+fn parse_sequence<T>(
+    chars: &mut Peekable<Chars>,
+    end: char,
+    mut parse_element: impl FnMut(&mut Peekable<Chars>) -> Option<T>,
+) -> Option<Vec<T>> {
+    let mut elements = Vec::new();
+    loop {
+        skip_whitespace(chars);
+        if chars.peek() == Some(&end) {
+            chars.next();
+            return Some(elements);
+        }
+        elements.push(parse_element(chars)?);
+        skip_whitespace(chars);
+        match chars.next()? {
+            ',' => {}
+            c if c == end => return Some(elements),
+            _ => return None,
+        }
+    }
+}
+
+/// Parses a literal or the name of a member, which ends at whitespace or a delimiter.
+fn parse_literal(chars: &mut Peekable<Chars>) -> Option<String> {
+    skip_whitespace(chars);
+    let mut literal = String::new();
+    while let Some(c) = chars.next_if(|c| !c.is_whitespace() && !"[]{},:".contains(*c)) {
+        literal.push(c);
+    }
+    (!literal.is_empty()).then_some(literal)
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+/// Matches a snarkVM value against the `expected` type.
+/// Returns the output and the visibility of its first literal, which is only given in records.
+fn decode_value(raw: RawValue, expected: &Type, structs: &IndexMap<Symbol, Struct>) -> Option<(ProgramOutput, Mode)> {
+    match (expected, raw) {
+        (Type::Array(array), RawValue::Array(elements)) if elements.len() == array.length() => {
+            let elements = elements
+                .into_iter()
+                .map(|element| decode_value(element, array.element_type(), structs))
+                .collect::<Option<Vec<_>>>()?;
+            let mode = elements.first().map_or(Mode::None, |(_, mode)| *mode);
+            Some((ProgramOutput::Array(elements.into_iter().map(|(element, _)| element).collect()), mode))
+        }
+        (Type::Identifier(identifier), RawValue::Struct(mut values)) => {
+            let struct_ = structs.get(&identifier.name)?;
+            let mut members = struct_
+                .members
+                .iter()
+                .map(|member| {
+                    let index = values.iter().position(|(name, _)| *name == member.name().to_string())?;
+                    let (output, mode) = decode_value(values.remove(index).1, &member.type_, structs)?;
+                    Some((member.name(), mode, output))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            let mode = members.first().map_or(Mode::None, |(_, mode, _)| *mode);
+            // Only the members of records have visibilities.
+            if !struct_.is_record {
+                members.iter_mut().for_each(|(_, member_mode, _)| *member_mode = Mode::None);
+            }
+            // The members that are not declared, such as the nonce of a record, are kept as they are given.
+            if !struct_.is_record && !values.is_empty() {
+                return None;
+            }
+            for (name, value) in values {
+                let RawValue::Literal(literal) = value else { return None };
+                let (literal, mode) = split_mode(literal);
+                members.push((Symbol::intern(&name), mode, ProgramOutput::Literal(literal)));
+            }
+            Some((ProgramOutput::Struct { name: identifier.name, members }, mode))
+        }
+        // A record that is encrypted to another address is given as a ciphertext.
+        (Type::Identifier(identifier), RawValue::Literal(ciphertext))
+            if structs.get(&identifier.name).map_or(false, |struct_| struct_.is_record)
+                && ciphertext.starts_with("record1") =>
+        {
+            Some((ProgramOutput::Literal(ciphertext), Mode::None))
+        }
+        (Type::Array(_) | Type::Identifier(_), _) | (_, RawValue::Array(_) | RawValue::Struct(_)) => None,
+        (_, RawValue::Literal(literal)) => {
+            let (literal, mode) = split_mode(literal);
+            Some((ProgramOutput::Literal(literal), mode))
+        }
+    }
+}
+
+/// Splits the visibility, such as `.private`, from a literal in a record.
+fn split_mode(literal: String) -> (String, Mode) {
+    let mode = match literal.rsplit_once('.') {
+        Some((_, "constant")) => Mode::Constant,
+        Some((_, "private")) => Mode::Private,
+        Some((_, "public")) => Mode::Public,
+        _ => return (literal, Mode::None),
+    };
+    let length = literal.rfind('.').unwrap_or(literal.len());
+    (literal[..length].to_string(), mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ArrayType, Identifier, IntegerType, Member, NonNegativeNumber};
+
+    use leo_span::symbol::create_session_if_not_set_then;
+    use serde_json::json;
+
+    /// Declares a struct, or a record, with members of the given names and types.
+    fn declare(structs: &mut IndexMap<Symbol, Struct>, name: &str, is_record: bool, members: &[(&str, Type)]) -> Type {
+        let identifier = Identifier::new(Symbol::intern(name), Default::default());
+        let members = members
+            .iter()
+            .map(|(name, type_)| Member {
+                mode: Mode::None,
+                identifier: Identifier::new(Symbol::intern(name), Default::default()),
+                type_: type_.clone(),
+                span: Default::default(),
+                id: Default::default(),
+            })
+            .collect();
+        structs.insert(identifier.name, Struct {
+            identifier,
+            members,
+            is_record,
+            doc: None,
+            span: Default::default(),
+            id: Default::default(),
+        });
+        Type::Identifier(identifier)
+    }
+
+    fn array(element: Type, length: usize) -> Type {
+        Type::Array(ArrayType::new(element, NonNegativeNumber::from(length.to_string())))
+    }
+
+    #[test]
+    fn tuple_elements_are_decoded_with_their_types() {
+        create_session_if_not_set_then(|_| {
+            // The elements of a tuple are separate outputs of the transition.
+            let structs = IndexMap::new();
+            let first = ProgramOutput::decode(0, "1u8", &Type::Integer(IntegerType::U8), &structs).unwrap();
+            let second = ProgramOutput::decode(1, "true", &Type::Boolean, &structs).unwrap();
+            let third = ProgramOutput::decode(2, " [1field, 2field] ", &array(Type::Field, 2), &structs).unwrap();
+
+            assert_eq!(first, ProgramOutput::Literal("1u8".to_string()));
+            assert_eq!(
+                (first.to_json(), second.to_json(), third.to_json()),
+                (json!("1u8"), json!(true), json!(["1field", "2field"]))
+            );
+            assert_eq!(third.to_string(), "[1field, 2field]");
+        })
+    }
+
+    #[test]
+    fn nested_structs_and_arrays() {
+        create_session_if_not_set_then(|_| {
+            let mut structs = IndexMap::new();
+            let point = declare(&mut structs, "Point", false, &[
+                ("x", Type::Integer(IntegerType::U8)),
+                ("y", Type::Integer(IntegerType::U8)),
+            ]);
+            let line = declare(&mut structs, "Line", false, &[("points", array(point, 2)), ("visible", Type::Boolean)]);
+
+            // The members are ordered as they are declared, not as snarkVM returns them.
+            let value = "{ points: [{ x: 1u8, y: 2u8 }, { y: 4u8, x: 3u8 }], visible: false }";
+            let output = ProgramOutput::decode(0, value, &line, &structs).unwrap();
+
+            assert_eq!(
+                output.to_json(),
+                json!({ "points": [{ "x": "1u8", "y": "2u8" }, { "x": "3u8", "y": "4u8" }], "visible": false })
+            );
+            let ProgramOutput::Struct { name, members } = &output else { panic!("expected a struct, found {output}") };
+            assert_eq!(name.to_string(), "Line");
+            assert!(members.iter().all(|(_, mode, _)| *mode == Mode::None));
+        })
+    }
+
+    #[test]
+    fn records_keep_visibilities_and_nonces() {
+        create_session_if_not_set_then(|_| {
+            let mut structs = IndexMap::new();
+            let token = declare(&mut structs, "Token", true, &[
+                ("owner", Type::Address),
+                ("amounts", array(Type::Integer(IntegerType::U64), 2)),
+            ]);
+
+            let value =
+                "{\n  owner: aleo1owner.private,\n  amounts: [1u64.public, 2u64.public],\n  _nonce: 0group.public\n}";
+            let output = ProgramOutput::decode(0, value, &token, &structs).unwrap();
+
+            assert_eq!(
+                output.to_json(),
+                json!({ "owner": "aleo1owner", "amounts": ["1u64", "2u64"], "_nonce": "0group" })
+            );
+            let rendered = "Token {
+    private owner: aleo1owner,
+    public amounts: [1u64, 2u64],
+    public _nonce: 0group
+}";
+            assert_eq!(output.to_string(), rendered);
+
+            // A record that is encrypted to another address is kept as a ciphertext.
+            let ciphertext = ProgramOutput::decode(0, "record1ciphertext", &token, &structs).unwrap();
+            assert_eq!(ciphertext, ProgramOutput::Literal("record1ciphertext".to_string()));
+        })
+    }
+
+    #[test]
+    fn mismatched_types_are_errors() {
+        create_session_if_not_set_then(|_| {
+            let mut structs = IndexMap::new();
+            let point = declare(&mut structs, "Point", false, &[("x", Type::Integer(IntegerType::U8))]);
+            let decode = |value: &str, expected: &Type| ProgramOutput::decode(3, value, expected, &structs);
+
+            let error = decode("1u8", &point).unwrap_err().to_string();
+            assert!(error.contains("The output 3 of the transition should be a `Point`, but snarkVM returned `1u8`."));
+
+            assert!(decode("{ x: 1u8, z: 2u8 }", &point).is_err());
+            assert!(decode("{ y: 1u8 }", &point).is_err());
+            assert!(decode("[1u8]", &array(Type::Integer(IntegerType::U8), 2)).is_err());
+            assert!(decode("[1u8, 2u8]", &Type::Integer(IntegerType::U8)).is_err());
+            assert!(decode("1u8 2u8", &Type::Integer(IntegerType::U8)).is_err());
+            assert!(decode("record1ciphertext", &point).is_err());
+        })
+    }
+}
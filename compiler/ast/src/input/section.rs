@@ -17,10 +17,12 @@
 use super::*;
 
 /// A single section in an input or a state file.
-/// An example of a section would be: `[main]`.
+/// An example of a section would be: `[main]`, or `[main.large]` for one of several cases of inputs to `main`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Section {
     pub name: Symbol,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub case: Option<Symbol>,
     pub definitions: Vec<Definition>,
     pub span: Span,
 }
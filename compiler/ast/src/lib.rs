@@ -55,6 +55,9 @@ pub use self::program::*;
 pub mod statement;
 pub use self::statement::*;
 
+pub mod stub;
+pub use self::stub::*;
+
 pub mod types;
 pub use self::types::*;
 
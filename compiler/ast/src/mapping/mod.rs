@@ -30,6 +30,9 @@ pub struct Mapping {
     pub key_type: Type,
     /// The type of the value.
     pub value_type: Type,
+    /// The doc comment of the mapping, from the `///` comments before it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
     /// The entire span of the mapping declaration.
     pub span: Span,
     /// The ID of the node.
@@ -14,8 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-//! This module contains both a Reducer and Visitor design pattern.
-//! These both iterate over the AST.
+//! This module contains a Reducer and Visitor design pattern, and a mutable Visitor.
+//! These all iterate over the AST.
 
 // TODO: Move the files in this module into `leo-passes` in a future PR.
 
@@ -27,3 +27,6 @@ pub use reconstructor::*;
 
 pub mod visitor;
 pub use visitor::*;
+
+pub mod visitor_mut;
+pub use visitor_mut::*;
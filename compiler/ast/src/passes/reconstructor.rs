@@ -343,6 +343,7 @@ pub trait StatementReconstructor: ExpressionReconstructor {
                 place: input.place,
                 type_: input.type_,
                 value: self.reconstruct_expression(input.value).0,
+                doc: input.doc,
                 span: input.span,
                 id: input.id,
             }),
@@ -417,6 +418,7 @@ pub trait ProgramReconstructor: StatementReconstructor {
                 .into_iter()
                 .map(|(id, import)| (id, (self.reconstruct_import(import.0), import.1)))
                 .collect(),
+            stubs: input.stubs,
             program_scopes: input
                 .program_scopes
                 .into_iter()
@@ -461,6 +463,7 @@ pub trait ProgramReconstructor: StatementReconstructor {
                 span: finalize.span,
                 id: finalize.id,
             }),
+            doc: input.doc,
             span: input.span,
             id: input.id,
         }
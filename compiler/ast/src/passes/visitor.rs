@@ -223,6 +223,8 @@ pub trait ProgramVisitor<'a>: StatementVisitor<'a> {
     fn visit_program(&mut self, input: &'a Program) {
         input.imports.values().for_each(|import| self.visit_import(&import.0));
 
+        input.stubs.values().for_each(|stub| self.visit_stub(stub));
+
         input.program_scopes.values().for_each(|scope| self.visit_program_scope(scope));
     }
 
@@ -240,6 +242,8 @@ pub trait ProgramVisitor<'a>: StatementVisitor<'a> {
         self.visit_program(input)
     }
 
+    fn visit_stub(&mut self, _input: &'a Stub) {}
+
     fn visit_struct(&mut self, _input: &'a Struct) {}
 
     fn visit_mapping(&mut self, _input: &'a Mapping) {}
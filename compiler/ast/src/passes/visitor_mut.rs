@@ -0,0 +1,276 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! This module contains mutable Visitor trait implementations for the AST.
+//! Unlike the Reconstructor, which consumes each node and builds a new one,
+//! these traits modify the AST in place, so a pass only pays for the nodes it changes.
+
+use crate::*;
+
+/// A mutable Visitor trait for expressions in the AST.
+pub trait ExpressionVisitorMut {
+    type AdditionalOutput: Default;
+
+    fn visit_expression_mut(&mut self, input: &mut Expression) -> Self::AdditionalOutput {
+        match input {
+            Expression::Access(access) => self.visit_access_mut(access),
+            Expression::Array(array) => self.visit_array_mut(array),
+            Expression::Binary(binary) => self.visit_binary_mut(binary),
+            Expression::Call(call) => self.visit_call_mut(call),
+            Expression::Cast(cast) => self.visit_cast_mut(cast),
+            Expression::Struct(struct_) => self.visit_struct_init_mut(struct_),
+            Expression::Err(err) => self.visit_err_mut(err),
+            Expression::Identifier(identifier) => self.visit_identifier_mut(identifier),
+            Expression::Literal(literal) => self.visit_literal_mut(literal),
+            Expression::Ternary(ternary) => self.visit_ternary_mut(ternary),
+            Expression::Tuple(tuple) => self.visit_tuple_mut(tuple),
+            Expression::Unary(unary) => self.visit_unary_mut(unary),
+            Expression::Unit(unit) => self.visit_unit_mut(unit),
+        }
+    }
+
+    fn visit_access_mut(&mut self, input: &mut AccessExpression) -> Self::AdditionalOutput {
+        match input {
+            AccessExpression::Array(array) => {
+                self.visit_expression_mut(&mut array.array);
+                self.visit_expression_mut(&mut array.index);
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                self.visit_associated_function_mut(function);
+            }
+            AccessExpression::Member(member) => {
+                self.visit_expression_mut(&mut member.inner);
+            }
+            AccessExpression::Tuple(tuple) => {
+                self.visit_expression_mut(&mut tuple.tuple);
+            }
+            AccessExpression::AssociatedConstant(_) => {}
+        }
+
+        Default::default()
+    }
+
+    fn visit_associated_function_mut(&mut self, input: &mut AssociatedFunction) -> Self::AdditionalOutput {
+        input.arguments.iter_mut().for_each(|argument| {
+            self.visit_expression_mut(argument);
+        });
+        Default::default()
+    }
+
+    fn visit_array_mut(&mut self, input: &mut ArrayExpression) -> Self::AdditionalOutput {
+        input.elements.iter_mut().for_each(|element| {
+            self.visit_expression_mut(element);
+        });
+        Default::default()
+    }
+
+    fn visit_binary_mut(&mut self, input: &mut BinaryExpression) -> Self::AdditionalOutput {
+        self.visit_expression_mut(&mut input.left);
+        self.visit_expression_mut(&mut input.right);
+        Default::default()
+    }
+
+    fn visit_call_mut(&mut self, input: &mut CallExpression) -> Self::AdditionalOutput {
+        self.visit_expression_mut(&mut input.function);
+        input.arguments.iter_mut().for_each(|argument| {
+            self.visit_expression_mut(argument);
+        });
+        Default::default()
+    }
+
+    fn visit_cast_mut(&mut self, input: &mut CastExpression) -> Self::AdditionalOutput {
+        self.visit_expression_mut(&mut input.expression);
+        Default::default()
+    }
+
+    fn visit_struct_init_mut(&mut self, input: &mut StructExpression) -> Self::AdditionalOutput {
+        input.members.iter_mut().for_each(|member| {
+            if let Some(expression) = member.expression.as_mut() {
+                self.visit_expression_mut(expression);
+            }
+        });
+        Default::default()
+    }
+
+    fn visit_err_mut(&mut self, _input: &mut ErrExpression) -> Self::AdditionalOutput {
+        unreachable!("`ErrExpression`s should not be in the AST at this phase of compilation.")
+    }
+
+    fn visit_identifier_mut(&mut self, _input: &mut Identifier) -> Self::AdditionalOutput {
+        Default::default()
+    }
+
+    fn visit_literal_mut(&mut self, _input: &mut Literal) -> Self::AdditionalOutput {
+        Default::default()
+    }
+
+    fn visit_ternary_mut(&mut self, input: &mut TernaryExpression) -> Self::AdditionalOutput {
+        self.visit_expression_mut(&mut input.condition);
+        self.visit_expression_mut(&mut input.if_true);
+        self.visit_expression_mut(&mut input.if_false);
+        Default::default()
+    }
+
+    fn visit_tuple_mut(&mut self, input: &mut TupleExpression) -> Self::AdditionalOutput {
+        input.elements.iter_mut().for_each(|element| {
+            self.visit_expression_mut(element);
+        });
+        Default::default()
+    }
+
+    fn visit_unary_mut(&mut self, input: &mut UnaryExpression) -> Self::AdditionalOutput {
+        self.visit_expression_mut(&mut input.receiver);
+        Default::default()
+    }
+
+    fn visit_unit_mut(&mut self, _input: &mut UnitExpression) -> Self::AdditionalOutput {
+        Default::default()
+    }
+}
+
+/// A mutable Visitor trait for statements in the AST.
+/// A pass that replaces a statement with a different kind of statement overrides `visit_statement_mut`.
+pub trait StatementVisitorMut: ExpressionVisitorMut {
+    fn visit_statement_mut(&mut self, input: &mut Statement) -> Self::AdditionalOutput {
+        match input {
+            Statement::Assert(stmt) => self.visit_assert_mut(stmt),
+            Statement::Assign(stmt) => self.visit_assign_mut(stmt),
+            Statement::Block(stmt) => self.visit_block_mut(stmt),
+            Statement::Conditional(stmt) => self.visit_conditional_mut(stmt),
+            Statement::Console(stmt) => self.visit_console_mut(stmt),
+            Statement::Const(stmt) => self.visit_const_mut(stmt),
+            Statement::Definition(stmt) => self.visit_definition_mut(stmt),
+            Statement::Expression(stmt) => self.visit_expression_statement_mut(stmt),
+            Statement::Iteration(stmt) => self.visit_iteration_mut(stmt),
+            Statement::Return(stmt) => self.visit_return_mut(stmt),
+        }
+    }
+
+    fn visit_assert_mut(&mut self, input: &mut AssertStatement) -> Self::AdditionalOutput {
+        match &mut input.variant {
+            AssertVariant::Assert(expr) => {
+                self.visit_expression_mut(expr);
+            }
+            AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
+                self.visit_expression_mut(left);
+                self.visit_expression_mut(right);
+            }
+        };
+        Default::default()
+    }
+
+    fn visit_assign_mut(&mut self, input: &mut AssignStatement) -> Self::AdditionalOutput {
+        self.visit_expression_mut(&mut input.value);
+        Default::default()
+    }
+
+    fn visit_block_mut(&mut self, input: &mut Block) -> Self::AdditionalOutput {
+        input.statements.iter_mut().for_each(|statement| {
+            self.visit_statement_mut(statement);
+        });
+        Default::default()
+    }
+
+    fn visit_conditional_mut(&mut self, input: &mut ConditionalStatement) -> Self::AdditionalOutput {
+        self.visit_expression_mut(&mut input.condition);
+        self.visit_block_mut(&mut input.then);
+        if let Some(stmt) = input.otherwise.as_mut() {
+            self.visit_statement_mut(stmt);
+        }
+        Default::default()
+    }
+
+    fn visit_console_mut(&mut self, input: &mut ConsoleStatement) -> Self::AdditionalOutput {
+        match &mut input.function {
+            ConsoleFunction::Assert(expr) => {
+                self.visit_expression_mut(expr);
+            }
+            ConsoleFunction::AssertEq(left, right) | ConsoleFunction::AssertNeq(left, right) => {
+                self.visit_expression_mut(left);
+                self.visit_expression_mut(right);
+            }
+        };
+        Default::default()
+    }
+
+    fn visit_const_mut(&mut self, input: &mut ConstDeclaration) -> Self::AdditionalOutput {
+        self.visit_expression_mut(&mut input.value);
+        Default::default()
+    }
+
+    fn visit_definition_mut(&mut self, input: &mut DefinitionStatement) -> Self::AdditionalOutput {
+        self.visit_expression_mut(&mut input.value);
+        Default::default()
+    }
+
+    fn visit_expression_statement_mut(&mut self, input: &mut ExpressionStatement) -> Self::AdditionalOutput {
+        self.visit_expression_mut(&mut input.expression);
+        Default::default()
+    }
+
+    fn visit_iteration_mut(&mut self, input: &mut IterationStatement) -> Self::AdditionalOutput {
+        self.visit_expression_mut(&mut input.start);
+        self.visit_expression_mut(&mut input.stop);
+        self.visit_block_mut(&mut input.block);
+        Default::default()
+    }
+
+    fn visit_return_mut(&mut self, input: &mut ReturnStatement) -> Self::AdditionalOutput {
+        self.visit_expression_mut(&mut input.expression);
+        if let Some(arguments) = input.finalize_arguments.as_mut() {
+            arguments.iter_mut().for_each(|argument| {
+                self.visit_expression_mut(argument);
+            });
+        }
+        Default::default()
+    }
+}
+
+/// A mutable Visitor trait for the program represented by the AST.
+pub trait ProgramVisitorMut: StatementVisitorMut {
+    fn visit_program_mut(&mut self, input: &mut Program) {
+        input.imports.values_mut().for_each(|import| self.visit_import_mut(&mut import.0));
+
+        input.program_scopes.values_mut().for_each(|scope| self.visit_program_scope_mut(scope));
+    }
+
+    fn visit_program_scope_mut(&mut self, input: &mut ProgramScope) {
+        input.structs.iter_mut().for_each(|(_, struct_)| self.visit_struct_mut(struct_));
+
+        input.mappings.iter_mut().for_each(|(_, mapping)| self.visit_mapping_mut(mapping));
+
+        input.functions.iter_mut().for_each(|(_, function)| self.visit_function_mut(function));
+
+        input.consts.iter_mut().for_each(|(_, declaration)| {
+            self.visit_const_mut(declaration);
+        });
+    }
+
+    fn visit_import_mut(&mut self, input: &mut Program) {
+        self.visit_program_mut(input)
+    }
+
+    fn visit_struct_mut(&mut self, _input: &mut Struct) {}
+
+    fn visit_mapping_mut(&mut self, _input: &mut Mapping) {}
+
+    fn visit_function_mut(&mut self, input: &mut Function) {
+        self.visit_block_mut(&mut input.block);
+        if let Some(finalize) = input.finalize.as_mut() {
+            self.visit_block_mut(&mut finalize.block);
+        }
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-//! A Leo program consists of import statements and program scopes.
+//! A Leo program consists of import statements, stubs of imported Aleo programs, and program scopes.
 
 pub mod program_id;
 pub use program_id::*;
@@ -22,6 +22,8 @@ pub use program_id::*;
 pub mod program_scope;
 pub use program_scope::*;
 
+use crate::Stub;
+
 use leo_span::{Span, Symbol};
 
 use indexmap::IndexMap;
@@ -33,6 +35,9 @@ use std::fmt;
 pub struct Program {
     /// A map from import names to import definitions.
     pub imports: IndexMap<Symbol, (Program, Span)>,
+    /// A map from the names of imported Aleo programs to their stubs.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub stubs: IndexMap<Symbol, Stub>,
     /// A map from program names to program scopes.
     pub program_scopes: IndexMap<Symbol, ProgramScope>,
 }
@@ -42,6 +47,9 @@ impl fmt::Display for Program {
         for (id, _import) in self.imports.iter() {
             writeln!(f, "import {id}.leo;")?;
         }
+        for (id, _stub) in self.stubs.iter() {
+            writeln!(f, "import {id}.aleo;")?;
+        }
         for (_, program_scope) in self.program_scopes.iter() {
             program_scope.fmt(f)?;
             writeln!(f,)?;
@@ -53,6 +61,6 @@ impl fmt::Display for Program {
 impl Default for Program {
     /// Constructs an empty program node.
     fn default() -> Self {
-        Self { imports: IndexMap::new(), program_scopes: IndexMap::new() }
+        Self { imports: IndexMap::new(), stubs: IndexMap::new(), program_scopes: IndexMap::new() }
     }
 }
@@ -42,15 +42,18 @@ pub struct ProgramScope {
 impl fmt::Display for ProgramScope {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "program {} {{", self.program_id)?;
+        for (_, const_decl) in self.consts.iter() {
+            writeln!(f, "    {const_decl}")?;
+        }
         for (_, struct_) in self.structs.iter() {
             writeln!(f, "    {struct_}")?;
         }
         for (_, mapping) in self.mappings.iter() {
-            writeln!(f, "    {mapping}")?;
+            writeln!(f, "    {mapping};")?;
         }
         for (_, function) in self.functions.iter() {
             writeln!(f, "    {function}")?;
         }
-        Ok(())
+        write!(f, "}}")
     }
 }
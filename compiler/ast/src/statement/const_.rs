@@ -29,6 +29,9 @@ pub struct ConstDeclaration {
     pub type_: Type,
     /// An initializer value for the binding.
     pub value: Expression,
+    /// The doc comment of a constant of a program scope, from the `///` comments before it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
     /// The span excluding the semicolon.
     pub span: Span,
     /// The ID of the node.
@@ -37,7 +40,7 @@ pub struct ConstDeclaration {
 
 impl fmt::Display for ConstDeclaration {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.place)?;
+        write!(f, "const {}", self.place)?;
         write!(f, ": {}", self.type_)?;
         write!(f, " = {};", self.value)
     }
@@ -47,7 +47,7 @@ impl fmt::Display for Member {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.mode {
             Mode::None => write!(f, "{}: {}", self.identifier, self.type_),
-            _ => write!(f, "{} {} {}", self.mode, self.identifier, self.type_),
+            _ => write!(f, "{} {}: {}", self.mode, self.identifier, self.type_),
         }
     }
 }
@@ -38,6 +38,9 @@ pub struct Struct {
     /// Was this a `record Foo { ... }`?
     /// If so, it wasn't a struct.
     pub is_record: bool,
+    /// The doc comment of the struct, from the `///` comments before it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
     /// The entire span of the struct definition.
     pub span: Span,
     /// The ID of the node.
@@ -68,11 +71,11 @@ impl fmt::Debug for Struct {
 impl fmt::Display for Struct {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(if self.is_record { "record" } else { "struct" })?;
-        writeln!(f, " {} {{ ", self.identifier)?;
+        writeln!(f, " {} {{", self.identifier)?;
         for field in self.members.iter() {
-            writeln!(f, "    {field}")?;
+            writeln!(f, "        {field},")?;
         }
-        write!(f, "}}")
+        write!(f, "    }}")
     }
 }
 
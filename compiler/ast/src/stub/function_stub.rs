@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Block, Finalize, Function, Identifier, Input, Node, NodeID, Output, TupleType, Type, Variant};
+use leo_span::{Span, Symbol};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The signature of a function in an imported Aleo program.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionStub {
+    /// Is this function a transition, inlined, or a regular function?.
+    pub variant: Variant,
+    /// The function identifier, e.g., `foo` in `transition foo(...);`.
+    pub identifier: Identifier,
+    /// The function's input parameters.
+    pub input: Vec<Input>,
+    /// The function's output declarations.
+    pub output: Vec<Output>,
+    /// The function's output type.
+    pub output_type: Type,
+    /// The signature of the finalize block, if one exists.
+    pub finalize: Option<FinalizeStub>,
+    /// The span of the function signature.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl FunctionStub {
+    /// Initialize a new function stub.
+    pub fn new(
+        variant: Variant,
+        identifier: Identifier,
+        input: Vec<Input>,
+        output: Vec<Output>,
+        finalize: Option<FinalizeStub>,
+        span: Span,
+        id: NodeID,
+    ) -> Self {
+        let output_type = match output.len() {
+            0 => Type::Unit,
+            1 => output[0].type_(),
+            _ => Type::Tuple(TupleType::new(output.iter().map(|output| output.type_()).collect())),
+        };
+
+        Self { variant, identifier, input, output, output_type, finalize, span, id }
+    }
+
+    /// Returns function name.
+    pub fn name(&self) -> Symbol {
+        self.identifier.name
+    }
+}
+
+impl fmt::Display for FunctionStub {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.variant {
+            Variant::Inline => write!(f, "inline ")?,
+            Variant::Standard => write!(f, "function ")?,
+            Variant::Transition => write!(f, "transition ")?,
+        }
+        let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+        let returns = match self.output.len() {
+            0 => String::new(),
+            1 => format!(" -> {}", self.output[0]),
+            _ => format!(" -> ({})", self.output.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ")),
+        };
+        write!(f, "{}({parameters}){returns};", self.identifier)?;
+
+        match &self.finalize {
+            Some(finalize) => write!(f, "\n    {finalize}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Converts the signature into a function with an empty body, so that it can be entered into the symbol table.
+impl From<FunctionStub> for Function {
+    fn from(stub: FunctionStub) -> Self {
+        let block = |id| Block { statements: Vec::new(), span: Default::default(), id };
+        Function {
+            annotations: Vec::new(),
+            variant: stub.variant,
+            identifier: stub.identifier,
+            input: stub.input,
+            output: stub.output,
+            output_type: stub.output_type,
+            block: block(stub.id),
+            finalize: stub.finalize.map(|finalize| Finalize {
+                identifier: finalize.identifier,
+                input: finalize.input,
+                output: Vec::new(),
+                output_type: Type::Unit,
+                block: block(finalize.id),
+                span: finalize.span,
+                id: finalize.id,
+            }),
+            doc: None,
+            span: stub.span,
+            id: stub.id,
+        }
+    }
+}
+
+crate::simple_node_impl!(FunctionStub);
+
+/// The signature of the finalize block of a function in an imported Aleo program.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FinalizeStub {
+    /// The finalize identifier.
+    pub identifier: Identifier,
+    /// The finalize block's input parameters.
+    pub input: Vec<Input>,
+    /// The span of the finalize signature.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for FinalizeStub {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+        write!(f, "finalize {}({parameters});", self.identifier)
+    }
+}
+
+crate::simple_node_impl!(FinalizeStub);
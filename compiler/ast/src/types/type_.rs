@@ -93,7 +93,7 @@ impl fmt::Display for Type {
         match *self {
             Type::Address => write!(f, "address"),
             Type::Array(ref array_type) => write!(f, "{array_type}"),
-            Type::Boolean => write!(f, "boolean"),
+            Type::Boolean => write!(f, "bool"),
             Type::Field => write!(f, "field"),
             Type::Group => write!(f, "group"),
             Type::Identifier(ref variable) => write!(f, "{variable}"),
@@ -873,12 +873,13 @@ impl TryFrom<&Literal> for Value {
         Ok(match literal {
             Literal::Address(string, span, _) => Self::Address(string.clone(), *span),
             Literal::Boolean(bool, span, _) => Self::Boolean(*bool, *span),
-            Literal::Field(string, span, _) => Self::Field(string.clone(), *span),
+            Literal::Field(string, span, _) => Self::Field(Literal::decimal_digits(string).into_owned(), *span),
             Literal::Group(group_literal) => Self::Group(group_literal.clone()),
-            Literal::Scalar(string, span, _) => Self::Scalar(string.clone(), *span),
+            Literal::Scalar(string, span, _) => Self::Scalar(Literal::decimal_digits(string).into_owned(), *span),
             Literal::String(string, span, _) => Self::String(string.clone(), *span),
             Literal::Integer(integer_type, raw_string, span, _) => {
-                let string = raw_string.replace('_', "");
+                // Digits that are not valid fail to parse as the empty string.
+                let string = Literal::to_decimal(raw_string).unwrap_or_default();
                 match integer_type {
                     IntegerType::U8 => Self::U8(string.parse()?, *span),
                     IntegerType::U16 => Self::U16(string.parse()?, *span),
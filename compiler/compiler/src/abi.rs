@@ -0,0 +1,228 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The ABI of a compiled program, a machine-readable description of its interface.
+
+use leo_ast::{Input, Mode, Output, Program, Struct, Type, Variant};
+use leo_errors::{CompilerError, Result};
+use leo_passes::SymbolTable;
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// The interface of a compiled program: its transitions, records, structs and mappings.
+/// Frontends and indexers use it to call the program and decode its values without parsing Aleo instructions.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgramAbi {
+    /// The program id, e.g., `hello.aleo`.
+    pub program: String,
+    /// The structs of the program, including the ones it imports.
+    pub structs: Vec<StructAbi>,
+    /// The records declared by the program.
+    pub records: Vec<StructAbi>,
+    pub mappings: Vec<MappingAbi>,
+    pub transitions: Vec<TransitionAbi>,
+}
+
+/// A struct or a record.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructAbi {
+    pub name: String,
+    pub fields: Vec<FieldAbi>,
+}
+
+/// A member of a struct or a record, or a parameter of a transition or a finalize block.
+/// Only the members of records and the parameters of transitions have a visibility.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldAbi {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: AbiType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+}
+
+/// A mapping, with the types of its keys and values.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MappingAbi {
+    pub name: String,
+    pub key: AbiType,
+    pub value: AbiType,
+}
+
+/// The signature of a transition.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransitionAbi {
+    pub name: String,
+    pub inputs: Vec<FieldAbi>,
+    pub outputs: Vec<OutputAbi>,
+    /// The parameters of the finalize block, if the transition has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finalize: Option<Vec<FieldAbi>>,
+}
+
+/// An output of a transition.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputAbi {
+    #[serde(rename = "type")]
+    pub type_: AbiType,
+    pub visibility: Visibility,
+}
+
+/// The visibility of a value. Values are private unless they are declared otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    Constant,
+    Private,
+    Public,
+}
+
+impl From<Mode> for Visibility {
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::Constant => Visibility::Constant,
+            Mode::None | Mode::Private => Visibility::Private,
+            Mode::Public => Visibility::Public,
+        }
+    }
+}
+
+/// A type, e.g., `{ "primitive": "u64" }` or `{ "array": { "element": { "struct": "Point" }, "length": 2 } }`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AbiType {
+    /// A type such as `address`, `bool`, `field` or `u64`.
+    Primitive(String),
+    Array {
+        element: Box<AbiType>,
+        length: usize,
+    },
+    Tuple(Vec<AbiType>),
+    Struct(String),
+    Record(String),
+    /// A record of another program, e.g., `token.aleo/Token`.
+    ExternalRecord {
+        program: String,
+        record: String,
+    },
+}
+
+impl ProgramAbi {
+    /// Returns the ABI of `program`, with the signatures and declarations of the type-checked `symbol_table`.
+    pub fn new(program: &Program, symbol_table: &SymbolTable) -> Self {
+        let scopes = program.program_scopes.values();
+        let program_id = scopes.clone().next().map(|scope| scope.program_id.to_string()).unwrap_or_default();
+
+        let structs = symbol_table.structs.values().filter(|struct_| !struct_.is_record);
+        let records = scopes.clone().flat_map(|scope| scope.structs.iter()).filter(|(_, struct_)| struct_.is_record);
+
+        // Mappings are global variables of the symbol table.
+        let mappings =
+            scopes.clone().flat_map(|scope| scope.mappings.iter()).filter_map(|(name, _)| {
+                match &symbol_table.lookup_variable(*name)?.type_ {
+                    Type::Mapping(mapping) => Some(MappingAbi {
+                        name: name.to_string(),
+                        key: abi_type(&mapping.key, symbol_table),
+                        value: abi_type(&mapping.value, symbol_table),
+                    }),
+                    _ => None,
+                }
+            });
+
+        let transitions = scopes.flat_map(|scope| scope.functions.iter()).filter_map(|(name, function)| {
+            let symbol = symbol_table.lookup_fn_symbol(*name).filter(|symbol| symbol.variant == Variant::Transition)?;
+            // The symbol table only stores the type of the outputs, so their visibilities are those of the declaration.
+            let output_types = match &symbol.output_type {
+                Type::Unit => Vec::new(),
+                Type::Tuple(tuple) if function.output.len() > 1 => tuple.elements().to_vec(),
+                type_ => vec![type_.clone()],
+            };
+            let outputs = output_types.iter().zip(function.output.iter()).map(|(type_, output)| OutputAbi {
+                type_: match output {
+                    Output::Internal(_) => abi_type(type_, symbol_table),
+                    Output::External(external) => AbiType::ExternalRecord {
+                        program: format!("{}.aleo", external.program_name),
+                        record: external.record.to_string(),
+                    },
+                },
+                visibility: output.mode().into(),
+            });
+            Some(TransitionAbi {
+                name: name.to_string(),
+                inputs: symbol.input.iter().map(|input| input_abi(input, true, symbol_table)).collect(),
+                outputs: outputs.collect(),
+                finalize: symbol
+                    .finalize
+                    .as_ref()
+                    .map(|finalize| finalize.input.iter().map(|input| input_abi(input, false, symbol_table)).collect()),
+            })
+        });
+
+        Self {
+            program: program_id,
+            structs: structs.map(|struct_| struct_abi(struct_, symbol_table)).collect(),
+            records: records.map(|(_, record)| struct_abi(record, symbol_table)).collect(),
+            mappings: mappings.collect(),
+            transitions: transitions.collect(),
+        }
+    }
+
+    /// Writes the ABI as JSON to the file `file_name` in the directory `path`.
+    pub fn to_json_file(&self, mut path: PathBuf, file_name: &str) -> Result<()> {
+        path.push(file_name);
+        let file = std::fs::File::create(&path).map_err(|e| CompilerError::failed_to_write_abi(&path, e))?;
+        let writer = std::io::BufWriter::new(file);
+        Ok(serde_json::to_writer_pretty(writer, &self).map_err(|e| CompilerError::failed_to_write_abi(&path, e))?)
+    }
+}
+
+fn struct_abi(struct_: &Struct, symbol_table: &SymbolTable) -> StructAbi {
+    let fields = struct_.members.iter().map(|member| FieldAbi {
+        name: member.name().to_string(),
+        type_: abi_type(&member.type_, symbol_table),
+        visibility: struct_.is_record.then(|| member.mode.into()),
+    });
+    StructAbi { name: struct_.name().to_string(), fields: fields.collect() }
+}
+
+/// Returns a parameter, with its visibility if it is a parameter of a transition.
+fn input_abi(input: &Input, is_transition: bool, symbol_table: &SymbolTable) -> FieldAbi {
+    let type_ = match input {
+        Input::Internal(input) => abi_type(&input.type_, symbol_table),
+        Input::External(external) => AbiType::ExternalRecord {
+            program: format!("{}.aleo", external.program_name),
+            record: external.record.to_string(),
+        },
+    };
+    FieldAbi { name: input.identifier().to_string(), type_, visibility: is_transition.then(|| input.mode().into()) }
+}
+
+fn abi_type(type_: &Type, symbol_table: &SymbolTable) -> AbiType {
+    match type_ {
+        Type::Array(array) => {
+            AbiType::Array { element: Box::new(abi_type(array.element_type(), symbol_table)), length: array.length() }
+        }
+        Type::Tuple(tuple) => {
+            AbiType::Tuple(tuple.elements().iter().map(|type_| abi_type(type_, symbol_table)).collect())
+        }
+        Type::Identifier(identifier) => match symbol_table.lookup_struct(identifier.name) {
+            Some(struct_) if struct_.is_record => AbiType::Record(identifier.name.to_string()),
+            _ => AbiType::Struct(identifier.name.to_string()),
+        },
+        type_ => AbiType::Primitive(type_.to_string()),
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The results of a compilation, returned to the caller rather than written to disk.
+
+use crate::{pipeline::PassTiming, OutputOptions};
+
+use leo_ast::{normalize_json_value, remove_key_from_json, Ast};
+use leo_errors::{AstError, CompilerError, LeoWarning, Result};
+use leo_passes::{CallGraph, StructGraph, SymbolTable};
+use leo_span::FileSystem;
+
+use indexmap::IndexMap;
+use std::path::Path;
+
+/// Everything the compiler produces for a program.
+/// The caller decides what to persist, e.g., `leo build` writes the bytecode and the requested snapshots.
+#[derive(Debug)]
+pub struct CompilationArtifacts {
+    /// The Aleo instructions of the program.
+    pub bytecode: String,
+    /// The symbol table after the last pass.
+    pub symbol_table: SymbolTable,
+    pub struct_graph: StructGraph,
+    pub call_graph: CallGraph,
+    /// The ASTs requested by [`OutputOptions::ast_snapshots`], by snapshot name, e.g., `initial_ast`.
+    pub asts: IndexMap<&'static str, Ast>,
+    /// The symbol tables requested by [`OutputOptions::symbol_table_snapshots`], by snapshot name,
+    /// e.g., `type_checked_symbol_table`.
+    pub symbol_tables: IndexMap<&'static str, SymbolTable>,
+    /// The warnings emitted while compiling the program.
+    pub warnings: Vec<LeoWarning>,
+    /// The wall time and AST node count of each pass, if timings are enabled.
+    pub timings: Vec<PassTiming>,
+}
+
+impl CompilationArtifacts {
+    /// Returns the JSON snapshots of the ASTs and symbol tables, by file name, e.g., `initial_ast.json`.
+    /// Spans are removed unless they are enabled in `options`.
+    pub fn snapshots(&self, options: &OutputOptions) -> Result<IndexMap<String, String>> {
+        let asts = self.asts.iter().map(|(name, ast)| {
+            Ok((format!("{name}.json"), to_json(ast.to_json_value()?, options.ast_spans_enabled, &["_span", "span"])?))
+        });
+        let symbol_tables = self.symbol_tables.iter().map(|(name, symbol_table)| {
+            let spans_enabled = options.symbol_table_spans_enabled;
+            Ok((format!("{name}.json"), to_json(symbol_table.to_json_value()?, spans_enabled, &["_span", "span"])?))
+        });
+        asts.chain(symbol_tables).collect()
+    }
+
+    /// Writes the snapshots to the files `{program_name}.{file_name}` in `output_directory`.
+    pub fn write_snapshots(
+        &self,
+        file_system: &dyn FileSystem,
+        output_directory: &Path,
+        program_name: &str,
+        options: &OutputOptions,
+    ) -> Result<()> {
+        for (file_name, json) in self.snapshots(options)? {
+            let path = output_directory.join(format!("{program_name}.{file_name}"));
+            file_system.write(&path, &json).map_err(|e| CompilerError::failed_to_write_json(&path, e))?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns `value` as pretty-printed JSON, without the `span_keys` if spans are not enabled.
+pub(crate) fn to_json(mut value: serde_json::Value, spans_enabled: bool, span_keys: &[&str]) -> Result<String> {
+    if !spans_enabled {
+        for key in span_keys {
+            value = remove_key_from_json(value, key);
+        }
+        value = normalize_json_value(value);
+    }
+    Ok(serde_json::to_string_pretty(&value).map_err(|e| AstError::failed_to_convert_ast_to_json_string(&e))?)
+}
@@ -19,24 +19,29 @@
 //! The [`Compiler`] type compiles Leo programs into R1CS circuits.
 pub use leo_ast::{Ast, InputAst};
 use leo_ast::{NodeBuilder, Program};
+use leo_decompiler::Decompiler;
 use leo_errors::{emitter::Handler, CompilerError, Result};
 pub use leo_passes::SymbolTable;
 use leo_passes::*;
 use leo_span::{source_map::FileName, symbol::with_session_globals};
+pub use leo_span::{FileSystem, MemoryFileSystem, RealFileSystem};
 
+use indexmap::IndexMap;
 use sha2::{Digest, Sha256};
-use std::{fs, path::PathBuf};
+use std::{path::PathBuf, time::Instant};
 
-use crate::CompilerOptions;
+use crate::{artifacts::to_json, pipeline::*, CompilationArtifacts, CompilerOptions, ProgramAbi};
 
 /// The primary entry point of the Leo compiler.
 #[derive(Clone)]
 pub struct Compiler<'a> {
     /// The handler is used for error and warning emissions.
     handler: &'a Handler,
+    /// The file system that sources are read from and outputs are written to.
+    file_system: &'a dyn FileSystem,
     /// The path to the main leo file.
     main_file_path: PathBuf,
-    /// The path to where the compiler outputs all generated files.
+    /// The path to where the compiler writes the input AST snapshot.
     output_directory: PathBuf,
     /// The program name,
     pub program_name: String,
@@ -54,6 +59,12 @@ pub struct Compiler<'a> {
     assigner: Assigner,
     /// The type table.
     type_table: TypeTable,
+    /// The wall time and AST node count of each pass that has run, if timings are enabled.
+    timings: Vec<PassTiming>,
+    /// The AST snapshots taken thus far, by snapshot name.
+    asts: IndexMap<&'static str, Ast>,
+    /// The symbol table snapshots taken thus far, by snapshot name.
+    symbol_tables: IndexMap<&'static str, SymbolTable>,
 }
 
 impl<'a> Compiler<'a> {
@@ -71,6 +82,7 @@ impl<'a> Compiler<'a> {
         let type_table = TypeTable::default();
         Self {
             handler,
+            file_system: &RealFileSystem,
             main_file_path,
             output_directory,
             program_name,
@@ -81,13 +93,24 @@ impl<'a> Compiler<'a> {
             node_builder,
             assigner,
             type_table,
+            timings: Vec::new(),
+            asts: IndexMap::new(),
+            symbol_tables: IndexMap::new(),
         }
     }
 
+    /// Reads the sources from and writes the outputs to `file_system` instead of the disk.
+    pub fn with_file_system(mut self, file_system: &'a dyn FileSystem) -> Self {
+        self.file_system = file_system;
+        self
+    }
+
     /// Returns a SHA256 checksum of the program file.
     pub fn checksum(&self) -> Result<String> {
         // Read in the main file as string
-        let unparsed_file = fs::read_to_string(&self.main_file_path)
+        let unparsed_file = self
+            .file_system
+            .read_to_string(&self.main_file_path)
             .map_err(|e| CompilerError::file_read_error(self.main_file_path.clone(), e))?;
 
         // Hash the file contents
@@ -104,7 +127,11 @@ impl<'a> Compiler<'a> {
         let prg_sf = with_session_globals(|s| s.source_map.new_source(program_string, name));
 
         // Use the parser to construct the abstract syntax tree (ast).
-        self.ast = leo_parser::parse_ast(self.handler, &self.node_builder, &prg_sf.src, prg_sf.start_pos)?;
+        self.ast =
+            leo_parser::parse_ast(self.handler, &self.node_builder, self.file_system, &prg_sf.src, prg_sf.start_pos)?;
+
+        // Recover the interfaces of the imported Aleo programs from their bytecode.
+        Self::resolve_stubs(self.file_system, &self.node_builder, &mut self.ast.ast)?;
 
         // If the program is imported, then check that the name of its program scope matches the file name.
         // Note that parsing enforces that there is exactly one program scope in a file.
@@ -120,8 +147,41 @@ impl<'a> Compiler<'a> {
             .into());
         }
 
-        if self.compiler_options.output.initial_ast {
-            self.write_ast_to_json("initial_ast.json")?;
+        if self.compiler_options.output.ast_snapshots.contains(PARSING) {
+            self.asts.insert("initial_ast", self.ast.clone());
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the stub of each Aleo program imported by `program`, or by its imports, with the interface
+    /// decompiled from its bytecode in the `imports` directory.
+    pub fn resolve_stubs(
+        file_system: &dyn FileSystem,
+        node_builder: &NodeBuilder,
+        program: &mut Program,
+    ) -> Result<()> {
+        for (import, _) in program.imports.values_mut() {
+            Self::resolve_stubs(file_system, node_builder, import)?;
+        }
+
+        for (name, stub) in program.stubs.iter_mut() {
+            let mut import_file_path =
+                file_system.current_dir().map_err(|err| CompilerError::cannot_open_cwd(err, stub.span))?;
+            import_file_path.push("imports");
+            import_file_path.push(format!("{name}.aleo"));
+
+            // Throw an error if the import file doesn't exist.
+            if !file_system.exists(&import_file_path) {
+                return Err(CompilerError::import_not_found(import_file_path.display(), stub.span).into());
+            }
+
+            let bytecode = file_system
+                .read_to_string(&import_file_path)
+                .map_err(|e| CompilerError::file_read_error(&import_file_path, e))?;
+            let span = stub.span;
+            *stub = Decompiler::stub(&bytecode, node_builder)?;
+            stub.span = span;
         }
 
         Ok(())
@@ -130,7 +190,9 @@ impl<'a> Compiler<'a> {
     /// Parses and stores the main program file, constructs a syntax tree, and generates a program.
     pub fn parse_program(&mut self) -> Result<()> {
         // Load the program file.
-        let program_string = fs::read_to_string(&self.main_file_path)
+        let program_string = self
+            .file_system
+            .read_to_string(&self.main_file_path)
             .map_err(|e| CompilerError::file_read_error(&self.main_file_path, e))?;
 
         self.parse_program_from_string(&program_string, FileName::Real(self.main_file_path.clone()))
@@ -138,28 +200,24 @@ impl<'a> Compiler<'a> {
 
     /// Parses and stores the input file, constructs a syntax tree, and generates a program input.
     pub fn parse_input(&mut self, input_file_path: PathBuf) -> Result<()> {
-        if input_file_path.exists() {
+        if self.file_system.exists(&input_file_path) {
             // Load the input file into the source map.
-            let input_sf = with_session_globals(|s| s.source_map.load_file(&input_file_path))
+            let input_string = self
+                .file_system
+                .read_to_string(&input_file_path)
                 .map_err(|e| CompilerError::file_read_error(&input_file_path, e))?;
+            let input_sf =
+                with_session_globals(|s| s.source_map.new_source(&input_string, FileName::Real(input_file_path)));
 
             // Parse and serialize it.
             let input_ast =
                 leo_parser::parse_input(self.handler, &self.node_builder, &input_sf.src, input_sf.start_pos)?;
-            if self.compiler_options.output.initial_ast {
+            if self.compiler_options.output.ast_snapshots.contains(PARSING) {
                 // Write the input AST snapshot post parsing.
-                if self.compiler_options.output.ast_spans_enabled {
-                    input_ast.to_json_file(
-                        self.output_directory.clone(),
-                        &format!("{}.initial_input_ast.json", self.program_name),
-                    )?;
-                } else {
-                    input_ast.to_json_file_without_keys(
-                        self.output_directory.clone(),
-                        &format!("{}.initial_input_ast.json", self.program_name),
-                        &["span"],
-                    )?;
-                }
+                let path = self.output_directory.join(format!("{}.initial_input_ast.json", self.program_name));
+                let spans_enabled = self.compiler_options.output.ast_spans_enabled;
+                let json = to_json(input_ast.to_json_value()?, spans_enabled, &["span"])?;
+                self.file_system.write(&path, &json).map_err(|e| CompilerError::failed_to_write_json(&path, e))?;
             }
 
             self.input_ast = Some(input_ast);
@@ -168,24 +226,34 @@ impl<'a> Compiler<'a> {
     }
 
     /// Runs the symbol table pass.
-    pub fn symbol_table_pass(&self) -> Result<SymbolTable> {
+    pub fn symbol_table_pass(&mut self) -> Result<SymbolTable> {
         let symbol_table = SymbolTableCreator::do_pass((&self.ast, self.handler))?;
-        if self.compiler_options.output.initial_symbol_table {
-            self.write_symbol_table_to_json("initial_symbol_table.json", &symbol_table)?;
-        }
+        self.take_symbol_table_snapshot("symbol_table", &symbol_table)?;
         Ok(symbol_table)
     }
 
     /// Runs the type checker pass.
-    pub fn type_checker_pass(&'a self, symbol_table: SymbolTable) -> Result<(SymbolTable, StructGraph, CallGraph)> {
+    pub fn type_checker_pass(&mut self, symbol_table: SymbolTable) -> Result<(SymbolTable, StructGraph, CallGraph)> {
         let (symbol_table, struct_graph, call_graph) =
             TypeChecker::do_pass((&self.ast, self.handler, symbol_table, &self.type_table))?;
-        if self.compiler_options.output.type_checked_symbol_table {
-            self.write_symbol_table_to_json("type_checked_symbol_table.json", &symbol_table)?;
-        }
+        self.take_symbol_table_snapshot("type_checking", &symbol_table)?;
         Ok((symbol_table, struct_graph, call_graph))
     }
 
+    /// Runs the pruning pass.
+    pub fn pruning_pass(
+        &mut self,
+        symbol_table: &mut SymbolTable,
+        struct_graph: &mut StructGraph,
+        call_graph: &mut CallGraph,
+    ) -> Result<()> {
+        Pruner::do_pass((&mut self.ast, self.handler, symbol_table, struct_graph, call_graph))?;
+
+        self.take_ast_snapshot("pruning")?;
+
+        Ok(())
+    }
+
     /// Runs the loop unrolling pass.
     pub fn loop_unrolling_pass(&mut self, symbol_table: SymbolTable) -> Result<SymbolTable> {
         let (ast, symbol_table) = Unroller::do_pass((
@@ -197,13 +265,8 @@ impl<'a> Compiler<'a> {
         ))?;
         self.ast = ast;
 
-        if self.compiler_options.output.unrolled_ast {
-            self.write_ast_to_json("unrolled_ast.json")?;
-        }
-
-        if self.compiler_options.output.unrolled_symbol_table {
-            self.write_symbol_table_to_json("unrolled_symbol_table.json", &symbol_table)?;
-        }
+        self.take_ast_snapshot("loop_unrolling")?;
+        self.take_symbol_table_snapshot("loop_unrolling", &symbol_table)?;
 
         Ok(symbol_table)
     }
@@ -218,9 +281,7 @@ impl<'a> Compiler<'a> {
             &self.type_table,
         ))?;
 
-        if self.compiler_options.output.ssa_ast {
-            self.write_ast_to_json("ssa_ast.json")?;
-        }
+        self.take_ast_snapshot("static_single_assignment")?;
 
         Ok(())
     }
@@ -235,43 +296,34 @@ impl<'a> Compiler<'a> {
             &self.assigner,
         ))?;
 
-        if self.compiler_options.output.flattened_ast {
-            self.write_ast_to_json("flattened_ast.json")?;
-        }
+        self.take_ast_snapshot("flattening")?;
 
         Ok(())
     }
 
     /// Runs the destructuring pass.
     pub fn destructuring_pass(&mut self) -> Result<()> {
-        self.ast = Destructurer::do_pass((
-            std::mem::take(&mut self.ast),
-            &self.type_table,
-            &self.node_builder,
-            &self.assigner,
-        ))?;
+        Destructurer::do_pass((&mut self.ast, &self.type_table, &self.node_builder, &self.assigner))?;
 
-        if self.compiler_options.output.destructured_ast {
-            self.write_ast_to_json("destructured_ast.json")?;
-        }
+        self.take_ast_snapshot("destructuring")?;
 
         Ok(())
     }
 
     /// Runs the function inlining pass.
     pub fn function_inlining_pass(&mut self, call_graph: &CallGraph) -> Result<()> {
-        let ast = FunctionInliner::do_pass((
-            std::mem::take(&mut self.ast),
-            &self.node_builder,
-            call_graph,
-            &self.assigner,
-            &self.type_table,
-        ))?;
-        self.ast = ast;
+        FunctionInliner::do_pass((&mut self.ast, &self.node_builder, call_graph, &self.assigner, &self.type_table))?;
 
-        if self.compiler_options.output.inlined_ast {
-            self.write_ast_to_json("inlined_ast.json")?;
-        }
+        self.take_ast_snapshot("function_inlining")?;
+
+        Ok(())
+    }
+
+    /// Runs the common subexpression elimination pass.
+    pub fn common_subexpression_elimination_pass(&mut self, symbol_table: &SymbolTable) -> Result<()> {
+        CommonSubexpressionEliminator::do_pass((&mut self.ast, &self.node_builder, symbol_table, &self.type_table))?;
+
+        self.take_ast_snapshot("common_subexpression_elimination")?;
 
         Ok(())
     }
@@ -279,12 +331,10 @@ impl<'a> Compiler<'a> {
     /// Runs the dead code elimination pass.
     pub fn dead_code_elimination_pass(&mut self) -> Result<()> {
         if self.compiler_options.build.dce_enabled {
-            self.ast = DeadCodeEliminator::do_pass((std::mem::take(&mut self.ast), &self.node_builder))?;
+            DeadCodeEliminator::do_pass((&mut self.ast, &self.node_builder))?;
         }
 
-        if self.compiler_options.output.dce_ast {
-            self.write_ast_to_json("dce_ast.json")?;
-        }
+        self.take_ast_snapshot("dead_code_elimination")?;
 
         Ok(())
     }
@@ -299,65 +349,125 @@ impl<'a> Compiler<'a> {
         CodeGenerator::do_pass((&self.ast, symbol_table, &self.type_table, struct_graph, call_graph, &self.ast.ast))
     }
 
-    /// Runs the compiler stages.
+    /// Runs the compiler stages selected by the compiler options.
     pub fn compiler_stages(&mut self) -> Result<(SymbolTable, StructGraph, CallGraph)> {
-        let st = self.symbol_table_pass()?;
-        let (st, struct_graph, call_graph) = self.type_checker_pass(st)?;
-
-        // TODO: Make this pass optional.
-        let st = self.loop_unrolling_pass(st)?;
+        let passes = select_passes(&self.compiler_options)?;
+        self.run_passes(&passes)
+    }
 
-        self.static_single_assignment_pass(&st)?;
+    /// Runs the given passes, after checking that their preconditions and those of code generation hold.
+    fn run_passes(&mut self, passes: &[&'static PassInfo]) -> Result<(SymbolTable, StructGraph, CallGraph)> {
+        // Check the whole pipeline before running any pass.
+        // Only `loop_unrolling` removes loops and constants, so it suffices to check the parsed program.
+        let mut ran = Vec::with_capacity(passes.len());
+        for pass in passes {
+            if let Some(precondition) = unmet_precondition(pass.preconditions, &ran, &self.ast.ast) {
+                return Err(CompilerError::pass_precondition_not_met(pass.name, precondition).into());
+            }
+            ran.push(pass.name);
+        }
+        if let Some(precondition) = unmet_precondition(CODE_GENERATION_PRECONDITIONS, &ran, &self.ast.ast) {
+            return Err(CompilerError::pass_precondition_not_met("code_generation", precondition).into());
+        }
 
-        self.flattening_pass(&st)?;
+        // Node IDs are unique after parsing, and every pass keeps them unique.
+        // Each pass also establishes further invariants for the passes after it.
+        let validate = cfg!(debug_assertions) || self.compiler_options.build.validate_ir;
+        let mut invariants = vec![Invariant::UniqueNodeIds];
+        if validate {
+            IrValidator::do_pass((&self.ast, self.handler, &invariants, PARSING))?;
+        }
 
-        self.destructuring_pass()?;
+        let mut state = PipelineState::default();
+        for pass in passes {
+            let start = Instant::now();
+            (pass.run)(self, &mut state)?;
+            self.record_timing(pass.name, start);
 
-        self.function_inlining_pass(&call_graph)?;
+            invariants.extend(pass.invariants);
+            if validate {
+                IrValidator::do_pass((&self.ast, self.handler, &invariants, pass.name))?;
+            }
+        }
 
-        self.dead_code_elimination_pass()?;
+        let expect = "The preconditions of code generation ensure that type checking has run.";
+        Ok((state.symbol_table.expect(expect), state.struct_graph.expect(expect), state.call_graph.expect(expect)))
+    }
 
-        Ok((st, struct_graph, call_graph))
+    /// Returns the ABI of the compiled program, given the symbol table returned by [`Compiler::compile`].
+    pub fn abi(&self, symbol_table: &SymbolTable) -> ProgramAbi {
+        ProgramAbi::new(&self.ast.ast, symbol_table)
     }
 
-    /// Returns a compiled Leo program.
-    pub fn compile(&mut self) -> Result<(SymbolTable, String)> {
+    /// Parses and compiles the main program file.
+    pub fn compile(&mut self) -> Result<CompilationArtifacts> {
+        // Select the passes before parsing, so that invalid options are reported first.
+        let passes = select_passes(&self.compiler_options)?;
+        let warning_count = self.handler.warning_count();
         // Parse the program.
         self.parse_program()?;
+        self.compile_parsed_program(&passes, warning_count)
+    }
+
+    /// Parses and compiles a program from a string, e.g., an in-memory source, registering it as `name`.
+    pub fn compile_from_string(&mut self, program_string: &str, name: FileName) -> Result<CompilationArtifacts> {
+        let passes = select_passes(&self.compiler_options)?;
+        let warning_count = self.handler.warning_count();
+        self.parse_program_from_string(program_string, name)?;
+        self.compile_parsed_program(&passes, warning_count)
+    }
+
+    /// Runs the given passes and code generation on the parsed program.
+    /// The artifacts include the warnings emitted after the first `warning_count` ones.
+    fn compile_parsed_program(
+        &mut self,
+        passes: &[&'static PassInfo],
+        warning_count: usize,
+    ) -> Result<CompilationArtifacts> {
         // Run the intermediate compiler stages.
-        let (symbol_table, struct_graph, call_graph) = self.compiler_stages()?;
+        let (symbol_table, struct_graph, call_graph) = self.run_passes(passes)?;
         // Run code generation.
+        let start = Instant::now();
         let bytecode = self.code_generation_pass(&symbol_table, &struct_graph, &call_graph)?;
-        Ok((symbol_table, bytecode))
+        self.record_timing("code_generation", start);
+
+        Ok(CompilationArtifacts {
+            bytecode,
+            symbol_table,
+            struct_graph,
+            call_graph,
+            asts: std::mem::take(&mut self.asts),
+            symbol_tables: std::mem::take(&mut self.symbol_tables),
+            warnings: self.handler.warnings().split_off(warning_count),
+            timings: std::mem::take(&mut self.timings),
+        })
+    }
+
+    /// Records the wall time of a pass started at `start`, if timings are enabled.
+    fn record_timing(&mut self, name: &'static str, start: Instant) {
+        if self.compiler_options.output.timings {
+            let duration = start.elapsed();
+            let nodes = count_nodes(&self.ast.ast);
+            self.timings.push(PassTiming { name, duration, nodes });
+        }
     }
 
-    /// Writes the AST to a JSON file.
-    fn write_ast_to_json(&self, file_suffix: &str) -> Result<()> {
-        // Remove `Span`s if they are not enabled.
-        if self.compiler_options.output.ast_spans_enabled {
-            self.ast.to_json_file(self.output_directory.clone(), &format!("{}.{file_suffix}", self.program_name))?;
-        } else {
-            self.ast.to_json_file_without_keys(
-                self.output_directory.clone(),
-                &format!("{}.{file_suffix}", self.program_name),
-                &["_span", "span"],
-            )?;
+    /// Takes the AST snapshot of the pass `name`, if it was requested.
+    fn take_ast_snapshot(&mut self, name: &str) -> Result<()> {
+        if self.compiler_options.output.ast_snapshots.contains(name) {
+            if let Some(snapshot) = find_pass(name)?.ast_snapshot {
+                self.asts.insert(snapshot, self.ast.clone());
+            }
         }
         Ok(())
     }
 
-    /// Writes the Symbol Table to a JSON file.
-    fn write_symbol_table_to_json(&self, file_suffix: &str, symbol_table: &SymbolTable) -> Result<()> {
-        // Remove `Span`s if they are not enabled.
-        if self.compiler_options.output.symbol_table_spans_enabled {
-            symbol_table
-                .to_json_file(self.output_directory.clone(), &format!("{}.{file_suffix}", self.program_name))?;
-        } else {
-            symbol_table.to_json_file_without_keys(
-                self.output_directory.clone(),
-                &format!("{}.{file_suffix}", self.program_name),
-                &["_span", "span"],
-            )?;
+    /// Takes the symbol table snapshot of the pass `name`, if it was requested.
+    fn take_symbol_table_snapshot(&mut self, name: &str, symbol_table: &SymbolTable) -> Result<()> {
+        if self.compiler_options.output.symbol_table_snapshots.contains(name) {
+            if let Some(snapshot) = find_pass(name)?.symbol_table_snapshot {
+                self.symbol_tables.insert(snapshot, symbol_table.clone());
+            }
         }
         Ok(())
     }
@@ -19,8 +19,16 @@
 #![allow(clippy::upper_case_acronyms)]
 #![doc = include_str!("../README.md")]
 
+pub mod abi;
+pub use abi::ProgramAbi;
+
+mod artifacts;
+pub use artifacts::*;
+
 mod compiler;
 pub use compiler::*;
 
 mod options;
 pub use options::*;
+
+pub mod pipeline;
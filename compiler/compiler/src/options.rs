@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-// NOTE: If compiler passes are made optional, pass preconditions and invariants may not necessarily hold true.
+// NOTE: Passes can be selected or skipped by name. Their preconditions are checked before any pass is run.
 
 #[derive(Clone, Default)]
 pub struct CompilerOptions {
@@ -24,38 +24,51 @@ pub struct CompilerOptions {
     pub output: OutputOptions,
 }
 
-#[derive(Clone, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct BuildOptions {
     /// Whether to enable dead code elimination.
     pub dce_enabled: bool,
+    /// The passes to run, in order. If empty, the default pipeline is used.
+    pub passes: Vec<String>,
+    /// The passes to remove from the pipeline.
+    pub skipped_passes: Vec<String>,
+    /// Whether to check the invariants of the AST after each pass. They are always checked in debug builds.
+    pub validate_ir: bool,
 }
 
 #[derive(Clone, Default)]
 pub struct OutputOptions {
     //// Whether spans are enabled in the output symbol tables.
     pub symbol_table_spans_enabled: bool,
-    // If enabled writes the symbol table after symbol table pass
-    pub initial_symbol_table: bool,
-    /// If enabled writes the symbol table after type checking.
-    pub type_checked_symbol_table: bool,
-    /// If enabled writes the symbol table after loop unrolling.
-    pub unrolled_symbol_table: bool,
+    /// The passes after which the symbol table is written.
+    pub symbol_table_snapshots: Snapshots,
     /// Whether spans are enabled in the output ASTs.
     pub ast_spans_enabled: bool,
-    /// If enabled writes the AST after parsing.
-    pub initial_ast: bool,
-    /// If enabled writes the input AST after parsing.
-    pub initial_input_ast: bool,
-    /// If enabled writes the AST after loop unrolling.
-    pub unrolled_ast: bool,
-    /// If enabled writes the AST after static single assignment.
-    pub ssa_ast: bool,
-    /// If enabled writes the AST after flattening.
-    pub flattened_ast: bool,
-    /// If enabled writes the AST after destructuring.
-    pub destructured_ast: bool,
-    /// If enabled writes the AST after inlining.
-    pub inlined_ast: bool,
-    /// If enabled writes the AST after dead code elimination.
-    pub dce_ast: bool,
+    /// The passes after which the AST is written. `parsing` writes the AST and input AST after parsing.
+    pub ast_snapshots: Snapshots,
+    /// Whether to record the wall time and AST node count of each pass.
+    pub timings: bool,
+}
+
+/// The passes after which a snapshot is written, by name.
+#[derive(Clone, Debug, Default)]
+pub enum Snapshots {
+    /// No snapshots are written.
+    #[default]
+    None,
+    /// A snapshot is written after every pass that produces one.
+    All,
+    /// A snapshot is written after each of the named passes.
+    Passes(Vec<String>),
+}
+
+impl Snapshots {
+    /// Returns whether a snapshot should be written after the pass `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        match self {
+            Snapshots::None => false,
+            Snapshots::All => true,
+            Snapshots::Passes(passes) => passes.iter().any(|pass| pass == name),
+        }
+    }
 }
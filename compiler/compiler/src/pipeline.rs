@@ -0,0 +1,435 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The compiler pipeline: the passes run between parsing and code generation, registered by name.
+//!
+//! Each pass declares the preconditions it relies on. A pipeline is checked against these preconditions,
+//! and against those of code generation, before any pass is run.
+
+use crate::{CompilerOptions, Snapshots};
+
+use leo_ast::{
+    AssignStatement,
+    ConstDeclaration,
+    DefinitionStatement,
+    Expression,
+    ExpressionVisitor,
+    Function,
+    IterationStatement,
+    Mapping,
+    Program,
+    ProgramVisitor,
+    Statement,
+    StatementVisitor,
+    Struct,
+    StructExpression,
+    Stub,
+};
+use leo_errors::{CompilerError, Result};
+use leo_passes::{CallGraph, Invariant, StructGraph, SymbolTable};
+
+use std::{fmt, time::Duration};
+
+/// The name under which the snapshots taken after parsing are requested.
+pub const PARSING: &str = "parsing";
+
+/// A condition that must hold before a pass is run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Precondition {
+    /// The named pass has already run.
+    Ran(&'static str),
+    /// The program contains no loops or constants, either because it has none or because `loop_unrolling` has run.
+    Unrolled,
+}
+
+impl fmt::Display for Precondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Precondition::Ran(pass) => write!(f, "the `{pass}` pass must run before it"),
+            Precondition::Unrolled => {
+                write!(f, "the program contains loops or constants, so the `loop_unrolling` pass must run before it")
+            }
+        }
+    }
+}
+
+/// The results of earlier passes that later passes depend on.
+#[derive(Default)]
+pub struct PipelineState {
+    /// The symbol table, once the `symbol_table` pass has run.
+    pub symbol_table: Option<SymbolTable>,
+    /// The struct graph, once the `type_checking` pass has run.
+    pub struct_graph: Option<StructGraph>,
+    /// The call graph, once the `type_checking` pass has run.
+    pub call_graph: Option<CallGraph>,
+}
+
+impl PipelineState {
+    fn symbol_table(&self) -> &SymbolTable {
+        self.symbol_table.as_ref().expect("The `symbol_table` pass is a precondition of this pass.")
+    }
+
+    fn call_graph(&self) -> &CallGraph {
+        self.call_graph.as_ref().expect("The `type_checking` pass is a precondition of this pass.")
+    }
+}
+
+/// The signature of the function that runs a registered pass.
+pub type RunPass = fn(&mut crate::Compiler<'_>, &mut PipelineState) -> Result<()>;
+
+/// A compiler pass registered with the pipeline.
+pub struct PassInfo {
+    /// The name of the pass, as used by `--passes`, `--skip-pass`, and the snapshot options.
+    pub name: &'static str,
+    /// A short description of the pass.
+    pub description: &'static str,
+    /// The conditions that must hold before the pass is run.
+    pub preconditions: &'static [Precondition],
+    /// The suffix of the AST snapshot written after the pass, if the pass transforms the AST.
+    pub ast_snapshot: Option<&'static str>,
+    /// The suffix of the symbol table snapshot written after the pass, if the pass produces a symbol table.
+    pub symbol_table_snapshot: Option<&'static str>,
+    /// The invariants of the AST established by the pass, which hold for the rest of the pipeline.
+    pub invariants: &'static [Invariant],
+    /// Runs the pass.
+    pub run: RunPass,
+}
+
+/// The registered passes, in the order of the default pipeline.
+pub static PASSES: &[PassInfo] = &[
+    PassInfo {
+        name: "symbol_table",
+        description: "Creates the symbol table of the program and its imports.",
+        preconditions: &[],
+        ast_snapshot: None,
+        symbol_table_snapshot: Some("initial_symbol_table"),
+        invariants: &[],
+        run: |compiler, state| {
+            state.symbol_table = Some(compiler.symbol_table_pass()?);
+            Ok(())
+        },
+    },
+    PassInfo {
+        name: "type_checking",
+        description: "Type checks the program and builds its struct and call graphs.",
+        preconditions: &[Precondition::Ran("symbol_table")],
+        ast_snapshot: None,
+        symbol_table_snapshot: Some("type_checked_symbol_table"),
+        invariants: &[],
+        run: |compiler, state| {
+            let symbol_table =
+                state.symbol_table.take().expect("The `symbol_table` pass is a precondition of this pass.");
+            let (symbol_table, struct_graph, call_graph) = compiler.type_checker_pass(symbol_table)?;
+            state.symbol_table = Some(symbol_table);
+            state.struct_graph = Some(struct_graph);
+            state.call_graph = Some(call_graph);
+            Ok(())
+        },
+    },
+    PassInfo {
+        name: "pruning",
+        description: "Removes the functions, structs, and constants that no transition uses, and warns about them.",
+        preconditions: &[Precondition::Ran("type_checking")],
+        ast_snapshot: Some("pruned_ast"),
+        symbol_table_snapshot: None,
+        invariants: &[],
+        run: |compiler, state| {
+            let (Some(symbol_table), Some(struct_graph), Some(call_graph)) =
+                (state.symbol_table.as_mut(), state.struct_graph.as_mut(), state.call_graph.as_mut())
+            else {
+                unreachable!("The `type_checking` pass is a precondition of this pass.")
+            };
+            compiler.pruning_pass(symbol_table, struct_graph, call_graph)
+        },
+    },
+    PassInfo {
+        name: "loop_unrolling",
+        description: "Unrolls loops and propagates constants.",
+        preconditions: &[Precondition::Ran("type_checking")],
+        ast_snapshot: Some("unrolled_ast"),
+        symbol_table_snapshot: Some("unrolled_symbol_table"),
+        invariants: &[],
+        run: |compiler, state| {
+            let symbol_table =
+                state.symbol_table.take().expect("The `symbol_table` pass is a precondition of this pass.");
+            state.symbol_table = Some(compiler.loop_unrolling_pass(symbol_table)?);
+            Ok(())
+        },
+    },
+    PassInfo {
+        name: "static_single_assignment",
+        description: "Converts the program into static single assignment form.",
+        preconditions: &[Precondition::Ran("type_checking"), Precondition::Unrolled],
+        ast_snapshot: Some("ssa_ast"),
+        symbol_table_snapshot: None,
+        invariants: &[Invariant::SsaForm],
+        run: |compiler, state| compiler.static_single_assignment_pass(state.symbol_table()),
+    },
+    PassInfo {
+        name: "flattening",
+        description: "Flattens conditional statements into a sequence of assignments.",
+        preconditions: &[Precondition::Ran("static_single_assignment")],
+        ast_snapshot: Some("flattened_ast"),
+        symbol_table_snapshot: None,
+        invariants: &[Invariant::NoConditionals],
+        run: |compiler, state| compiler.flattening_pass(state.symbol_table()),
+    },
+    PassInfo {
+        name: "destructuring",
+        description: "Destructures tuples into individual variables.",
+        preconditions: &[Precondition::Ran("flattening")],
+        ast_snapshot: Some("destructured_ast"),
+        symbol_table_snapshot: None,
+        invariants: &[Invariant::NoTuples],
+        run: |compiler, _| compiler.destructuring_pass(),
+    },
+    PassInfo {
+        name: "function_inlining",
+        description: "Inlines `inline` functions at their call sites.",
+        preconditions: &[Precondition::Ran("type_checking"), Precondition::Ran("flattening")],
+        ast_snapshot: Some("inlined_ast"),
+        symbol_table_snapshot: None,
+        invariants: &[Invariant::NoInlineCalls],
+        run: |compiler, state| compiler.function_inlining_pass(state.call_graph()),
+    },
+    PassInfo {
+        name: "common_subexpression_elimination",
+        description: "Reuses the values of repeated pure expressions.",
+        preconditions: &[Precondition::Ran("function_inlining")],
+        ast_snapshot: Some("cse_ast"),
+        symbol_table_snapshot: None,
+        invariants: &[],
+        run: |compiler, state| compiler.common_subexpression_elimination_pass(state.symbol_table()),
+    },
+    PassInfo {
+        name: "dead_code_elimination",
+        description: "Removes unused assignments, if dead code elimination is enabled.",
+        preconditions: &[Precondition::Ran("function_inlining")],
+        ast_snapshot: Some("dce_ast"),
+        symbol_table_snapshot: None,
+        invariants: &[],
+        run: |compiler, _| compiler.dead_code_elimination_pass(),
+    },
+];
+
+/// The conditions that must hold before code generation.
+pub const CODE_GENERATION_PRECONDITIONS: &[Precondition] = &[
+    Precondition::Ran("type_checking"),
+    Precondition::Unrolled,
+    Precondition::Ran("destructuring"),
+    Precondition::Ran("function_inlining"),
+];
+
+/// Returns the registered pass with the given name.
+pub fn find_pass(name: &str) -> Result<&'static PassInfo> {
+    PASSES.iter().find(|pass| pass.name == name).ok_or_else(|| {
+        CompilerError::unknown_pass(name, PASSES.iter().map(|pass| pass.name).collect::<Vec<_>>().join(", ")).into()
+    })
+}
+
+/// Returns the passes selected by the options, after checking the requested snapshots.
+/// If no passes are given, the default pipeline is used, without any skipped passes.
+pub fn select_passes(options: &CompilerOptions) -> Result<Vec<&'static PassInfo>> {
+    let mut passes = match options.build.passes.is_empty() {
+        true => PASSES.iter().collect(),
+        false => options.build.passes.iter().map(|name| find_pass(name)).collect::<Result<Vec<_>>>()?,
+    };
+    for name in options.build.skipped_passes.iter() {
+        let skipped = find_pass(name)?;
+        passes.retain(|pass| pass.name != skipped.name);
+    }
+
+    // The AST is the only snapshot taken after parsing.
+    check_snapshots(&options.output.ast_snapshots, "AST", true, |pass| pass.ast_snapshot)?;
+    check_snapshots(&options.output.symbol_table_snapshots, "symbol table", false, |pass| pass.symbol_table_snapshot)?;
+
+    Ok(passes)
+}
+
+/// Checks that each of the named passes produces a snapshot of the given kind.
+/// `after_parsing` is whether a snapshot of this kind is also taken after parsing.
+fn check_snapshots(
+    snapshots: &Snapshots,
+    kind: &str,
+    after_parsing: bool,
+    snapshot: impl Fn(&PassInfo) -> Option<&'static str>,
+) -> Result<()> {
+    if let Snapshots::Passes(names) = snapshots {
+        for name in names.iter() {
+            let has_snapshot = match name.as_str() {
+                PARSING => after_parsing,
+                _ => snapshot(find_pass(name)?).is_some(),
+            };
+            if !has_snapshot {
+                return Err(CompilerError::pass_has_no_snapshot(name, kind).into());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the first precondition that does not hold, given the passes that have run on `program`.
+pub fn unmet_precondition(
+    preconditions: &[Precondition],
+    ran: &[&'static str],
+    program: &Program,
+) -> Option<Precondition> {
+    preconditions.iter().copied().find(|precondition| match precondition {
+        Precondition::Ran(pass) => !ran.contains(pass),
+        Precondition::Unrolled => !ran.contains(&"loop_unrolling") && LoopFinder::contains_loops(program),
+    })
+}
+
+/// The wall time of a pass and the number of AST nodes after it.
+#[derive(Clone, Debug)]
+pub struct PassTiming {
+    /// The name of the pass.
+    pub name: &'static str,
+    /// The wall time of the pass.
+    pub duration: Duration,
+    /// The number of AST nodes after the pass.
+    pub nodes: usize,
+}
+
+impl fmt::Display for PassTiming {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:<26} {:>10.3?} {:>8} nodes", self.name, self.duration, self.nodes)
+    }
+}
+
+/// Returns the number of nodes in `program`, i.e., its expressions, statements, and program items.
+pub fn count_nodes(program: &Program) -> usize {
+    let mut counter = NodeCounter::default();
+    counter.visit_program(program);
+    counter.nodes
+}
+
+/// Counts the expressions, statements, and program items that it visits.
+#[derive(Default)]
+struct NodeCounter {
+    nodes: usize,
+}
+
+impl<'a> ExpressionVisitor<'a> for NodeCounter {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_expression(&mut self, input: &'a Expression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.nodes += 1;
+        match input {
+            Expression::Access(access) => self.visit_access(access, additional),
+            Expression::Array(array) => self.visit_array(array, additional),
+            Expression::Binary(binary) => self.visit_binary(binary, additional),
+            Expression::Call(call) => self.visit_call(call, additional),
+            Expression::Cast(cast) => self.visit_cast(cast, additional),
+            Expression::Struct(struct_) => self.visit_struct_init(struct_, additional),
+            Expression::Err(err) => self.visit_err(err, additional),
+            Expression::Identifier(identifier) => self.visit_identifier(identifier, additional),
+            Expression::Literal(literal) => self.visit_literal(literal, additional),
+            Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
+            Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
+            Expression::Unary(unary) => self.visit_unary(unary, additional),
+            Expression::Unit(unit) => self.visit_unit(unit, additional),
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().filter_map(|member| member.expression.as_ref()).for_each(|expression| {
+            self.visit_expression(expression, additional);
+        });
+    }
+}
+
+impl<'a> StatementVisitor<'a> for NodeCounter {
+    fn visit_statement(&mut self, input: &'a Statement) {
+        self.nodes += 1;
+        match input {
+            Statement::Assert(stmt) => self.visit_assert(stmt),
+            Statement::Assign(stmt) => self.visit_assign(stmt),
+            Statement::Block(stmt) => self.visit_block(stmt),
+            Statement::Conditional(stmt) => self.visit_conditional(stmt),
+            Statement::Console(stmt) => self.visit_console(stmt),
+            Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Definition(stmt) => self.visit_definition(stmt),
+            Statement::Expression(stmt) => self.visit_expression_statement(stmt),
+            Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Return(stmt) => self.visit_return(stmt),
+        }
+    }
+
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.place, &Default::default());
+        self.visit_expression(&input.value, &Default::default());
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.visit_expression(&input.place, &Default::default());
+        self.visit_expression(&input.value, &Default::default());
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for NodeCounter {
+    fn visit_stub(&mut self, input: &'a Stub) {
+        self.nodes += input.structs.len() + input.mappings.len() + input.functions.len();
+    }
+
+    fn visit_struct(&mut self, _input: &'a Struct) {
+        self.nodes += 1;
+    }
+
+    fn visit_mapping(&mut self, _input: &'a Mapping) {
+        self.nodes += 1;
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.nodes += 1;
+        self.visit_block(&input.block);
+        if let Some(finalize) = &input.finalize {
+            self.visit_block(&finalize.block);
+        }
+    }
+}
+
+/// Finds the loops and constants that the `loop_unrolling` pass removes.
+#[derive(Default)]
+struct LoopFinder {
+    found: bool,
+}
+
+impl LoopFinder {
+    fn contains_loops(program: &Program) -> bool {
+        let mut finder = LoopFinder::default();
+        finder.visit_program(program);
+        finder.found
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for LoopFinder {
+    type AdditionalInput = ();
+    type Output = ();
+}
+
+impl<'a> StatementVisitor<'a> for LoopFinder {
+    fn visit_const(&mut self, _input: &'a ConstDeclaration) {
+        self.found = true;
+    }
+
+    fn visit_iteration(&mut self, _input: &'a IterationStatement) {
+        self.found = true;
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for LoopFinder {}
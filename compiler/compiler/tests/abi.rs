@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::{abi::*, Compiler};
+use leo_errors::emitter::Handler;
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+const TOKEN: &str = r#"
+program token.aleo {
+    struct Point {
+        x: i8,
+        y: i8,
+    }
+
+    record Token {
+        owner: address,
+        public amount: u64,
+        points: [Point; 2],
+    }
+
+    mapping balances: address => u64;
+
+    transition mint(receiver: address, public amount: u64) -> (Token, public u64) {
+        let origin: Point = Point { x: 0i8, y: 0i8 };
+        return (Token { owner: receiver, amount, points: [origin, origin] }, amount) then finalize(receiver, amount);
+    }
+
+    finalize mint(receiver: address, amount: u64) {
+        let balance: u64 = Mapping::get_or_use(balances, receiver, 0u64);
+        Mapping::set(balances, receiver, balance + amount);
+    }
+
+    function helper(a: u64) -> u64 {
+        return a;
+    }
+}
+"#;
+
+/// Type checks `source` and returns its ABI.
+fn abi(source: &str) -> ProgramAbi {
+    let handler = Handler::default();
+    let mut compiler =
+        Compiler::new("token".to_string(), "aleo".to_string(), &handler, Default::default(), Default::default(), None);
+    compiler.parse_program_from_string(source, FileName::Custom("token".into())).unwrap();
+    let (symbol_table, _, _) = compiler.compiler_stages().unwrap();
+    compiler.abi(&symbol_table)
+}
+
+fn field(name: &str, type_: AbiType, visibility: Option<Visibility>) -> FieldAbi {
+    FieldAbi { name: name.to_string(), type_, visibility }
+}
+
+fn primitive(name: &str) -> AbiType {
+    AbiType::Primitive(name.to_string())
+}
+
+#[test]
+fn program_abi() {
+    create_session_if_not_set_then(|_| {
+        let abi = abi(TOKEN);
+        assert_eq!(abi.program, "token.aleo");
+        assert_eq!(abi.structs, vec![StructAbi {
+            name: "Point".to_string(),
+            fields: vec![field("x", primitive("i8"), None), field("y", primitive("i8"), None)],
+        }]);
+        assert_eq!(abi.records, vec![StructAbi {
+            name: "Token".to_string(),
+            fields: vec![
+                field("owner", primitive("address"), Some(Visibility::Private)),
+                field("amount", primitive("u64"), Some(Visibility::Public)),
+                field(
+                    "points",
+                    AbiType::Array { element: Box::new(AbiType::Struct("Point".to_string())), length: 2 },
+                    Some(Visibility::Private),
+                ),
+            ],
+        }]);
+        assert_eq!(abi.mappings, vec![MappingAbi {
+            name: "balances".to_string(),
+            key: primitive("address"),
+            value: primitive("u64"),
+        }]);
+
+        // Only transitions are part of the interface.
+        assert_eq!(abi.transitions, vec![TransitionAbi {
+            name: "mint".to_string(),
+            inputs: vec![
+                field("receiver", primitive("address"), Some(Visibility::Private)),
+                field("amount", primitive("u64"), Some(Visibility::Public)),
+            ],
+            outputs: vec![
+                OutputAbi { type_: AbiType::Record("Token".to_string()), visibility: Visibility::Private },
+                OutputAbi { type_: primitive("u64"), visibility: Visibility::Public },
+            ],
+            finalize: Some(vec![
+                field("receiver", primitive("address"), None),
+                field("amount", primitive("u64"), None)
+            ]),
+        }]);
+
+        // The JSON format is part of the interface too.
+        let json = serde_json::to_value(&abi).unwrap();
+        assert_eq!(
+            json["records"][0]["fields"][2],
+            serde_json::json!({
+                "name": "points",
+                "type": { "array": { "element": { "struct": "Point" }, "length": 2 } },
+                "visibility": "private",
+            })
+        );
+        assert_eq!(serde_json::from_value::<ProgramAbi>(json).unwrap(), abi);
+    });
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::{BuildOptions, Compiler, CompilerOptions};
+use leo_errors::emitter::Handler;
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+const HASHES: &str = r#"
+program test.aleo {
+    struct Point {
+        x: field,
+        y: field,
+    }
+
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    transition main(a: field, p: Point) -> (field, field, field) {
+        let first: field = BHP256::hash_to_field(a) + p.x;
+        let second: field = p.x + BHP256::hash_to_field(a);
+        return (first, second, p.x * p.y);
+    }
+
+    transition mint(amount: u64) -> (Token, Token) {
+        return (Token { owner: self.caller, amount }, Token { owner: self.caller, amount });
+    }
+}
+"#;
+
+const FINALIZE: &str = r#"
+program test.aleo {
+    mapping balances: address => u64;
+
+    transition main(receiver: address, amount: u64) {
+        return then finalize(receiver, amount);
+    }
+
+    finalize main(receiver: address, amount: u64) {
+        let first: u64 = ChaCha::rand_u64();
+        let second: u64 = ChaCha::rand_u64();
+        let before: u64 = Mapping::get(balances, receiver);
+        let same: u64 = Mapping::get(balances, receiver);
+        Mapping::set(balances, receiver, before + same + first + second);
+        let after: u64 = Mapping::get(balances, receiver);
+        assert(after > amount);
+    }
+}
+"#;
+
+/// Compiles `source` and returns its bytecode.
+fn compile(source: &str) -> String {
+    let handler = Handler::default();
+    let options =
+        CompilerOptions { build: BuildOptions { dce_enabled: true, ..Default::default() }, ..Default::default() };
+    let mut compiler = Compiler::new(
+        "test".to_string(),
+        "aleo".to_string(),
+        &handler,
+        Default::default(),
+        Default::default(),
+        Some(options),
+    );
+    compiler.compile_from_string(source, FileName::Custom("test".into())).unwrap().bytecode
+}
+
+/// Counts the instructions of the bytecode with the given opcode.
+fn count(bytecode: &str, opcode: &str) -> usize {
+    bytecode.lines().filter(|line| line.trim_start().split(' ').next() == Some(opcode)).count()
+}
+
+#[test]
+fn reuse_pure_expressions() {
+    create_session_if_not_set_then(|_| {
+        let bytecode = compile(HASHES);
+        assert_eq!(count(&bytecode, "hash.bhp256"), 1);
+        // `p.x + hash` is the same value as `hash + p.x`.
+        assert_eq!(count(&bytecode, "add"), 1);
+        assert_eq!(count(&bytecode, "mul"), 1);
+        // Each record is only spent once, so the two identical records are both constructed.
+        assert_eq!(count(&bytecode, "cast"), 2);
+    });
+}
+
+#[test]
+fn keep_side_effects() {
+    create_session_if_not_set_then(|_| {
+        let bytecode = compile(FINALIZE);
+        assert_eq!(count(&bytecode, "rand.chacha"), 2);
+        // The second read is reused, but the mapping is read again after it is updated.
+        assert_eq!(count(&bytecode, "get"), 2);
+        assert_eq!(count(&bytecode, "set"), 1);
+    });
+}
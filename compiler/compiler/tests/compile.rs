@@ -16,18 +16,17 @@
 
 mod utilities;
 use utilities::{
-    compile_and_process,
+    compile_program,
     get_build_options,
     get_cwd_option,
     hash_asts,
     hash_content,
     hash_symbol_tables,
-    parse_program,
     setup_build_directory,
     BufferEmitter,
 };
 
-use leo_compiler::{CompilerOptions, OutputOptions};
+use leo_compiler::{CompilerOptions, OutputOptions, Snapshots};
 use leo_errors::{emitter::Handler, LeoError};
 use leo_span::symbol::create_session_if_not_set_then;
 use leo_test_framework::{
@@ -39,7 +38,7 @@ use snarkvm::console::prelude::*;
 
 use serde::{Deserialize, Serialize};
 use serde_yaml::Value;
-use std::{fs, path::Path, rc::Rc};
+use std::rc::Rc;
 
 struct CompileNamespace;
 
@@ -68,6 +67,7 @@ struct CompileOutput {
     pub flattened_ast: String,
     pub destructured_ast: String,
     pub inlined_ast: String,
+    pub cse_ast: String,
     pub dce_ast: String,
     pub bytecode: String,
     pub warnings: String,
@@ -87,28 +87,18 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
             build,
             output: OutputOptions {
                 symbol_table_spans_enabled: false,
-                initial_symbol_table: true,
-                type_checked_symbol_table: true,
-                unrolled_symbol_table: true,
+                symbol_table_snapshots: Snapshots::All,
                 ast_spans_enabled: false,
-                initial_input_ast: true,
-                initial_ast: true,
-                unrolled_ast: true,
-                ssa_ast: true,
-                flattened_ast: true,
-                destructured_ast: true,
-                inlined_ast: true,
-                dce_ast: true,
+                ast_snapshots: Snapshots::All,
+                timings: false,
             },
         };
 
-        // Parse the program.
-        let mut parsed =
-            handler.extend_if_error(parse_program(handler, &test.content, cwd.clone(), Some(compiler_options)))?;
-
         // Compile the program to bytecode.
-        let program_name = format!("{}.{}", parsed.program_name, parsed.network);
-        let bytecode = handler.extend_if_error(compile_and_process(&mut parsed))?;
+        let output = compiler_options.output.clone();
+        let (program_name, artifacts) =
+            handler.extend_if_error(compile_program(handler, &test.content, cwd.clone(), Some(compiler_options)))?;
+        let bytecode = artifacts.bytecode.clone();
 
         // Set up the build directory.
         // Note that this function checks that the bytecode is well-formed.
@@ -117,16 +107,13 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
         // Get the program process and check all instructions.
         handler.extend_if_error(package.get_process().map_err(LeoError::Anyhow))?;
 
-        // Hash the ast files.
-        let (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, dce_ast) = hash_asts();
+        // Hash the ast snapshots.
+        let snapshots = handler.extend_if_error(artifacts.snapshots(&output))?;
+        let (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, cse_ast, dce_ast) =
+            hash_asts(&snapshots);
 
         // Hash the symbol tables.
-        let (initial_symbol_table, type_checked_symbol_table, unrolled_symbol_table) = hash_symbol_tables();
-
-        // Clean up the output directory.
-        if fs::read_dir("/tmp/output").is_ok() {
-            fs::remove_dir_all(Path::new("/tmp/output")).expect("Error failed to clean up output dir.");
-        }
+        let (initial_symbol_table, type_checked_symbol_table, unrolled_symbol_table) = hash_symbol_tables(&snapshots);
 
         let final_output = CompileOutput {
             initial_symbol_table,
@@ -138,6 +125,7 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
             flattened_ast,
             destructured_ast,
             inlined_ast,
+            cse_ast,
             dce_ast,
             bytecode: hash_content(&bytecode),
             warnings: buf.1.take().to_string(),
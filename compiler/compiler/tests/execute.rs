@@ -17,21 +17,20 @@
 mod utilities;
 use utilities::{
     buffer_if_err,
-    compile_and_process,
+    compile_program,
     dotenv_private_key,
     get_build_options,
     get_cwd_option,
     hash_asts,
     hash_content,
     hash_symbol_tables,
-    parse_program,
     setup_build_directory,
     Aleo,
     BufferEmitter,
     Network,
 };
 
-use leo_compiler::{CompilerOptions, OutputOptions};
+use leo_compiler::{CompilerOptions, OutputOptions, Snapshots};
 use leo_errors::emitter::Handler;
 use leo_span::symbol::create_session_if_not_set_then;
 use leo_test_framework::{
@@ -45,7 +44,7 @@ use snarkvm::{console, prelude::*};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_yaml::Value;
-use std::{collections::BTreeMap, fs, path::Path, rc::Rc};
+use std::{collections::BTreeMap, rc::Rc};
 
 // TODO: Evaluate namespace.
 struct ExecuteNamespace;
@@ -76,6 +75,7 @@ struct ExecuteOutput {
     pub flattened_ast: String,
     pub destructured_ast: String,
     pub inlined_ast: String,
+    pub cse_ast: String,
     pub dce_ast: String,
     pub bytecode: String,
     pub warnings: String,
@@ -101,28 +101,18 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
             build,
             output: OutputOptions {
                 symbol_table_spans_enabled: false,
-                initial_symbol_table: true,
-                type_checked_symbol_table: true,
-                unrolled_symbol_table: true,
+                symbol_table_snapshots: Snapshots::All,
                 ast_spans_enabled: false,
-                initial_input_ast: true,
-                initial_ast: true,
-                unrolled_ast: true,
-                ssa_ast: true,
-                flattened_ast: true,
-                destructured_ast: true,
-                inlined_ast: true,
-                dce_ast: true,
+                ast_snapshots: Snapshots::All,
+                timings: false,
             },
         };
 
-        // Parse the program.
-        let mut parsed =
-            handler.extend_if_error(parse_program(handler, &test.content, cwd.clone(), Some(compiler_options)))?;
-
         // Compile the program to bytecode.
-        let program_name = format!("{}.{}", parsed.program_name, parsed.network);
-        let bytecode = handler.extend_if_error(compile_and_process(&mut parsed))?;
+        let output = compiler_options.output.clone();
+        let (program_name, artifacts) =
+            handler.extend_if_error(compile_program(handler, &test.content, cwd.clone(), Some(compiler_options)))?;
+        let bytecode = artifacts.bytecode.clone();
 
         // Extract the cases from the test config.
         let all_cases = test
@@ -201,16 +191,13 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
             results.insert(function_name.to_string(), function_results);
         }
 
-        // Hash the ast files.
-        let (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, dce_ast) = hash_asts();
+        // Hash the ast snapshots.
+        let snapshots = handler.extend_if_error(artifacts.snapshots(&output))?;
+        let (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, cse_ast, dce_ast) =
+            hash_asts(&snapshots);
 
         // Hash the symbol tables.
-        let (initial_symbol_table, type_checked_symbol_table, unrolled_symbol_table) = hash_symbol_tables();
-
-        // Clean up the output directory.
-        if fs::read_dir("/tmp/output").is_ok() {
-            fs::remove_dir_all(Path::new("/tmp/output")).expect("Error failed to clean up output dir.");
-        }
+        let (initial_symbol_table, type_checked_symbol_table, unrolled_symbol_table) = hash_symbol_tables(&snapshots);
 
         let final_output = ExecuteOutput {
             initial_symbol_table,
@@ -222,6 +209,7 @@ fn run_test(test: Test, handler: &Handler, err_buf: &BufferEmitter) -> Result<Va
             flattened_ast,
             destructured_ast,
             inlined_ast,
+            cse_ast,
             dce_ast,
             bytecode: hash_content(&bytecode),
             warnings: err_buf.1.take().to_string(),
@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::{CompilationArtifacts, Compiler, CompilerOptions, MemoryFileSystem, OutputOptions, Snapshots};
+use leo_errors::emitter::Handler;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use std::path::{Path, PathBuf};
+
+const MAIN: &str = r#"
+import point.leo;
+
+program test.aleo {
+    transition main(a: i8) -> point.leo/Point {
+        return Point { x: a, y: a };
+    }
+}
+"#;
+
+const POINT: &str = r#"
+program point.aleo {
+    struct Point {
+        x: i8,
+        y: i8,
+    }
+
+    transition origin() -> Point {
+        return Point { x: 0i8, y: 0i8 };
+    }
+}
+"#;
+
+/// Compiles `src/main.leo` of `file_system`.
+fn compile(file_system: &MemoryFileSystem, options: CompilerOptions) -> leo_errors::Result<CompilationArtifacts> {
+    let handler = Handler::default();
+    let mut compiler = Compiler::new(
+        "test".to_string(),
+        "aleo".to_string(),
+        &handler,
+        PathBuf::from("src/main.leo"),
+        PathBuf::from("outputs"),
+        Some(options),
+    )
+    .with_file_system(file_system);
+    compiler.compile()
+}
+
+#[test]
+fn compile_in_memory() {
+    create_session_if_not_set_then(|_| {
+        let file_system: MemoryFileSystem =
+            [("src/main.leo", MAIN), ("imports/point.leo", POINT)].into_iter().collect();
+        let output =
+            OutputOptions { ast_snapshots: Snapshots::Passes(vec!["parsing".to_string()]), ..Default::default() };
+        let artifacts =
+            compile(&file_system, CompilerOptions { output: output.clone(), ..Default::default() }).unwrap();
+        assert!(artifacts.bytecode.contains("import point.aleo;"), "{}", artifacts.bytecode);
+
+        // The snapshots are written to the file system rather than to the disk.
+        artifacts.write_snapshots(&file_system, Path::new("outputs"), "test", &output).unwrap();
+        let snapshot = file_system.get("outputs/test.initial_ast.json").expect("the snapshot was not written");
+        assert!(snapshot.contains("\"point\""), "{snapshot}");
+        assert!(!Path::new("outputs/test.initial_ast.json").exists());
+    });
+}
+
+#[test]
+fn import_not_found_in_memory() {
+    create_session_if_not_set_then(|_| {
+        let file_system: MemoryFileSystem = [("src/main.leo", MAIN)].into_iter().collect();
+        let error = compile(&file_system, CompilerOptions::default()).unwrap_err();
+        assert!(error.to_string().contains("imports/point.leo"), "{error}");
+    });
+}
@@ -0,0 +1,252 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::Ast;
+use leo_compiler::{
+    pipeline::PASSES,
+    BuildOptions,
+    CompilationArtifacts,
+    Compiler,
+    CompilerOptions,
+    MemoryFileSystem,
+    OutputOptions,
+    Snapshots,
+};
+use leo_errors::{emitter::Handler, Result};
+use leo_passes::{Invariant, IrValidator, Pass};
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+use std::path::Path;
+
+const LOOP_FREE: &str = r#"
+program test.aleo {
+    transition main(a: u32, b: u32) -> u32 {
+        if a > b {
+            return a - b;
+        }
+        return b - a;
+    }
+}
+"#;
+
+const WITH_LOOP: &str = r#"
+program test.aleo {
+    transition main(a: u32) -> u32 {
+        let sum: u32 = 0u32;
+        for i: u32 in 0u32..4u32 {
+            sum += a;
+        }
+        return sum;
+    }
+}
+"#;
+
+const UNLOWERED: &str = r#"
+program test.aleo {
+    inline double(a: u32) -> u32 {
+        return a + a;
+    }
+
+    transition main(a: u32, b: u32) -> (u32, u32) {
+        let t: (u32, u32) = (a, b);
+        if a > b {
+            return (double(a), t.1);
+        }
+        return t;
+    }
+}
+"#;
+
+/// Compiles `source` with the given options, returning its artifacts.
+fn compile_artifacts(source: &str, options: CompilerOptions) -> Result<CompilationArtifacts> {
+    let handler = Handler::default();
+    let mut compiler = Compiler::new(
+        "test".to_string(),
+        "aleo".to_string(),
+        &handler,
+        Default::default(),
+        Default::default(),
+        Some(options),
+    );
+    compiler.compile_from_string(source, FileName::Custom("test".into()))
+}
+
+/// Compiles `source` with the given options, returning the bytecode and the names of the timed passes.
+fn compile(source: &str, options: CompilerOptions) -> Result<(String, Vec<&'static str>)> {
+    let artifacts = compile_artifacts(source, options)?;
+    Ok((artifacts.bytecode, artifacts.timings.iter().map(|timing| timing.name).collect()))
+}
+
+fn build_options(passes: &[&str], skipped_passes: &[&str]) -> CompilerOptions {
+    CompilerOptions {
+        build: BuildOptions {
+            dce_enabled: true,
+            passes: passes.iter().map(|pass| pass.to_string()).collect(),
+            skipped_passes: skipped_passes.iter().map(|pass| pass.to_string()).collect(),
+            validate_ir: true,
+        },
+        output: Default::default(),
+    }
+}
+
+#[test]
+fn skip_loop_unrolling() {
+    create_session_if_not_set_then(|_| {
+        let (expected, _) = compile(LOOP_FREE, build_options(&[], &[])).unwrap();
+        let (bytecode, _) = compile(LOOP_FREE, build_options(&[], &["loop_unrolling"])).unwrap();
+        assert_eq!(bytecode, expected);
+
+        // Loops must be unrolled before the program is converted into SSA form.
+        let err = compile(WITH_LOOP, build_options(&[], &["loop_unrolling"])).unwrap_err();
+        assert!(err.to_string().contains("Cannot run `static_single_assignment`"));
+    });
+}
+
+#[test]
+fn check_pipeline() {
+    create_session_if_not_set_then(|_| {
+        let err = compile(LOOP_FREE, build_options(&[], &["unrolling"])).unwrap_err();
+        assert!(err.to_string().contains("Unknown compiler pass `unrolling`"));
+
+        let err = compile(LOOP_FREE, build_options(&["symbol_table", "static_single_assignment"], &[])).unwrap_err();
+        assert!(err.to_string().contains("the `type_checking` pass must run before it"));
+
+        let err = compile(LOOP_FREE, build_options(&[], &["flattening"])).unwrap_err();
+        assert!(err.to_string().contains("Cannot run `destructuring`"));
+
+        // Code generation cannot run on a program that still contains tuples.
+        let err = compile(LOOP_FREE, build_options(&[], &["destructuring"])).unwrap_err();
+        assert!(err.to_string().contains("Cannot run `code_generation`"));
+
+        let options = CompilerOptions {
+            output: OutputOptions {
+                ast_snapshots: Snapshots::Passes(vec!["type_checking".to_string()]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let err = compile(LOOP_FREE, options).unwrap_err();
+        assert!(err.to_string().contains("The pass `type_checking` does not produce AST snapshots"));
+
+        // Only the AST is snapshotted after parsing.
+        let options = CompilerOptions {
+            output: OutputOptions {
+                symbol_table_snapshots: Snapshots::Passes(vec!["parsing".to_string()]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let err = compile(LOOP_FREE, options).unwrap_err();
+        assert!(err.to_string().contains("The pass `parsing` does not produce symbol table snapshots"));
+    });
+}
+
+#[test]
+fn pass_timings() {
+    create_session_if_not_set_then(|_| {
+        let options =
+            CompilerOptions { output: OutputOptions { timings: true, ..Default::default() }, ..Default::default() };
+        let (_, timed) = compile(LOOP_FREE, options).unwrap();
+        let mut expected = PASSES.iter().map(|pass| pass.name).collect::<Vec<_>>();
+        expected.push("code_generation");
+        assert_eq!(timed, expected);
+
+        // The function, its conditional and two return statements, and their three binary expressions of two operands.
+        let artifacts = compile_artifacts(LOOP_FREE, CompilerOptions {
+            output: OutputOptions { timings: true, ..Default::default() },
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(artifacts.timings[0].nodes, 13);
+
+        // Timings are only recorded when requested.
+        let (_, timed) = compile(LOOP_FREE, Default::default()).unwrap();
+        assert!(timed.is_empty());
+    });
+}
+
+#[test]
+fn snapshot_artifacts() {
+    create_session_if_not_set_then(|_| {
+        let output = OutputOptions {
+            ast_snapshots: Snapshots::Passes(vec!["parsing".to_string(), "flattening".to_string()]),
+            symbol_table_snapshots: Snapshots::Passes(vec!["type_checking".to_string()]),
+            ..Default::default()
+        };
+        let options = CompilerOptions { output: output.clone(), ..Default::default() };
+        let artifacts = compile_artifacts(LOOP_FREE, options).unwrap();
+        assert_eq!(artifacts.asts.keys().copied().collect::<Vec<_>>(), ["initial_ast", "flattened_ast"]);
+        assert_eq!(artifacts.symbol_tables.keys().copied().collect::<Vec<_>>(), ["type_checked_symbol_table"]);
+
+        // The snapshots are only written where the caller asks for them.
+        let file_system = MemoryFileSystem::new();
+        artifacts.write_snapshots(&file_system, Path::new("outputs"), "test", &output).unwrap();
+        let files = file_system.into_files();
+        assert_eq!(files.keys().collect::<Vec<_>>(), [
+            Path::new("outputs/test.flattened_ast.json"),
+            Path::new("outputs/test.initial_ast.json"),
+            Path::new("outputs/test.type_checked_symbol_table.json"),
+        ]);
+        assert!(!files.values().any(|json| json.contains("\"span\"")));
+    });
+}
+
+/// Checks the parsed `source` against the given invariant, returning the errors.
+fn validate(ast: &Ast, invariant: Invariant) -> String {
+    let (handler, buffer) = Handler::new_with_buf();
+    let _ = IrValidator::do_pass((ast, &handler, &[invariant], "test"));
+    buffer.extract_errs().to_string()
+}
+
+#[test]
+fn validate_ir() {
+    create_session_if_not_set_then(|_| {
+        // The passes establish the invariants that the validators check.
+        // Node IDs stay unique, even though unrolling, inlining, and flattening copy nodes.
+        compile(UNLOWERED, build_options(&[], &[])).unwrap();
+        compile(WITH_LOOP, build_options(&[], &[])).unwrap();
+
+        let handler = Handler::default();
+        let mut compiler = Compiler::new(
+            "test".to_string(),
+            "aleo".to_string(),
+            &handler,
+            Default::default(),
+            Default::default(),
+            None,
+        );
+        compiler.parse_program_from_string(UNLOWERED, FileName::Custom("test".into())).unwrap();
+
+        for (invariant, node) in [
+            (Invariant::SsaForm, "let t: (u32,u32) = (a,b);"),
+            (Invariant::NoConditionals, "if a > b { ... }"),
+            (Invariant::NoTuples, "t.1"),
+            (Invariant::NoInlineCalls, "double(a)"),
+        ] {
+            let errors = validate(&compiler.ast, invariant);
+            assert!(errors.contains(&format!("`test` broke the invariant that {invariant}")), "{errors}");
+            assert!(errors.contains(&format!("`{node}`")), "{errors}");
+        }
+        assert_eq!(validate(&compiler.ast, Invariant::UniqueNodeIds), "");
+
+        // Copying a statement copies its node IDs.
+        let mut program = compiler.ast.into_repr();
+        let main = &mut program.program_scopes.values_mut().next().unwrap().functions[1].1;
+        main.block.statements.push(main.block.statements[0].clone());
+        let errors = validate(&Ast::new(program), Invariant::UniqueNodeIds);
+        assert!(errors.contains("broke the invariant that every node has a unique `NodeID`"), "{errors}");
+    });
+}
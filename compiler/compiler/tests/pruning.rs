@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::{CompilationArtifacts, Compiler};
+use leo_errors::emitter::Handler;
+use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+const PROGRAM: &str = r#"
+program test.aleo {
+    struct Inner {
+        a: u8,
+    }
+
+    struct Outer {
+        inner: Inner,
+    }
+
+    struct Key {
+        a: u8,
+    }
+
+    struct Unused {
+        a: u8,
+    }
+
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    mapping values: Key => u8;
+
+    const TWO: u8 = 2u8;
+    const THREE: u8 = 3u8;
+
+    inline double(a: u8) -> u8 {
+        return a + a;
+    }
+
+    function add_two(a: u8) -> u8 {
+        return double(a) + TWO;
+    }
+
+    inline square(a: u8) -> u8 {
+        return a * a;
+    }
+
+    function call_unused(u: Unused) -> u8 {
+        return square(u.a) + THREE;
+    }
+
+    transition main(o: Outer) -> u8 {
+        return add_two(o.inner.a) then finalize(o.inner.a);
+    }
+
+    finalize main(a: u8) {
+        Mapping::set(values, Key { a }, a);
+    }
+}
+"#;
+
+/// Compiles `source`, returning its artifacts.
+fn compile(source: &str) -> CompilationArtifacts {
+    let handler = Handler::default();
+    let mut compiler =
+        Compiler::new("test".to_string(), "aleo".to_string(), &handler, Default::default(), Default::default(), None);
+    compiler.compile_from_string(source, FileName::Custom("test".into())).unwrap()
+}
+
+#[test]
+fn prune_unused_items() {
+    create_session_if_not_set_then(|_| {
+        let artifacts = compile(PROGRAM);
+
+        // The structs used by a transition, a mapping, or a record are kept, along with the records themselves.
+        for declaration in ["struct Inner:", "struct Outer:", "struct Key:", "record Token:", "closure add_two:"] {
+            assert!(artifacts.bytecode.contains(declaration), "`{declaration}` was pruned");
+        }
+        for declaration in ["struct Unused:", "closure call_unused:"] {
+            assert!(!artifacts.bytecode.contains(declaration), "`{declaration}` was not pruned");
+        }
+
+        // Each pruned item is reported, in the order in which it is declared.
+        let warnings = artifacts.warnings.iter().map(|warning| warning.to_string()).collect::<Vec<_>>();
+        assert_eq!(warnings.len(), 4);
+        for (warning, expected) in warnings.iter().zip([
+            "The struct `Unused` is never used",
+            "The constant `THREE` is never used",
+            "The function `square` is never called by a transition",
+            "The function `call_unused` is never called by a transition",
+        ]) {
+            assert!(warning.contains(expected), "expected `{expected}` in `{warning}`");
+        }
+
+        // The pruned items are not part of the program's interface.
+        assert!(!artifacts.symbol_table.structs.contains_key(&leo_span::Symbol::intern("Unused")));
+        assert!(!artifacts.call_graph.contains_node(leo_span::Symbol::intern("square")));
+    });
+}
@@ -17,7 +17,7 @@
 mod check_unique_node_ids;
 use check_unique_node_ids::*;
 
-use leo_compiler::{BuildOptions, Compiler, CompilerOptions};
+use leo_compiler::{BuildOptions, CompilationArtifacts, Compiler, CompilerOptions};
 use leo_errors::{
     emitter::{Buffer, Emitter, Handler},
     LeoError,
@@ -29,6 +29,7 @@ use leo_test_framework::{test::TestConfig, Test};
 
 use snarkvm::prelude::*;
 
+use indexmap::IndexMap;
 use leo_ast::ProgramVisitor;
 use snarkvm::{file::Manifest, package::Package};
 use std::{
@@ -44,22 +45,25 @@ pub type Network = Testnet3;
 #[allow(unused)]
 pub type Aleo = snarkvm::circuit::AleoV0;
 
-pub fn hash_asts() -> (String, String, String, String, String, String, String) {
-    let initial_ast = hash_file("/tmp/output/test.initial_ast.json");
-    let unrolled_ast = hash_file("/tmp/output/test.unrolled_ast.json");
-    let ssa_ast = hash_file("/tmp/output/test.ssa_ast.json");
-    let flattened_ast = hash_file("/tmp/output/test.flattened_ast.json");
-    let destructured_ast = hash_file("/tmp/output/test.destructured_ast.json");
-    let inlined_ast = hash_file("/tmp/output/test.inlined_ast.json");
-    let dce_ast = hash_file("/tmp/output/test.dce_ast.json");
-
-    (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, dce_ast)
-}
-
-pub fn hash_symbol_tables() -> (String, String, String) {
-    let initial_symbol_table = hash_file("/tmp/output/test.initial_symbol_table.json");
-    let type_checked_symbol_table = hash_file("/tmp/output/test.type_checked_symbol_table.json");
-    let unrolled_symbol_table = hash_file("/tmp/output/test.unrolled_symbol_table.json");
+pub fn hash_asts(
+    snapshots: &IndexMap<String, String>,
+) -> (String, String, String, String, String, String, String, String) {
+    let initial_ast = hash_content(&snapshots["initial_ast.json"]);
+    let unrolled_ast = hash_content(&snapshots["unrolled_ast.json"]);
+    let ssa_ast = hash_content(&snapshots["ssa_ast.json"]);
+    let flattened_ast = hash_content(&snapshots["flattened_ast.json"]);
+    let destructured_ast = hash_content(&snapshots["destructured_ast.json"]);
+    let inlined_ast = hash_content(&snapshots["inlined_ast.json"]);
+    let cse_ast = hash_content(&snapshots["cse_ast.json"]);
+    let dce_ast = hash_content(&snapshots["dce_ast.json"]);
+
+    (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, cse_ast, dce_ast)
+}
+
+pub fn hash_symbol_tables(snapshots: &IndexMap<String, String>) -> (String, String, String) {
+    let initial_symbol_table = hash_content(&snapshots["initial_symbol_table.json"]);
+    let type_checked_symbol_table = hash_content(&snapshots["type_checked_symbol_table.json"]);
+    let unrolled_symbol_table = hash_content(&snapshots["unrolled_symbol_table.json"]);
     (initial_symbol_table, type_checked_symbol_table, unrolled_symbol_table)
 }
 
@@ -96,11 +100,12 @@ pub fn get_build_options(test_config: &TestConfig) -> Vec<BuildOptions> {
                             .expect("Expected key `dce_enabled`")
                             .as_bool()
                             .expect("Expected value to be a boolean."),
+                        ..Default::default()
                     }
                 })
                 .collect()
         }
-        None => vec![BuildOptions { dce_enabled: true }],
+        None => vec![BuildOptions { dce_enabled: true, ..Default::default() }],
     }
 }
 
@@ -139,25 +144,27 @@ pub fn new_compiler(
     main_file_path: PathBuf,
     compiler_options: Option<CompilerOptions>,
 ) -> Compiler<'_> {
-    let output_dir = PathBuf::from("/tmp/output/");
-    fs::create_dir_all(output_dir.clone()).unwrap();
-
-    Compiler::new(String::from("test"), String::from("aleo"), handler, main_file_path, output_dir, compiler_options)
+    // The snapshots are returned as artifacts, so nothing is written to the output directory.
+    Compiler::new(String::from("test"), String::from("aleo"), handler, main_file_path, PathBuf::new(), compiler_options)
 }
 
-pub fn parse_program<'a>(
-    handler: &'a Handler,
+/// Compiles `program_string`, returning the program name and the compilation artifacts.
+pub fn compile_program(
+    handler: &Handler,
     program_string: &str,
     cwd: Option<PathBuf>,
     compiler_options: Option<CompilerOptions>,
-) -> Result<Compiler<'a>, LeoError> {
+) -> Result<(String, CompilationArtifacts), LeoError> {
     let mut compiler = new_compiler(handler, cwd.clone().unwrap_or_else(|| "compiler-test".into()), compiler_options);
     let name = cwd.map_or_else(|| FileName::Custom("compiler-test".into()), FileName::Real);
-    compiler.parse_program_from_string(program_string, name)?;
 
-    CheckUniqueNodeIds::new().visit_program(&compiler.ast.ast);
+    // In debug builds, the invariants of the AST, including unique node IDs, are checked after each pass.
+    let artifacts = compiler.compile_from_string(program_string, name)?;
+    if let Some(initial_ast) = artifacts.asts.get("initial_ast") {
+        CheckUniqueNodeIds::new().visit_program(&initial_ast.ast);
+    }
 
-    Ok(compiler)
+    Ok((format!("{}.{}", compiler.program_name, compiler.network), artifacts))
 }
 
 pub fn hash_content(content: &str) -> String {
@@ -169,11 +176,6 @@ pub fn hash_content(content: &str) -> String {
     format!("{hash:x}")
 }
 
-pub fn hash_file(path: &str) -> String {
-    let file = fs::read_to_string(Path::new(path)).unwrap();
-    hash_content(&file)
-}
-
 /// Errors used in this module.
 pub enum LeoOrString {
     Leo(LeoError),
@@ -221,33 +223,6 @@ pub fn temp_dir() -> PathBuf {
     tempfile::tempdir().expect("Failed to open temporary directory").into_path()
 }
 
-pub fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<String, LeoError> {
-    let st = parsed.symbol_table_pass()?;
-
-    CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
-
-    let (st, struct_graph, call_graph) = parsed.type_checker_pass(st)?;
-
-    CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
-
-    let st = parsed.loop_unrolling_pass(st)?;
-
-    parsed.static_single_assignment_pass(&st)?;
-
-    parsed.flattening_pass(&st)?;
-
-    parsed.destructuring_pass()?;
-
-    parsed.function_inlining_pass(&call_graph)?;
-
-    parsed.dead_code_elimination_pass()?;
-
-    // Compile Leo program to bytecode.
-    let bytecode = parsed.code_generation_pass(&st, &struct_graph, &call_graph)?;
-
-    Ok(bytecode)
-}
-
 /// Returns the private key from the .env file specified in the directory.
 #[allow(unused)]
 pub fn dotenv_private_key(directory: &Path) -> Result<PrivateKey<Network>> {
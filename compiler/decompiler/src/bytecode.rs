@@ -0,0 +1,314 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A lightweight view over an Aleo program.
+//!
+//! snarkVM parses and validates the bytecode. This module walks the resulting program and keeps its types
+//! and operands in their canonical text form, which is all the decompiler needs to reconstruct Leo code.
+
+use leo_errors::{DecompilerError, Result};
+
+use snarkvm::prelude::{CallOperator, Command, Instruction as AleoInstruction, Program as AleoProgram, Testnet3};
+use std::{fmt, str::FromStr};
+
+/// A single instruction or command, e.g. `add r0 r1 into r2;`.
+#[derive(Clone, Debug)]
+pub(crate) struct Instruction {
+    /// The opcode, e.g. `add`, `hash.bhp256`, or `get.or_use`.
+    pub(crate) opcode: String,
+    /// The operands, excluding the callee of a `call` or an `async` and the mapping of a mapping command.
+    pub(crate) operands: Vec<String>,
+    /// The destination registers.
+    pub(crate) destinations: Vec<String>,
+    /// The destination type of a `cast`, a hash, a commitment, or `rand.chacha`.
+    pub(crate) type_: Option<String>,
+    /// The program and function called by a `call`, where the program is `None` for local calls.
+    pub(crate) callee: Option<(Option<String>, String)>,
+    /// The program and mapping accessed by a mapping command, where the program is `None` for local mappings.
+    pub(crate) mapping: Option<(Option<String>, String)>,
+    /// The original text of the instruction.
+    text: String,
+}
+
+impl Instruction {
+    fn from_instruction(instruction: &AleoInstruction<Testnet3>) -> Self {
+        let type_ = match instruction {
+            AleoInstruction::Cast(cast) => Some(cast.cast_type().to_string()),
+            _ => destination_type(instruction),
+        };
+        let callee = match instruction {
+            AleoInstruction::Call(call) => Some(locate(call.operator())),
+            _ => None,
+        };
+        Self {
+            opcode: instruction.opcode().to_string(),
+            operands: instruction.operands().iter().map(ToString::to_string).collect(),
+            destinations: instruction.destinations().iter().map(ToString::to_string).collect(),
+            type_,
+            callee,
+            mapping: None,
+            text: instruction.to_string(),
+        }
+    }
+
+    fn from_command(command: &Command<Testnet3>) -> Self {
+        let (opcode, operands, destinations, type_, mapping) = match command {
+            Command::Instruction(instruction) => return Self::from_instruction(instruction),
+            Command::Await(await_) => ("await", vec![await_.register().to_string()], Vec::new(), None, None),
+            Command::Contains(contains) => (
+                "contains",
+                vec![contains.key().to_string()],
+                vec![contains.destination().to_string()],
+                None,
+                Some(locate(contains.mapping())),
+            ),
+            Command::Get(get) => (
+                "get",
+                vec![get.key().to_string()],
+                vec![get.destination().to_string()],
+                None,
+                Some(locate(get.mapping())),
+            ),
+            Command::GetOrUse(get_or_use) => (
+                "get.or_use",
+                vec![get_or_use.key().to_string(), get_or_use.default().to_string()],
+                vec![get_or_use.destination().to_string()],
+                None,
+                Some(locate(get_or_use.mapping())),
+            ),
+            Command::RandChaCha(rand) => (
+                "rand.chacha",
+                rand.operands().iter().map(ToString::to_string).collect(),
+                vec![rand.destination().to_string()],
+                Some(rand.destination_type().to_string()),
+                None,
+            ),
+            Command::Set(set) => (
+                "set",
+                vec![set.key().to_string(), set.value().to_string()],
+                Vec::new(),
+                None,
+                Some((None, set.mapping_name().to_string())),
+            ),
+            Command::Remove(remove) => (
+                "remove",
+                vec![remove.key().to_string()],
+                Vec::new(),
+                None,
+                Some((None, remove.mapping_name().to_string())),
+            ),
+            Command::BranchEq(_) => ("branch.eq", Vec::new(), Vec::new(), None, None),
+            Command::BranchNeq(_) => ("branch.neq", Vec::new(), Vec::new(), None, None),
+            Command::Position(_) => ("position", Vec::new(), Vec::new(), None, None),
+        };
+        Self {
+            opcode: opcode.to_string(),
+            operands,
+            destinations,
+            type_,
+            callee: None,
+            mapping,
+            text: command.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+/// A closure or a function, along with its finalize block.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct FunctionBody {
+    /// Whether this is a `closure` rather than a `function`.
+    pub(crate) is_closure: bool,
+    /// The name of the closure or function.
+    pub(crate) name: String,
+    /// The input registers and their types.
+    pub(crate) inputs: Vec<(String, String)>,
+    /// The body of the closure or function.
+    pub(crate) instructions: Vec<Instruction>,
+    /// The output operands and their types.
+    pub(crate) outputs: Vec<(String, String)>,
+    /// The finalize block, if one exists.
+    pub(crate) finalize: Option<FinalizeBody>,
+}
+
+/// The finalize block of a function.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct FinalizeBody {
+    /// The input registers and their types.
+    pub(crate) inputs: Vec<(String, String)>,
+    /// The commands of the finalize block.
+    pub(crate) commands: Vec<Instruction>,
+}
+
+/// The sections of an Aleo program.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Bytecode {
+    /// The program name, without the `.aleo` suffix.
+    pub(crate) name: String,
+    /// The names of the imported programs, without the `.aleo` suffix.
+    pub(crate) imports: Vec<String>,
+    /// The structs, each with its members and their types.
+    pub(crate) structs: Vec<(String, Vec<(String, String)>)>,
+    /// The records, each with its entries and their types.
+    pub(crate) records: Vec<(String, Vec<(String, String)>)>,
+    /// The mappings, each with its key and value type.
+    pub(crate) mappings: Vec<(String, String, String)>,
+    /// The closures, followed by the functions.
+    pub(crate) functions: Vec<FunctionBody>,
+}
+
+impl Bytecode {
+    /// Parses and validates `bytecode` with snarkVM, and collects the declarations of the program.
+    pub(crate) fn parse(bytecode: &str) -> Result<Self> {
+        let program = AleoProgram::<Testnet3>::from_str(bytecode).map_err(DecompilerError::failed_to_parse_bytecode)?;
+
+        let structs = program
+            .structs()
+            .iter()
+            .map(|(name, struct_)| {
+                let members = struct_.members().iter().map(|(member, type_)| (member.to_string(), type_.to_string()));
+                (name.to_string(), members.collect())
+            })
+            .collect();
+
+        let records = program
+            .records()
+            .iter()
+            .map(|(name, record)| {
+                // snarkVM keeps the owner apart from the other entries, but Leo declares it as a regular member.
+                let owner = match record.owner().is_public() {
+                    true => "address.public",
+                    false => "address.private",
+                };
+                let mut entries = vec![("owner".to_string(), owner.to_string())];
+                entries.extend(record.entries().iter().map(|(entry, type_)| (entry.to_string(), type_.to_string())));
+                (name.to_string(), entries)
+            })
+            .collect();
+
+        let mappings = program
+            .mappings()
+            .iter()
+            .map(|(name, mapping)| {
+                (
+                    name.to_string(),
+                    mapping.key().plaintext_type().to_string(),
+                    mapping.value().plaintext_type().to_string(),
+                )
+            })
+            .collect();
+
+        let closures = program.closures().values().map(|closure| FunctionBody {
+            is_closure: true,
+            name: closure.name().to_string(),
+            inputs: closure
+                .inputs()
+                .iter()
+                .map(|input| (input.register().to_string(), input.register_type().to_string()))
+                .collect(),
+            instructions: closure.instructions().iter().map(Instruction::from_instruction).collect(),
+            outputs: closure
+                .outputs()
+                .iter()
+                .map(|output| (output.operand().to_string(), output.register_type().to_string()))
+                .collect(),
+            finalize: None,
+        });
+
+        let functions = program.functions().values().map(|function| FunctionBody {
+            is_closure: false,
+            name: function.name().to_string(),
+            inputs: function
+                .inputs()
+                .iter()
+                .map(|input| (input.register().to_string(), input.value_type().to_string()))
+                .collect(),
+            instructions: function.instructions().iter().map(Instruction::from_instruction).collect(),
+            outputs: function
+                .outputs()
+                .iter()
+                .map(|output| (output.operand().to_string(), output.value_type().to_string()))
+                .collect(),
+            finalize: function.finalize_logic().map(|finalize| FinalizeBody {
+                inputs: finalize
+                    .inputs()
+                    .iter()
+                    .map(|input| (input.register().to_string(), input.finalize_type().to_string()))
+                    .collect(),
+                commands: finalize.commands().iter().map(Instruction::from_command).collect(),
+            }),
+        });
+
+        Ok(Self {
+            name: program.id().name().to_string(),
+            imports: program.imports().keys().map(|import| import.name().to_string()).collect(),
+            structs,
+            records,
+            mappings,
+            functions: closures.chain(functions).collect(),
+        })
+    }
+}
+
+/// Splits a call operator into its program, if it refers to another program, and its resource.
+fn locate(operator: &CallOperator<Testnet3>) -> (Option<String>, String) {
+    match operator {
+        CallOperator::Locator(locator) => {
+            (Some(locator.program_id().name().to_string()), locator.resource().to_string())
+        }
+        CallOperator::Resource(resource) => (None, resource.to_string()),
+    }
+}
+
+/// Returns the destination type of a hash or commitment instruction.
+fn destination_type(instruction: &AleoInstruction<Testnet3>) -> Option<String> {
+    macro_rules! destination_type {
+        ($($variant:ident),*) => {
+            match instruction {
+                $(AleoInstruction::$variant(instruction) => Some(instruction.destination_type().to_string()),)*
+                _ => None,
+            }
+        };
+    }
+    destination_type!(
+        HashBHP256,
+        HashBHP512,
+        HashBHP768,
+        HashBHP1024,
+        HashKeccak256,
+        HashKeccak384,
+        HashKeccak512,
+        HashPED64,
+        HashPED128,
+        HashPSD2,
+        HashPSD4,
+        HashPSD8,
+        HashSha3_256,
+        HashSha3_384,
+        HashSha3_512,
+        CommitBHP256,
+        CommitBHP512,
+        CommitBHP768,
+        CommitBHP1024,
+        CommitPED64,
+        CommitPED128
+    )
+}
@@ -0,0 +1,439 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bytecode::{Bytecode, FunctionBody},
+    function::FunctionDecompiler,
+    types::{parse_type, ValueType},
+};
+
+use leo_ast::{
+    External,
+    Finalize,
+    FinalizeStub,
+    Function,
+    FunctionInput,
+    FunctionOutput,
+    FunctionStub,
+    Identifier,
+    Input,
+    Mapping,
+    Member,
+    Mode,
+    NodeBuilder,
+    NodeID,
+    Output,
+    Program,
+    ProgramId,
+    ProgramScope,
+    Struct,
+    Stub,
+    Type,
+    Variant,
+};
+use leo_errors::{DecompilerError, Result};
+use leo_span::{sym, Span, Symbol};
+
+use indexmap::IndexMap;
+use snarkvm::prelude::{Program as AleoProgram, Testnet3};
+
+/// Reconstructs Leo programs from Aleo bytecode.
+#[derive(Default)]
+pub struct Decompiler {
+    /// The imported programs, used to resolve calls into other programs.
+    imports: IndexMap<String, Bytecode>,
+    /// The node builder used to assign IDs to the reconstructed AST nodes.
+    node_builder: NodeBuilder,
+}
+
+impl Decompiler {
+    /// Registers the bytecode of an imported program.
+    /// Imports are needed to recover the types of the values returned by external calls.
+    pub fn add_import(&mut self, bytecode: &str) -> Result<()> {
+        let import = Bytecode::parse(bytecode)?;
+        self.imports.insert(import.name.clone(), import);
+        Ok(())
+    }
+
+    /// Decompiles the given bytecode into a Leo program.
+    pub fn decompile(&mut self, bytecode: &str) -> Result<Program> {
+        let bytecode = Bytecode::parse(bytecode)?;
+
+        // `credits.aleo` ships with snarkVM, so its bytecode never has to be provided.
+        if bytecode.imports.iter().any(|import| import == "credits") && !self.imports.contains_key("credits") {
+            let credits = AleoProgram::<Testnet3>::credits().map_err(DecompilerError::failed_to_parse_bytecode)?;
+            self.add_import(&credits.to_string())?;
+        }
+
+        self.program(&bytecode)
+    }
+
+    /// Reconstructs the interface of an Aleo program: its structs, records, mappings, and function signatures.
+    /// Node IDs are drawn from `node_builder`, so that the stub can be embedded in the AST of another program.
+    pub fn stub(bytecode: &str, node_builder: &NodeBuilder) -> Result<Stub> {
+        let bytecode = Bytecode::parse(bytecode)?;
+        let imports = IndexMap::new();
+        let context = Context { bytecode: &bytecode, imports: &imports, node_builder };
+
+        let mut functions = Vec::new();
+        // Closures cannot be called from other programs, so they are not part of the interface.
+        for function in bytecode.functions.iter().filter(|function| !function.is_closure) {
+            let input = function
+                .inputs
+                .iter()
+                .map(|(register, type_)| Ok(context.input(register, type_)?.0))
+                .collect::<Result<Vec<_>>>()?;
+            let output = context.outputs(&function.outputs)?;
+
+            let finalize = match &function.finalize {
+                Some(finalize) => {
+                    let mut input = Vec::new();
+                    for (register, type_) in finalize.inputs.iter() {
+                        if !matches!(ValueType::parse(type_, node_builder)?, ValueType::Future) {
+                            input.push(context.input(register, type_)?.0);
+                        }
+                    }
+                    Some(FinalizeStub {
+                        identifier: context.identifier(&function.name),
+                        input,
+                        span: Span::default(),
+                        id: context.id(),
+                    })
+                }
+                None => None,
+            };
+
+            functions.push((
+                Symbol::intern(&function.name),
+                FunctionStub::new(
+                    Variant::Transition,
+                    context.identifier(&function.name),
+                    input,
+                    output,
+                    finalize,
+                    Span::default(),
+                    context.id(),
+                ),
+            ));
+        }
+
+        Ok(Stub {
+            stub_id: ProgramId {
+                name: context.identifier(&bytecode.name),
+                network: Identifier::new(sym::aleo, context.id()),
+            },
+            structs: context.structs()?,
+            mappings: context.mappings()?,
+            functions,
+            span: Span::default(),
+        })
+    }
+
+    /// Reconstructs a program and any of its imports that are known.
+    fn program(&self, bytecode: &Bytecode) -> Result<Program> {
+        let mut imports = IndexMap::new();
+        for name in bytecode.imports.iter() {
+            let import = match self.imports.get(name) {
+                Some(import) => self.program(import)?,
+                None => Program::default(),
+            };
+            imports.insert(Symbol::intern(name), (import, Span::default()));
+        }
+
+        let context = Context { bytecode, imports: &self.imports, node_builder: &self.node_builder };
+
+        let structs = context.structs()?;
+        let mappings = context.mappings()?;
+
+        let functions = bytecode
+            .functions
+            .iter()
+            .map(|function| Ok((Symbol::intern(&function.name), self.function(&context, function)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let program_id =
+            ProgramId { name: context.identifier(&bytecode.name), network: Identifier::new(sym::aleo, context.id()) };
+        let program_scope =
+            ProgramScope { program_id, consts: Vec::new(), structs, mappings, functions, span: Span::default() };
+
+        Ok(Program {
+            imports,
+            stubs: IndexMap::new(),
+            program_scopes: IndexMap::from([(program_id.name.name, program_scope)]),
+        })
+    }
+
+    /// Reconstructs a closure as an inline function, or a function as a transition with its finalize block.
+    fn function(&self, context: &Context, function: &FunctionBody) -> Result<Function> {
+        let mut body = FunctionDecompiler::new(context, &function.name);
+
+        let input =
+            function.inputs.iter().map(|(register, type_)| body.input(register, type_)).collect::<Result<Vec<_>>>()?;
+        let output = context.outputs(&function.outputs)?;
+        let block = body.body(&function.instructions, &function.outputs)?;
+
+        let finalize = match &function.finalize {
+            Some(finalize) => {
+                let mut body = FunctionDecompiler::new(context, &function.name);
+                let mut input = Vec::new();
+                for (register, type_) in finalize.inputs.iter() {
+                    // Futures are awaited implicitly in Leo, so they do not appear in the signature.
+                    if !matches!(ValueType::parse(type_, context.node_builder)?, ValueType::Future) {
+                        input.push(body.input(register, type_)?);
+                    }
+                }
+                let block = body.finalize(&finalize.commands)?;
+                Some(Finalize::new(
+                    context.identifier(&function.name),
+                    input,
+                    Vec::new(),
+                    block,
+                    Span::default(),
+                    context.id(),
+                ))
+            }
+            None => None,
+        };
+
+        let variant = match function.is_closure {
+            true => Variant::Inline,
+            false => Variant::Transition,
+        };
+
+        Ok(Function::new(
+            Vec::new(),
+            variant,
+            context.identifier(&function.name),
+            input,
+            output,
+            block,
+            finalize,
+            Span::default(),
+            context.id(),
+        ))
+    }
+}
+
+/// The program-level information shared by the closures and functions of a program.
+pub(crate) struct Context<'a> {
+    /// The program being decompiled.
+    pub(crate) bytecode: &'a Bytecode,
+    /// The imported programs.
+    pub(crate) imports: &'a IndexMap<String, Bytecode>,
+    /// The node builder used to assign IDs to the reconstructed AST nodes.
+    pub(crate) node_builder: &'a NodeBuilder,
+}
+
+impl<'a> Context<'a> {
+    /// Returns a fresh node ID.
+    pub(crate) fn id(&self) -> NodeID {
+        self.node_builder.next_id()
+    }
+
+    /// Returns an identifier with the given name.
+    pub(crate) fn identifier(&self, name: &str) -> Identifier {
+        Identifier::new(Symbol::intern(name), self.id())
+    }
+
+    /// Reconstructs the structs and records of the program.
+    pub(crate) fn structs(&self) -> Result<Vec<(Symbol, Struct)>> {
+        let mut structs = Vec::new();
+        for (name, members) in self.bytecode.structs.iter() {
+            let members = members
+                .iter()
+                .map(|(member, type_)| {
+                    Ok(Member {
+                        mode: Mode::None,
+                        identifier: self.identifier(member),
+                        type_: parse_type(type_, self.node_builder)?,
+                        span: Span::default(),
+                        id: self.id(),
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            structs.push((Symbol::intern(name), Struct {
+                identifier: self.identifier(name),
+                members,
+                is_record: false,
+                doc: None,
+                span: Span::default(),
+                id: self.id(),
+            }));
+        }
+        for (name, entries) in self.bytecode.records.iter() {
+            let members = entries
+                .iter()
+                .map(|(entry, type_)| {
+                    let (type_, mode) = match ValueType::parse(type_, self.node_builder)? {
+                        ValueType::Plaintext(type_, mode) => (type_, mode),
+                        _ => return Err(DecompilerError::unsupported_type(type_).into()),
+                    };
+                    Ok(Member { mode, identifier: self.identifier(entry), type_, span: Span::default(), id: self.id() })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            structs.push((Symbol::intern(name), Struct {
+                identifier: self.identifier(name),
+                members,
+                is_record: true,
+                doc: None,
+                span: Span::default(),
+                id: self.id(),
+            }));
+        }
+        Ok(structs)
+    }
+
+    /// Reconstructs the mappings of the program.
+    pub(crate) fn mappings(&self) -> Result<Vec<(Symbol, Mapping)>> {
+        self.bytecode
+            .mappings
+            .iter()
+            .map(|(name, key, value)| {
+                Ok((Symbol::intern(name), Mapping {
+                    identifier: self.identifier(name),
+                    key_type: self.plaintext_type(key)?,
+                    value_type: self.plaintext_type(value)?,
+                    doc: None,
+                    span: Span::default(),
+                    id: self.id(),
+                }))
+            })
+            .collect()
+    }
+
+    /// Parses a plaintext type, dropping its visibility.
+    pub(crate) fn plaintext_type(&self, type_: &str) -> Result<Type> {
+        match ValueType::parse(type_, self.node_builder)? {
+            ValueType::Plaintext(type_, _) => Ok(type_),
+            _ => Err(DecompilerError::unsupported_type(type_).into()),
+        }
+    }
+
+    /// Returns the Leo output for an output operand and its type, or `None` if the output is a future.
+    pub(crate) fn output(&self, operand: &str, type_: &str) -> Result<Option<Output>> {
+        Ok(match ValueType::parse(type_, self.node_builder)? {
+            ValueType::Plaintext(type_, mode) => {
+                Some(Output::Internal(FunctionOutput { mode, type_, span: Span::default(), id: self.id() }))
+            }
+            ValueType::Record(type_) => {
+                Some(Output::Internal(FunctionOutput { mode: Mode::None, type_, span: Span::default(), id: self.id() }))
+            }
+            ValueType::ExternalRecord(program, record) => Some(Output::External(External {
+                identifier: self.identifier(operand),
+                program_name: self.identifier(&program),
+                record: self.identifier(&record),
+                span: Span::default(),
+                id: self.id(),
+            })),
+            ValueType::Future => None,
+        })
+    }
+
+    /// Returns the Leo outputs for the output operands of a function, skipping any future.
+    pub(crate) fn outputs(&self, outputs: &[(String, String)]) -> Result<Vec<Output>> {
+        outputs.iter().map(|(operand, type_)| self.output(operand, type_)).filter_map(Result::transpose).collect()
+    }
+
+    /// Returns the Leo input for an input register and its type.
+    pub(crate) fn input(&self, register: &str, type_: &str) -> Result<(Input, Type)> {
+        Ok(match ValueType::parse(type_, self.node_builder)? {
+            ValueType::Plaintext(type_, mode) => (
+                Input::Internal(FunctionInput {
+                    identifier: self.identifier(register),
+                    mode,
+                    type_: type_.clone(),
+                    span: Span::default(),
+                    id: self.id(),
+                }),
+                type_,
+            ),
+            ValueType::Record(type_) => (
+                Input::Internal(FunctionInput {
+                    identifier: self.identifier(register),
+                    mode: Mode::None,
+                    type_: type_.clone(),
+                    span: Span::default(),
+                    id: self.id(),
+                }),
+                type_,
+            ),
+            ValueType::ExternalRecord(program, record) => {
+                let external = External {
+                    identifier: self.identifier(register),
+                    program_name: self.identifier(&program),
+                    record: self.identifier(&record),
+                    span: Span::default(),
+                    id: self.id(),
+                };
+                let type_ = external.type_();
+                (Input::External(external), type_)
+            }
+            ValueType::Future => return Err(DecompilerError::unsupported_type(type_).into()),
+        })
+    }
+
+    /// Returns the types of the values returned by a call to `function` in `program`, or in the current program.
+    pub(crate) fn call_outputs(&self, program: Option<&str>, function: &str) -> Result<Vec<ValueType>> {
+        let bytecode = match program {
+            Some(program) => self.imports.get(program).ok_or_else(|| DecompilerError::missing_import(program))?,
+            None => self.bytecode,
+        };
+        let function = bytecode
+            .functions
+            .iter()
+            .find(|candidate| candidate.name == function)
+            .ok_or_else(|| DecompilerError::unknown_function(function, &bytecode.name))?;
+        function.outputs.iter().map(|(_, type_)| ValueType::parse(type_, self.node_builder)).collect()
+    }
+
+    /// Returns the type of `member` in the struct or record `composite`.
+    pub(crate) fn member_type(&self, composite: &Type, member: &str) -> Result<Type> {
+        let Type::Identifier(identifier) = composite else {
+            return Err(DecompilerError::unsupported_type(format!("{composite}.{member}")).into());
+        };
+        let name = identifier.name.to_string();
+        for bytecode in std::iter::once(self.bytecode).chain(self.imports.values()) {
+            let members = bytecode
+                .structs
+                .iter()
+                .chain(bytecode.records.iter())
+                .find(|(candidate, _)| *candidate == name)
+                .and_then(|(_, members)| members.iter().find(|(candidate, _)| candidate == member));
+            if let Some((_, type_)) = members {
+                return self.plaintext_type(type_);
+            }
+        }
+        Err(DecompilerError::unsupported_type(format!("{composite}.{member}")).into())
+    }
+
+    /// Returns the names of the members of the struct or record `composite`, in declaration order.
+    pub(crate) fn members(&self, composite: &str) -> Option<Vec<String>> {
+        self.bytecode
+            .structs
+            .iter()
+            .chain(self.bytecode.records.iter())
+            .find(|(candidate, _)| candidate == composite)
+            .map(|(_, members)| members.iter().map(|(member, _)| member.clone()).collect())
+    }
+
+    /// Returns the value type of the mapping `name`.
+    pub(crate) fn mapping_value_type(&self, name: &str) -> Result<Type> {
+        match self.bytecode.mappings.iter().find(|(candidate, ..)| candidate == name) {
+            Some((_, _, value)) => self.plaintext_type(value),
+            None => Err(DecompilerError::unsupported_type(name).into()),
+        }
+    }
+}
@@ -0,0 +1,736 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bytecode::Instruction,
+    decompiler::Context,
+    types::{integer_type, parse_type, ValueType},
+};
+
+use leo_ast::{
+    AccessExpression,
+    ArrayAccess,
+    ArrayExpression,
+    AssertStatement,
+    AssertVariant,
+    AssociatedConstant,
+    AssociatedFunction,
+    BinaryExpression,
+    BinaryOperation,
+    Block,
+    CallExpression,
+    CastExpression,
+    DeclarationType,
+    DefinitionStatement,
+    Expression,
+    ExpressionStatement,
+    GroupLiteral,
+    Identifier,
+    Input,
+    IntegerType,
+    Literal,
+    MemberAccess,
+    ReturnStatement,
+    Statement,
+    StructExpression,
+    StructVariableInitializer,
+    TernaryExpression,
+    TupleExpression,
+    TupleType,
+    Type,
+    UnaryExpression,
+    UnaryOperation,
+    UnitExpression,
+};
+use leo_errors::{DecompilerError, Result};
+use leo_span::{sym, Span, Symbol};
+
+use snarkvm::prelude::{ProgramID, Testnet3};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+/// Reconstructs the body of a single closure, function, or finalize block.
+///
+/// Every register that is read exactly once, and whose defining instruction has no side effects,
+/// is folded into the expression that reads it. All other registers are bound with `let`.
+pub(crate) struct FunctionDecompiler<'a> {
+    /// The program-level information.
+    context: &'a Context<'a>,
+    /// The name of the function being decompiled, used in error messages.
+    function: &'a str,
+    /// The type of each register defined so far.
+    types: HashMap<String, Type>,
+    /// The expressions of the registers that are folded into their only use.
+    folded: HashMap<String, Expression>,
+    /// The number of times each register is read.
+    uses: HashMap<String, usize>,
+    /// The registers that are read through a member or index access.
+    accessed: HashSet<String>,
+    /// The registers holding futures.
+    futures: HashSet<String>,
+    /// The arguments passed to the finalize block, if the body ends in an `async` instruction.
+    finalize_arguments: Option<Vec<Expression>>,
+    /// The statements reconstructed so far.
+    statements: Vec<Statement>,
+}
+
+impl<'a> FunctionDecompiler<'a> {
+    pub(crate) fn new(context: &'a Context<'a>, function: &'a str) -> Self {
+        Self {
+            context,
+            function,
+            types: Default::default(),
+            folded: Default::default(),
+            uses: Default::default(),
+            accessed: Default::default(),
+            futures: Default::default(),
+            finalize_arguments: None,
+            statements: Vec::new(),
+        }
+    }
+
+    /// Declares an input register and returns the corresponding Leo input.
+    pub(crate) fn input(&mut self, register: &str, type_: &str) -> Result<Input> {
+        let (input, type_) = self.context.input(register, type_)?;
+        self.types.insert(register.to_string(), type_);
+        Ok(input)
+    }
+
+    /// Reconstructs the body of a closure or function, ending in a return statement.
+    pub(crate) fn body(&mut self, instructions: &[Instruction], outputs: &[(String, String)]) -> Result<Block> {
+        for instruction in instructions {
+            self.count_instruction_uses(instruction);
+        }
+        for (operand, _) in outputs {
+            self.count_uses(operand);
+        }
+
+        for instruction in instructions {
+            self.instruction(instruction, false)?;
+        }
+
+        let mut elements = Vec::new();
+        for (operand, type_) in outputs {
+            match ValueType::parse(type_, self.context.node_builder)? {
+                ValueType::Future => {}
+                _ => elements.push(self.operand(operand)?.0),
+            }
+        }
+        let expression = match elements.len() {
+            0 => Expression::Unit(UnitExpression { span: Span::default(), id: self.context.id() }),
+            1 => elements.remove(0),
+            _ => Expression::Tuple(TupleExpression { elements, span: Span::default(), id: self.context.id() }),
+        };
+        self.statements.push(Statement::Return(ReturnStatement {
+            expression,
+            finalize_arguments: self.finalize_arguments.take(),
+            span: Span::default(),
+            id: self.context.id(),
+        }));
+
+        Ok(self.block())
+    }
+
+    /// Reconstructs the body of a finalize block.
+    pub(crate) fn finalize(&mut self, commands: &[Instruction]) -> Result<Block> {
+        for command in commands {
+            self.count_instruction_uses(command);
+        }
+        for command in commands {
+            self.instruction(command, true)?;
+        }
+        Ok(self.block())
+    }
+
+    fn block(&mut self) -> Block {
+        Block { statements: std::mem::take(&mut self.statements), span: Span::default(), id: self.context.id() }
+    }
+
+    /// Counts the registers read by an instruction.
+    fn count_instruction_uses(&mut self, instruction: &Instruction) {
+        for operand in instruction.operands.iter() {
+            self.count_uses(operand);
+        }
+    }
+
+    /// Counts the registers read by an operand, e.g. `r0`, `r1.owner`, or `r2[r3]`.
+    fn count_uses(&mut self, operand: &str) {
+        let end = operand.find(['.', '[']).unwrap_or(operand.len());
+        let base = &operand[..end];
+        if is_register(base) {
+            *self.uses.entry(base.to_string()).or_default() += 1;
+            if end < operand.len() {
+                self.accessed.insert(base.to_string());
+            }
+        }
+        let mut rest = &operand[end..];
+        while let Some(start) = rest.find('[') {
+            let Some(length) = rest[start..].find(']') else { break };
+            self.count_uses(&rest[start + 1..start + length]);
+            rest = &rest[start + length..];
+        }
+    }
+
+    /// Binds `destination` to `expression`, either by folding it into its only use or with a `let` statement.
+    fn define(&mut self, destination: &str, expression: Expression, type_: Type, foldable: bool) {
+        self.types.insert(destination.to_string(), type_.clone());
+        if foldable && self.uses.get(destination) == Some(&1) && !self.accessed.contains(destination) {
+            self.folded.insert(destination.to_string(), expression);
+        } else {
+            let place = Expression::Identifier(self.context.identifier(destination));
+            self.statements.push(Statement::Definition(DefinitionStatement {
+                declaration_type: DeclarationType::Let,
+                place,
+                type_,
+                value: expression,
+                span: Span::default(),
+                id: self.context.id(),
+            }));
+        }
+    }
+
+    /// Appends a statement that evaluates `expression` for its side effects.
+    fn evaluate(&mut self, expression: Expression) {
+        self.statements.push(Statement::Expression(ExpressionStatement {
+            expression,
+            span: Span::default(),
+            id: self.context.id(),
+        }));
+    }
+
+    fn unsupported(&self, instruction: &Instruction, in_finalize: bool) -> DecompilerError {
+        match in_finalize {
+            true => DecompilerError::unsupported_command(instruction, self.function),
+            false => DecompilerError::unsupported_instruction(instruction, self.function),
+        }
+    }
+
+    /// Reconstructs a single instruction or command.
+    fn instruction(&mut self, instruction: &Instruction, in_finalize: bool) -> Result<()> {
+        let opcode = instruction.opcode.as_str();
+        let operands = &instruction.operands;
+        let destination = instruction.destinations.first().map(String::as_str);
+
+        if let (Some(op), [receiver], Some(destination)) = (unary_operation(opcode), operands.as_slice(), destination) {
+            let (receiver, type_) = self.operand(receiver)?;
+            let expression = Expression::Unary(UnaryExpression {
+                receiver: Box::new(receiver),
+                op,
+                span: Span::default(),
+                id: self.context.id(),
+            });
+            self.define(destination, expression, type_, true);
+            return Ok(());
+        }
+
+        if let (Some(op), [left, right], Some(destination)) =
+            (binary_operation(opcode), operands.as_slice(), destination)
+        {
+            let (left, left_type) = self.operand(left)?;
+            let (right, _) = self.operand(right)?;
+            let op = match (op, &left_type) {
+                (BinaryOperation::BitwiseAnd, Type::Boolean) => BinaryOperation::And,
+                (BinaryOperation::BitwiseOr, Type::Boolean) => BinaryOperation::Or,
+                (op, _) => op,
+            };
+            let type_ = match op {
+                BinaryOperation::Eq
+                | BinaryOperation::Neq
+                | BinaryOperation::Gt
+                | BinaryOperation::Gte
+                | BinaryOperation::Lt
+                | BinaryOperation::Lte => Type::Boolean,
+                _ => left_type,
+            };
+            let expression = Expression::Binary(BinaryExpression {
+                left: Box::new(left),
+                right: Box::new(right),
+                op,
+                span: Span::default(),
+                id: self.context.id(),
+            });
+            self.define(destination, expression, type_, true);
+            return Ok(());
+        }
+
+        match (opcode, operands.as_slice(), destination) {
+            ("ternary", [condition, if_true, if_false], Some(destination)) => {
+                let (condition, _) = self.operand(condition)?;
+                let (if_true, type_) = self.operand(if_true)?;
+                let (if_false, _) = self.operand(if_false)?;
+                let expression = Expression::Ternary(TernaryExpression {
+                    condition: Box::new(condition),
+                    if_true: Box::new(if_true),
+                    if_false: Box::new(if_false),
+                    span: Span::default(),
+                    id: self.context.id(),
+                });
+                self.define(destination, expression, type_, true);
+            }
+            ("assert.eq" | "assert.neq", [left, right], None) => {
+                let (left, _) = self.operand(left)?;
+                let variant = match (opcode, right.as_str()) {
+                    // `assert(x)` is compiled to `assert.eq x true`.
+                    ("assert.eq", "true") => AssertVariant::Assert(left),
+                    ("assert.eq", _) => AssertVariant::AssertEq(left, self.operand(right)?.0),
+                    _ => AssertVariant::AssertNeq(left, self.operand(right)?.0),
+                };
+                self.statements.push(Statement::Assert(AssertStatement {
+                    variant,
+                    span: Span::default(),
+                    id: self.context.id(),
+                }));
+            }
+            ("cast", _, Some(destination)) => {
+                let (expression, type_) = self.cast(instruction, in_finalize)?;
+                self.define(destination, expression, type_, true);
+            }
+            (_, _, Some(destination)) if opcode.starts_with("hash.") || opcode.starts_with("commit.") => {
+                let (kind, variant) = opcode.split_once('.').unwrap_or_default();
+                let (Some(core), Some(type_)) = (hasher(variant), &instruction.type_) else {
+                    return Err(self.unsupported(instruction, in_finalize).into());
+                };
+                let name = format!("{kind}_to_{type_}");
+                let type_ = parse_type(type_, self.context.node_builder)?;
+                let expression = self.associated_function(core, &name, operands)?;
+                self.define(destination, expression, type_, true);
+            }
+            ("sign.verify", [_, _, _], Some(destination)) => {
+                let expression = self.associated_function("signature", "verify", operands)?;
+                self.define(destination, expression, Type::Boolean, true);
+            }
+            ("rand.chacha", [], Some(destination)) => {
+                let Some(type_) = &instruction.type_ else {
+                    return Err(self.unsupported(instruction, in_finalize).into());
+                };
+                let name = match type_.as_str() {
+                    "boolean" => "rand_bool".to_string(),
+                    type_ => format!("rand_{type_}"),
+                };
+                let type_ = parse_type(type_, self.context.node_builder)?;
+                let expression = self.associated_function("ChaCha", &name, &[])?;
+                self.define(destination, expression, type_, false);
+            }
+            ("call", arguments, _) => {
+                let Some((program, function)) = &instruction.callee else {
+                    return Err(self.unsupported(instruction, in_finalize).into());
+                };
+                self.call(program.as_deref(), function, arguments, &instruction.destinations)?
+            }
+            ("async", arguments, Some(destination)) => {
+                let mut finalize_arguments = Vec::new();
+                for argument in arguments {
+                    // Futures are awaited implicitly in Leo, so they are not passed to the finalize block.
+                    if !self.futures.contains(argument) {
+                        finalize_arguments.push(self.operand(argument)?.0);
+                    }
+                }
+                self.finalize_arguments = Some(finalize_arguments);
+                self.futures.insert(destination.to_string());
+            }
+            ("await", [_], None) if in_finalize => {}
+            ("get" | "contains", [key], Some(destination)) if in_finalize => {
+                let mapping = self.mapping(instruction)?;
+                let (key, _) = self.operand(key)?;
+                let type_ = match opcode {
+                    "get" => self.context.mapping_value_type(&mapping)?,
+                    _ => Type::Boolean,
+                };
+                let expression = self.mapping_function(opcode, &mapping, vec![key]);
+                self.define(destination, expression, type_, false);
+            }
+            ("get.or_use", [key, default], Some(destination)) if in_finalize => {
+                let mapping = self.mapping(instruction)?;
+                let (key, _) = self.operand(key)?;
+                let (default, _) = self.operand(default)?;
+                let type_ = self.context.mapping_value_type(&mapping)?;
+                let expression = self.mapping_function("get_or_use", &mapping, vec![key, default]);
+                self.define(destination, expression, type_, false);
+            }
+            ("set", [key, value], None) if in_finalize => {
+                let mapping = self.mapping(instruction)?;
+                let (key, _) = self.operand(key)?;
+                let (value, _) = self.operand(value)?;
+                let expression = self.mapping_function("set", &mapping, vec![key, value]);
+                self.evaluate(expression);
+            }
+            ("remove", [key], None) if in_finalize => {
+                let mapping = self.mapping(instruction)?;
+                let (key, _) = self.operand(key)?;
+                let expression = self.mapping_function("remove", &mapping, vec![key]);
+                self.evaluate(expression);
+            }
+            _ => return Err(self.unsupported(instruction, in_finalize).into()),
+        }
+
+        Ok(())
+    }
+
+    /// Reconstructs a `cast` instruction as a cast, a struct or record initializer, an array, or a coordinate.
+    fn cast(&mut self, instruction: &Instruction, in_finalize: bool) -> Result<(Expression, Type)> {
+        let Some(target) = &instruction.type_ else {
+            return Err(self.unsupported(instruction, in_finalize).into());
+        };
+        let operands = &instruction.operands;
+
+        if let ("group.x" | "group.y", [operand]) = (target.as_str(), operands.as_slice()) {
+            let op = match target.as_str() {
+                "group.x" => UnaryOperation::ToXCoordinate,
+                _ => UnaryOperation::ToYCoordinate,
+            };
+            let (receiver, _) = self.operand(operand)?;
+            let expression = Expression::Unary(UnaryExpression {
+                receiver: Box::new(receiver),
+                op,
+                span: Span::default(),
+                id: self.context.id(),
+            });
+            return Ok((expression, Type::Field));
+        }
+
+        if target.starts_with('[') {
+            let type_ = parse_type(target, self.context.node_builder)?;
+            let elements = operands.iter().map(|operand| Ok(self.operand(operand)?.0)).collect::<Result<Vec<_>>>()?;
+            let expression =
+                Expression::Array(ArrayExpression { elements, span: Span::default(), id: self.context.id() });
+            return Ok((expression, type_));
+        }
+
+        let composite = target.strip_suffix(".record").unwrap_or(target);
+        if let Some(members) = self.context.members(composite) {
+            if members.len() != operands.len() {
+                return Err(self.unsupported(instruction, in_finalize).into());
+            }
+            let mut initializers = Vec::new();
+            for (member, operand) in members.iter().zip(operands.iter()) {
+                let (expression, _) = self.operand(operand)?;
+                initializers.push(StructVariableInitializer {
+                    identifier: self.context.identifier(member),
+                    expression: Some(expression),
+                    span: Span::default(),
+                    id: self.context.id(),
+                });
+            }
+            let name = self.context.identifier(composite);
+            let expression = Expression::Struct(StructExpression {
+                name,
+                members: initializers,
+                span: Span::default(),
+                id: self.context.id(),
+            });
+            return Ok((expression, Type::Identifier(name)));
+        }
+
+        let [operand] = operands.as_slice() else {
+            return Err(self.unsupported(instruction, in_finalize).into());
+        };
+        let (expression, _) = self.operand(operand)?;
+        let type_ = parse_type(target, self.context.node_builder)?;
+        let expression = Expression::Cast(CastExpression {
+            expression: Box::new(expression),
+            type_: type_.clone(),
+            span: Span::default(),
+            id: self.context.id(),
+        });
+        Ok((expression, type_))
+    }
+
+    /// Reconstructs a `call` instruction, binding the values it returns other than futures.
+    fn call(
+        &mut self,
+        program: Option<&str>,
+        function: &str,
+        arguments: &[String],
+        destinations: &[String],
+    ) -> Result<()> {
+        let arguments = arguments.iter().map(|argument| Ok(self.operand(argument)?.0)).collect::<Result<Vec<_>>>()?;
+        let expression = Expression::Call(CallExpression {
+            function: Box::new(Expression::Identifier(self.context.identifier(function))),
+            arguments,
+            external: program.map(|program| Box::new(Expression::Identifier(self.context.identifier(program)))),
+            span: Span::default(),
+            id: self.context.id(),
+        });
+
+        let mut results = Vec::new();
+        if !destinations.is_empty() {
+            for (destination, output) in destinations.iter().zip(self.context.call_outputs(program, function)?) {
+                let type_ = match output {
+                    ValueType::Plaintext(type_, _) | ValueType::Record(type_) => type_,
+                    ValueType::ExternalRecord(_, record) => Type::Identifier(self.context.identifier(&record)),
+                    ValueType::Future => {
+                        self.futures.insert(destination.clone());
+                        continue;
+                    }
+                };
+                results.push((destination.as_str(), type_));
+            }
+        }
+
+        match results.len() {
+            0 => self.evaluate(expression),
+            1 => {
+                let (destination, type_) = results.remove(0);
+                self.define(destination, expression, type_, false);
+            }
+            _ => {
+                let mut elements = Vec::new();
+                let mut types = Vec::new();
+                for (destination, type_) in results {
+                    elements.push(Expression::Identifier(self.context.identifier(destination)));
+                    self.types.insert(destination.to_string(), type_.clone());
+                    types.push(type_);
+                }
+                self.statements.push(Statement::Definition(DefinitionStatement {
+                    declaration_type: DeclarationType::Let,
+                    place: Expression::Tuple(TupleExpression {
+                        elements,
+                        span: Span::default(),
+                        id: self.context.id(),
+                    }),
+                    type_: Type::Tuple(TupleType::new(types)),
+                    value: expression,
+                    span: Span::default(),
+                    id: self.context.id(),
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the name of the mapping accessed by a mapping command.
+    fn mapping(&self, instruction: &Instruction) -> Result<String> {
+        match &instruction.mapping {
+            Some((None, mapping)) => Ok(mapping.clone()),
+            // Leo programs cannot read the mappings of other programs.
+            _ => Err(self.unsupported(instruction, true).into()),
+        }
+    }
+
+    /// Returns a call to `Mapping::<name>` on the given mapping.
+    fn mapping_function(&self, name: &str, mapping: &str, arguments: Vec<Expression>) -> Expression {
+        let mut all_arguments = vec![Expression::Identifier(self.context.identifier(mapping))];
+        all_arguments.extend(arguments);
+        Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+            ty: Type::Identifier(self.context.identifier("Mapping")),
+            name: self.context.identifier(name),
+            arguments: all_arguments,
+            span: Span::default(),
+            id: self.context.id(),
+        }))
+    }
+
+    /// Returns a call to the core function `<ty>::<name>` on the given operands.
+    fn associated_function(&mut self, ty: &str, name: &str, operands: &[String]) -> Result<Expression> {
+        let arguments = operands.iter().map(|operand| Ok(self.operand(operand)?.0)).collect::<Result<Vec<_>>>()?;
+        Ok(Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+            ty: Type::Identifier(self.context.identifier(ty)),
+            name: self.context.identifier(name),
+            arguments,
+            span: Span::default(),
+            id: self.context.id(),
+        })))
+    }
+
+    /// Reconstructs an operand and its type.
+    fn operand(&mut self, operand: &str) -> Result<(Expression, Type)> {
+        match operand {
+            "self.caller" | "self.signer" => return Ok((self.member(sym::SelfLower, &operand[5..]), Type::Address)),
+            "block.height" => return Ok((self.member(sym::block, "height"), Type::Integer(IntegerType::U32))),
+            "group::GEN" => {
+                let expression = Expression::Access(AccessExpression::AssociatedConstant(AssociatedConstant {
+                    ty: Type::Group,
+                    name: self.context.identifier("GEN"),
+                    span: Span::default(),
+                    id: self.context.id(),
+                }));
+                return Ok((expression, Type::Group));
+            }
+            _ => {}
+        }
+
+        let end = operand.find(['.', '[']).unwrap_or(operand.len());
+        let base = &operand[..end];
+        if !is_register(base) {
+            return self.literal(operand);
+        }
+
+        let Some(type_) = self.types.get(base).cloned() else {
+            return Err(DecompilerError::undefined_register(base, self.function).into());
+        };
+        let expression = match self.folded.remove(base) {
+            Some(expression) => expression,
+            None => Expression::Identifier(self.context.identifier(base)),
+        };
+        self.access(expression, type_, &operand[end..])
+    }
+
+    /// Applies a chain of member and index accesses, e.g. `.owner` or `[0u32].x`, to `expression`.
+    fn access(&mut self, mut expression: Expression, mut type_: Type, mut path: &str) -> Result<(Expression, Type)> {
+        while !path.is_empty() {
+            if let Some(rest) = path.strip_prefix('.') {
+                let end = rest.find(['.', '[']).unwrap_or(rest.len());
+                let member = &rest[..end];
+                type_ = self.context.member_type(&type_, member)?;
+                expression = Expression::Access(AccessExpression::Member(MemberAccess {
+                    inner: Box::new(expression),
+                    name: self.context.identifier(member),
+                    span: Span::default(),
+                    id: self.context.id(),
+                }));
+                path = &rest[end..];
+            } else if let Some((index, rest)) = path.strip_prefix('[').and_then(|path| path.split_once(']')) {
+                let Type::Array(array_type) = type_ else {
+                    return Err(DecompilerError::unsupported_type(format!("{type_}{path}")).into());
+                };
+                type_ = array_type.element_type().clone();
+                let (index, _) = self.operand(index)?;
+                expression = Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: Box::new(expression),
+                    index: Box::new(index),
+                    span: Span::default(),
+                    id: self.context.id(),
+                }));
+                path = rest;
+            } else {
+                return Err(DecompilerError::unsupported_type(path).into());
+            }
+        }
+        Ok((expression, type_))
+    }
+
+    /// Returns an access to a member of `self` or `block`.
+    fn member(&self, inner: Symbol, name: &str) -> Expression {
+        Expression::Access(AccessExpression::Member(MemberAccess {
+            inner: Box::new(Expression::Identifier(Identifier::new(inner, self.context.id()))),
+            name: self.context.identifier(name),
+            span: Span::default(),
+            id: self.context.id(),
+        }))
+    }
+
+    /// Reconstructs a literal, e.g. `1u8`, `true`, `2field`, or a program ID.
+    fn literal(&self, text: &str) -> Result<(Expression, Type)> {
+        let (span, id) = (Span::default(), self.context.id());
+        let (literal, type_) = match text {
+            "true" | "false" => (Literal::Boolean(text == "true", span, id), Type::Boolean),
+            _ if text.starts_with("aleo1") => (Literal::Address(text.to_string(), span, id), Type::Address),
+            _ if text.ends_with(".aleo") => {
+                // Leo has no program ID literals, so the ID is replaced by the address of the program.
+                let address = ProgramID::<Testnet3>::from_str(text)
+                    .and_then(|program_id| program_id.to_address())
+                    .map_err(|_| DecompilerError::unsupported_type(text))?;
+                (Literal::Address(address.to_string(), span, id), Type::Address)
+            }
+            _ => {
+                if let Some(value) = text.strip_suffix("field") {
+                    (Literal::Field(value.to_string(), span, id), Type::Field)
+                } else if let Some(value) = text.strip_suffix("group") {
+                    (Literal::Group(Box::new(GroupLiteral::Single(value.to_string(), span, id))), Type::Group)
+                } else if let Some(value) = text.strip_suffix("scalar") {
+                    (Literal::Scalar(value.to_string(), span, id), Type::Scalar)
+                } else {
+                    let start = text.find(['u', 'i']).ok_or_else(|| DecompilerError::unsupported_type(text))?;
+                    let integer_type =
+                        integer_type(&text[start..]).ok_or_else(|| DecompilerError::unsupported_type(text))?;
+                    (Literal::Integer(integer_type, text[..start].to_string(), span, id), Type::Integer(integer_type))
+                }
+            }
+        };
+        Ok((Expression::Literal(literal), type_))
+    }
+}
+
+/// Returns `true` if `text` names a register, e.g. `r12`.
+fn is_register(text: &str) -> bool {
+    text.strip_prefix('r').map_or(false, |index| !index.is_empty() && index.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Returns the Leo operator for a unary opcode.
+fn unary_operation(opcode: &str) -> Option<UnaryOperation> {
+    Some(match opcode {
+        "abs" => UnaryOperation::Abs,
+        "abs.w" => UnaryOperation::AbsWrapped,
+        "double" => UnaryOperation::Double,
+        "inv" => UnaryOperation::Inverse,
+        "neg" => UnaryOperation::Negate,
+        "not" => UnaryOperation::Not,
+        "square" => UnaryOperation::Square,
+        "sqrt" => UnaryOperation::SquareRoot,
+        _ => return None,
+    })
+}
+
+/// Returns the Leo operator for a binary opcode.
+/// Note that `and` and `or` are resolved to their logical variants when applied to booleans.
+fn binary_operation(opcode: &str) -> Option<BinaryOperation> {
+    Some(match opcode {
+        "add" => BinaryOperation::Add,
+        "add.w" => BinaryOperation::AddWrapped,
+        "and" => BinaryOperation::BitwiseAnd,
+        "div" => BinaryOperation::Div,
+        "div.w" => BinaryOperation::DivWrapped,
+        "is.eq" => BinaryOperation::Eq,
+        "gte" => BinaryOperation::Gte,
+        "gt" => BinaryOperation::Gt,
+        "lte" => BinaryOperation::Lte,
+        "lt" => BinaryOperation::Lt,
+        "mod" => BinaryOperation::Mod,
+        "mul" => BinaryOperation::Mul,
+        "mul.w" => BinaryOperation::MulWrapped,
+        "nand" => BinaryOperation::Nand,
+        "is.neq" => BinaryOperation::Neq,
+        "nor" => BinaryOperation::Nor,
+        "or" => BinaryOperation::BitwiseOr,
+        "pow" => BinaryOperation::Pow,
+        "pow.w" => BinaryOperation::PowWrapped,
+        "rem" => BinaryOperation::Rem,
+        "rem.w" => BinaryOperation::RemWrapped,
+        "shl" => BinaryOperation::Shl,
+        "shl.w" => BinaryOperation::ShlWrapped,
+        "shr" => BinaryOperation::Shr,
+        "shr.w" => BinaryOperation::ShrWrapped,
+        "sub" => BinaryOperation::Sub,
+        "sub.w" => BinaryOperation::SubWrapped,
+        "xor" => BinaryOperation::Xor,
+        _ => return None,
+    })
+}
+
+/// Returns the Leo core struct for a hash or commitment variant, e.g. `BHP256` for `bhp256`.
+fn hasher(variant: &str) -> Option<&'static str> {
+    Some(match variant {
+        "bhp256" => "BHP256",
+        "bhp512" => "BHP512",
+        "bhp768" => "BHP768",
+        "bhp1024" => "BHP1024",
+        "keccak256" => "Keccak256",
+        "keccak384" => "Keccak384",
+        "keccak512" => "Keccak512",
+        "ped64" => "Pedersen64",
+        "ped128" => "Pedersen128",
+        "psd2" => "Poseidon2",
+        "psd4" => "Poseidon4",
+        "psd8" => "Poseidon8",
+        "sha3_256" => "SHA3_256",
+        "sha3_384" => "SHA3_384",
+        "sha3_512" => "SHA3_512",
+        _ => return None,
+    })
+}
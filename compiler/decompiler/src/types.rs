@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{ArrayType, Identifier, IntegerType, Mode, NodeBuilder, NonNegativeNumber, Type};
+use leo_errors::{DecompilerError, Result};
+use leo_span::Symbol;
+
+/// The type of an input, an output, or a record entry in Aleo bytecode.
+#[derive(Clone, Debug)]
+pub(crate) enum ValueType {
+    /// A plaintext value with a visibility, e.g. `u32.public`.
+    Plaintext(Type, Mode),
+    /// A record defined in the current program, e.g. `Token.record`.
+    Record(Type),
+    /// A record defined in an imported program, e.g. `credits.aleo/credits.record`.
+    ExternalRecord(String, String),
+    /// A future produced by a function with a finalize block, e.g. `credits.aleo/transfer_public.future`.
+    Future,
+}
+
+impl ValueType {
+    /// Parses a value type, e.g. `u64.private`, `Token.record`, or `[u8; 4u32]`.
+    pub(crate) fn parse(text: &str, node_builder: &NodeBuilder) -> Result<Self> {
+        if text.ends_with(".future") {
+            return Ok(Self::Future);
+        }
+        if let Some(record) = text.strip_suffix(".record") {
+            return Ok(match record.split_once('/') {
+                Some((program, record)) => {
+                    Self::ExternalRecord(program.trim_end_matches(".aleo").to_string(), record.to_string())
+                }
+                None => Self::Record(Type::Identifier(Identifier::new(Symbol::intern(record), node_builder.next_id()))),
+            });
+        }
+        // Private is the default mode of inputs and outputs, so it is left implicit.
+        let (text, mode) = match text.rsplit_once('.') {
+            Some((text, "public")) => (text, Mode::Public),
+            Some((text, "private")) => (text, Mode::None),
+            Some((text, "constant")) => (text, Mode::Constant),
+            _ => (text, Mode::None),
+        };
+        Ok(Self::Plaintext(parse_type(text, node_builder)?, mode))
+    }
+}
+
+/// Parses a plaintext type, e.g. `u8`, `Point`, or `[[bool; 2u32]; 4u32]`.
+pub(crate) fn parse_type(text: &str, node_builder: &NodeBuilder) -> Result<Type> {
+    let text = text.trim();
+    if let Some(array) = text.strip_prefix('[').and_then(|text| text.strip_suffix(']')) {
+        // The length follows the last semicolon, since the element type may itself be an array.
+        let (element, length) = array.rsplit_once(';').ok_or_else(|| DecompilerError::unsupported_type(text))?;
+        let length = length
+            .trim()
+            .trim_end_matches("u32")
+            .parse::<usize>()
+            .map_err(|_| DecompilerError::unsupported_type(text))?;
+        return Ok(Type::Array(ArrayType::new(parse_type(element, node_builder)?, NonNegativeNumber::from(length))));
+    }
+
+    Ok(match text {
+        "address" => Type::Address,
+        "boolean" => Type::Boolean,
+        "field" => Type::Field,
+        "group" => Type::Group,
+        "scalar" => Type::Scalar,
+        "signature" => Type::Signature,
+        "string" => Type::String,
+        _ => match integer_type(text) {
+            Some(integer_type) => Type::Integer(integer_type),
+            None if is_identifier(text) => {
+                Type::Identifier(Identifier::new(Symbol::intern(text), node_builder.next_id()))
+            }
+            None => return Err(DecompilerError::unsupported_type(text).into()),
+        },
+    })
+}
+
+/// Returns the integer type with the given name, e.g. `u32`.
+pub(crate) fn integer_type(text: &str) -> Option<IntegerType> {
+    Some(match text {
+        "u8" => IntegerType::U8,
+        "u16" => IntegerType::U16,
+        "u32" => IntegerType::U32,
+        "u64" => IntegerType::U64,
+        "u128" => IntegerType::U128,
+        "i8" => IntegerType::I8,
+        "i16" => IntegerType::I16,
+        "i32" => IntegerType::I32,
+        "i64" => IntegerType::I64,
+        "i128" => IntegerType::I128,
+        _ => return None,
+    })
+}
+
+/// Returns `true` if `text` is a valid Aleo identifier.
+pub(crate) fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic()) && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_compiler::Compiler;
+use leo_decompiler::Decompiler;
+use leo_errors::emitter::Handler;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use std::fs;
+
+/// Compiles `source` as the program `test.aleo` and returns its bytecode.
+fn compile(source: &str) -> String {
+    let directory = tempfile::tempdir().expect("failed to create a temporary directory");
+    let main_file_path = directory.path().join("main.leo");
+    fs::write(&main_file_path, source).expect("failed to write the program");
+
+    let handler = Handler::default();
+    let mut compiler = Compiler::new(
+        "test".to_string(),
+        "aleo".to_string(),
+        &handler,
+        main_file_path,
+        directory.path().to_path_buf(),
+        None,
+    );
+    match compiler.compile() {
+        Ok(artifacts) => artifacts.bytecode,
+        Err(error) => panic!("failed to compile:\n{source}\n{error}"),
+    }
+}
+
+/// Decompiles `bytecode` and prints the resulting Leo program.
+fn decompile(bytecode: &str) -> String {
+    Decompiler::default().decompile(bytecode).expect("failed to decompile").to_string()
+}
+
+/// Checks that decompiling the bytecode of `source` yields a Leo program that compiles,
+/// and that compiling and decompiling it again is a fixed point.
+///
+/// The first round may differ from the original program, since folding registers into expressions
+/// can reorder independent instructions, and closures come back as inline functions that are
+/// inlined once the program is compiled again.
+fn check_round_trip(source: &str) {
+    create_session_if_not_set_then(|_| {
+        let decompiled = decompile(&compile(&decompile(&compile(source))));
+        let bytecode = compile(&decompiled);
+        let redecompiled = decompile(&bytecode);
+        assert_eq!(decompiled, redecompiled, "the decompiled program does not round-trip");
+        assert_eq!(bytecode, compile(&redecompiled));
+    });
+}
+
+#[test]
+fn round_trip_token() {
+    check_round_trip(
+        r#"
+program test.aleo {
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    mapping balances: address => u64;
+
+    transition mint_private(receiver: address, amount: u64) -> Token {
+        return Token { owner: receiver, amount: amount };
+    }
+
+    transition transfer_private(token: Token, to: address, amount: u64) -> (Token, Token) {
+        let difference: u64 = token.amount - amount;
+        let remaining: Token = Token { owner: token.owner, amount: difference };
+        let transferred: Token = Token { owner: to, amount: amount };
+        return (remaining, transferred);
+    }
+
+    transition mint_public(public receiver: address, public amount: u64) {
+        return then finalize(receiver, amount);
+    }
+
+    finalize mint_public(public receiver: address, public amount: u64) {
+        let current_amount: u64 = Mapping::get_or_use(balances, receiver, 0u64);
+        Mapping::set(balances, receiver, current_amount + amount);
+    }
+
+    transition burn_public(public amount: u64) {
+        return then finalize(self.caller, amount);
+    }
+
+    finalize burn_public(public owner: address, public amount: u64) {
+        assert(balances.contains(owner));
+        let current_amount: u64 = balances.get(owner);
+        assert(current_amount >= amount);
+        balances.set(owner, current_amount - amount);
+    }
+}
+"#,
+    );
+}
+
+#[test]
+fn round_trip_expressions() {
+    check_round_trip(
+        r#"
+program test.aleo {
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    transition main(a: u32, b: u32, flag: bool, p: Point) -> (u32, bool, field, Point) {
+        let sum: u32 = a + b * 2u32;
+        let wrapped: u32 = sum.add_wrapped(a).pow_wrapped(2u8);
+        let chosen: u32 = flag ? sum : wrapped;
+        let both: bool = flag && a > b || !flag;
+        let hashed: field = BHP256::hash_to_field(p);
+        let moved: Point = Point { x: p.x - 1i32, y: -p.y };
+        assert_neq(chosen, 0u32);
+        return (chosen >> 1u8, both, hashed + 1field, moved);
+    }
+
+    function scale(value: u32, factor: u32) -> (u32, u32) {
+        return (value * factor, value / factor);
+    }
+
+    transition calls(a: u32) -> u32 {
+        let (product, quotient): (u32, u32) = scale(a, 3u32);
+        return product % quotient;
+    }
+
+    transition arrays(values: [u8; 4]) -> (u8, [u8; 2]) {
+        return (values[0u32] ^ values[3u32], [values[1u32], values[2u32]]);
+    }
+}
+"#,
+    );
+}
@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::NodeBuilder;
+use leo_compiler::{Compiler, MemoryFileSystem};
+use leo_decompiler::Decompiler;
+use leo_errors::{emitter::Handler, Result};
+use leo_span::symbol::create_session_if_not_set_then;
+
+use std::path::PathBuf;
+
+const TOKEN: &str = r#"
+program token.aleo {
+    record Token {
+        owner: address,
+        amount: u64,
+    }
+
+    mapping balances: address => u64;
+
+    transition mint_private(receiver: address, amount: u64) -> Token {
+        return Token { owner: receiver, amount: amount };
+    }
+
+    transition transfer_public(public receiver: address, public amount: u64) {
+        return then finalize(self.caller, receiver, amount);
+    }
+
+    finalize transfer_public(sender: address, receiver: address, amount: u64) {
+        let sender_amount: u64 = Mapping::get_or_use(balances, sender, 0u64);
+        Mapping::set(balances, sender, sender_amount - amount);
+        let receiver_amount: u64 = Mapping::get_or_use(balances, receiver, 0u64);
+        Mapping::set(balances, receiver, receiver_amount + amount);
+    }
+}
+"#;
+
+/// Compiles the program `name` from `source` in `file_system` and returns its bytecode.
+fn compile(file_system: &MemoryFileSystem, name: &str, source: &str) -> Result<String> {
+    let main_file_path = PathBuf::from(format!("{name}.leo"));
+    file_system.insert(&main_file_path, source);
+
+    let handler = Handler::default();
+    let mut compiler =
+        Compiler::new(name.to_string(), "aleo".to_string(), &handler, main_file_path, PathBuf::from("outputs"), None)
+            .with_file_system(file_system);
+    compiler.compile().map(|artifacts| artifacts.bytecode)
+}
+
+#[test]
+fn import_aleo_program() {
+    create_session_if_not_set_then(|_| {
+        let file_system = MemoryFileSystem::new();
+        let token = compile(&file_system, "token", TOKEN).expect("failed to compile the token program");
+
+        // The stub keeps the signatures of the transitions but none of their bodies.
+        let stub = Decompiler::stub(&token, &NodeBuilder::default()).expect("failed to generate the stub");
+        assert_eq!(stub.structs.len(), 1);
+        assert_eq!(stub.mappings.len(), 1);
+        assert_eq!(stub.functions.iter().map(|(_, function)| function.to_string()).collect::<Vec<_>>(), vec![
+            "transition mint_private(r0: address, r1: u64) -> Token;",
+            "transition transfer_public(public r0: address, public r1: u64);\n    finalize transfer_public(public r0: address, public r1: address, public r2: u64);",
+        ]);
+
+        // Imports are resolved against the `imports` directory of the file system.
+        file_system.insert("imports/token.aleo", token);
+
+        let bytecode = compile(
+            &file_system,
+            "test",
+            r#"
+import token.aleo;
+program test.aleo {
+    transition mint(receiver: address) -> token.aleo/Token.record {
+        return token.aleo/mint_private(receiver, 100u64);
+    }
+
+    transition transfer(receiver: address) {
+        token.aleo/transfer_public(receiver, 1u64);
+    }
+}
+"#,
+        )
+        .expect("failed to compile a program importing an Aleo program");
+        assert!(bytecode.starts_with("import token.aleo;\n"));
+        assert!(bytecode.contains("call token.aleo/mint_private r0 100u64 into r1;"));
+        assert!(bytecode.contains("call token.aleo/transfer_public r0 1u64 into r1;"));
+        assert!(bytecode.contains("input r0 as token.aleo/transfer_public.future;"));
+
+        // Calls are checked against the signatures in the stub.
+        let mismatched = r#"
+import token.aleo;
+program test.aleo {
+    transition mint(receiver: address) -> token.aleo/Token.record {
+        return token.aleo/mint_private(receiver, 100u32);
+    }
+}
+"#;
+        assert!(compile(&file_system, "test", mismatched).is_err());
+    });
+}
+
+#[test]
+fn call_to_unknown_function() {
+    create_session_if_not_set_then(|_| {
+        let file_system = MemoryFileSystem::new();
+        let token = compile(&file_system, "token", TOKEN).expect("failed to compile the token program");
+
+        let bytecode = r#"import token.aleo;
+program test.aleo;
+
+function mint:
+    input r0 as address.private;
+    call token.aleo/mint_public r0 100u64 into r1;
+    output r1 as token.aleo/Token.record;
+"#;
+        let mut decompiler = Decompiler::default();
+        decompiler.add_import(&token).expect("failed to add the import");
+        let error = decompiler.decompile(bytecode).expect_err("decompiled a call to an unknown function");
+        assert_eq!(error.error_code(), "EDEC0378006");
+    });
+}
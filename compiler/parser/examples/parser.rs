@@ -18,7 +18,7 @@
 
 use leo_ast::{Ast, NodeBuilder};
 use leo_errors::emitter::Handler;
-use leo_span::symbol::create_session_if_not_set_then;
+use leo_span::{symbol::create_session_if_not_set_then, RealFileSystem};
 
 use clap::Parser;
 use std::{
@@ -48,7 +48,7 @@ fn main() -> Result<(), String> {
 
         Handler::with(|h| {
             let node_builder = NodeBuilder::default();
-            let ast = leo_parser::parse_ast(h, &node_builder, &code.src, code.start_pos)?;
+            let ast = leo_parser::parse_ast(h, &node_builder, &RealFileSystem, &code.src, code.start_pos)?;
             let json = Ast::to_json_string(&ast)?;
             println!("{json}");
             Ok(json)
@@ -24,7 +24,7 @@
 #![doc = include_str!("../README.md")]
 
 pub(crate) mod tokenizer;
-use leo_span::span::BytePos;
+use leo_span::{span::BytePos, FileSystem};
 pub use tokenizer::KEYWORD_TOKENS;
 pub(crate) use tokenizer::*;
 
@@ -37,9 +37,15 @@ use leo_errors::{emitter::Handler, Result};
 #[cfg(test)]
 mod test;
 
-/// Creates a new AST from a given file path and source code text.
-pub fn parse_ast(handler: &Handler, node_builder: &NodeBuilder, source: &str, start_pos: BytePos) -> Result<Ast> {
-    Ok(Ast::new(parser::parse(handler, node_builder, source, start_pos)?))
+/// Creates a new AST from a given file path and source code text, reading its imports from `file_system`.
+pub fn parse_ast(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    file_system: &dyn FileSystem,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<Ast> {
+    Ok(Ast::new(parser::parse(handler, node_builder, file_system, source, start_pos)?))
 }
 
 /// Parses program inputs from the input file path
@@ -18,9 +18,9 @@ use crate::{tokenizer::*, Token};
 
 use leo_ast::*;
 use leo_errors::{emitter::Handler, ParserError, ParserWarning, Result};
-use leo_span::{Span, Symbol};
+use leo_span::{span::BytePos, sym, FileSystem, Span, Symbol};
 
-use std::{fmt::Display, mem};
+use std::{collections::HashMap, fmt::Display, mem};
 
 /// Stores a program in tokenized format plus additional context.
 /// May be converted into a [`Program`] AST by parsing all tokens.
@@ -29,6 +29,8 @@ pub(crate) struct ParserContext<'a> {
     pub(crate) handler: &'a Handler,
     /// Counter used to generate unique node ids.
     pub(crate) node_builder: &'a NodeBuilder,
+    /// The file system that imports are read from.
+    pub(crate) file_system: &'a dyn FileSystem,
     /// All un-bumped tokens.
     tokens: Vec<SpannedToken>,
     /// The current token, i.e., if `p.tokens = ['3', *, '4']`,
@@ -41,6 +43,10 @@ pub(crate) struct ParserContext<'a> {
     pub(crate) disallow_struct_construction: bool,
     /// true if parsing an identifier inside an input file.
     pub(crate) allow_identifier_underscores: bool,
+    /// The value types of the mappings parsed thus far, used to fix deprecated mapping operations.
+    pub(crate) mapping_value_types: HashMap<Symbol, Type>,
+    /// The doc comments, by the start of the token after them.
+    doc_comments: HashMap<BytePos, String>,
 }
 
 /// Dummy span used to appease borrow checker.
@@ -48,9 +54,27 @@ const DUMMY_EOF: SpannedToken = SpannedToken { token: Token::Eof, span: Span::du
 
 impl<'a> ParserContext<'a> {
     /// Returns a new [`ParserContext`] type given a vector of tokens.
-    pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder, mut tokens: Vec<SpannedToken>) -> Self {
-        // Strip out comments.
-        tokens.retain(|x| !matches!(x.token, Token::CommentLine(_) | Token::CommentBlock(_)));
+    pub fn new(
+        handler: &'a Handler,
+        node_builder: &'a NodeBuilder,
+        file_system: &'a dyn FileSystem,
+        mut tokens: Vec<SpannedToken>,
+    ) -> Self {
+        // Strip out comments, keeping doc comments for the token after them.
+        let mut doc_comments = HashMap::new();
+        let mut doc_comment = Vec::new();
+        tokens.retain(|x| match &x.token {
+            Token::CommentLine(comment) | Token::CommentBlock(comment) => {
+                doc_comment.extend(doc_comment_lines(comment));
+                false
+            }
+            _ => {
+                if !doc_comment.is_empty() {
+                    doc_comments.insert(x.span.lo, mem::take(&mut doc_comment).join("\n"));
+                }
+                true
+            }
+        });
         // For performance we reverse so that we get cheap `.pop()`s.
         tokens.reverse();
 
@@ -58,8 +82,11 @@ impl<'a> ParserContext<'a> {
         let mut p = Self {
             handler,
             node_builder,
+            file_system,
             disallow_struct_construction: false,
             allow_identifier_underscores: false,
+            mapping_value_types: HashMap::new(),
+            doc_comments,
             prev_token: token.clone(),
             token,
             tokens,
@@ -85,6 +112,11 @@ impl<'a> ParserContext<'a> {
         self.prev_token = mem::replace(&mut self.token, next_token);
     }
 
+    /// Returns the doc comment before the current token, if there is one.
+    pub(super) fn doc_comment(&self) -> Option<String> {
+        self.doc_comments.get(&self.token.span.lo).cloned()
+    }
+
     /// Checks whether the current token is `tok`.
     pub(super) fn check(&self, tok: &Token) -> bool {
         &self.token.token == tok
@@ -101,6 +133,14 @@ impl<'a> ParserContext<'a> {
         self.check(token).then(|| self.bump()).is_some()
     }
 
+    /// Returns `true` if the next token is the suffix of an external program, i.e. `leo` or `aleo` followed by `/`.
+    /// Advances the parser past the suffix.
+    pub(super) fn eat_program_suffix(&mut self) -> bool {
+        let is_aleo = matches!(self.token.token, Token::Identifier(name) if name == sym::aleo)
+            && self.look_ahead(1, |next| next.token == Token::Div);
+        self.eat(&Token::Leo) || is_aleo.then(|| self.bump()).is_some()
+    }
+
     /// Look-ahead `dist` tokens of `self.token` and get access to that token there.
     /// When `dist == 0` then the current token is looked at.
     pub(super) fn look_ahead<'s, R>(&'s self, dist: usize, looker: impl FnOnce(&'s SpannedToken) -> R) -> R {
@@ -253,3 +293,29 @@ impl<'a> ParserContext<'a> {
         matches!(self.token.token, Token::LeftParen)
     }
 }
+
+/// Returns the lines of a `///` or `/** */` doc comment, or nothing if `comment` is an ordinary comment.
+fn doc_comment_lines(comment: &str) -> Vec<String> {
+    if let Some(line) = comment.strip_prefix("///").filter(|line| !line.starts_with('/')) {
+        let line = line.trim_end_matches(['\n', '\r']);
+        return vec![line.strip_prefix(' ').unwrap_or(line).to_string()];
+    }
+    match comment.strip_prefix("/**").and_then(|block| block.strip_suffix("*/")) {
+        Some(block) if !comment.starts_with("/**/") => {
+            let mut lines = block
+                .lines()
+                .map(|line| {
+                    let line = line.trim_start();
+                    let line = line.strip_prefix('*').unwrap_or(line);
+                    line.strip_prefix(' ').unwrap_or(line).trim_end().to_string()
+                })
+                .skip_while(String::is_empty)
+                .collect::<Vec<_>>();
+            while lines.last().map_or(false, String::is_empty) {
+                lines.pop();
+            }
+            lines
+        }
+        _ => Vec::new(),
+    }
+}
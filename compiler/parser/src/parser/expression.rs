@@ -449,7 +449,7 @@ impl ParserContext<'_> {
                         span,
                         id: self.node_builder.next_id(),
                     }))
-                } else if self.eat(&Token::Leo) {
+                } else if self.eat_program_suffix() {
                     // Eat an external function call.
                     self.eat(&Token::Div); // todo: Make `/` a more general token.
 
@@ -562,11 +562,15 @@ impl ParserContext<'_> {
         let (advanced, gc) = self.look_ahead(*dist, |t0| match &t0.token {
             Token::Add => Some((1, GroupCoordinate::SignHigh)),
             Token::Sub => self.look_ahead(*dist + 1, |t1| match &t1.token {
-                Token::Integer(value) => Some((2, GroupCoordinate::Number(format!("-{value}"), t1.span))),
+                Token::Integer(value) => {
+                    Some((2, GroupCoordinate::Number(format!("-{}", Literal::decimal_digits(value)), t1.span)))
+                }
                 _ => Some((1, GroupCoordinate::SignLow)),
             }),
             Token::Underscore => Some((1, GroupCoordinate::Inferred)),
-            Token::Integer(value) => Some((1, GroupCoordinate::Number(value.clone(), t0.span))),
+            Token::Integer(value) => {
+                Some((1, GroupCoordinate::Number(Literal::decimal_digits(value).into_owned(), t0.span)))
+            }
             _ => None,
         })?;
         *dist += advanced;
@@ -685,8 +689,9 @@ impl ParserContext<'_> {
                     // Literal followed by `group`, e.g., `42group`.
                     Some(Token::Group) => {
                         assert_no_whitespace("group")?;
+                        // Group literals are written in decimal in the AST, as they are not checked by `Value`.
                         Expression::Literal(Literal::Group(Box::new(GroupLiteral::Single(
-                            value,
+                            Literal::decimal_digits(&value).into_owned(),
                             full_span,
                             self.node_builder.next_id(),
                         ))))
@@ -19,12 +19,17 @@ use crate::parse_ast;
 use leo_errors::{CompilerError, ParserError, Result};
 use leo_span::{source_map::FileName, symbol::with_session_globals};
 
-use std::fs;
+/// A parsed import, either a Leo program or an Aleo program whose stub is resolved by the compiler.
+pub(super) enum Import {
+    Program((Program, Span)),
+    Stub(Stub),
+}
 
 impl ParserContext<'_> {
     /// Returns a [`Program`] AST if all tokens can be consumed and represent a valid Leo program.
     pub fn parse_program(&mut self) -> Result<Program> {
         let mut imports = IndexMap::new();
+        let mut stubs = IndexMap::new();
         let mut program_scopes = IndexMap::new();
 
         // TODO: Remove restrictions on multiple program scopes
@@ -32,10 +37,14 @@ impl ParserContext<'_> {
 
         while self.has_next() {
             match &self.token.token {
-                Token::Import => {
-                    let (id, import) = self.parse_import()?;
-                    imports.insert(id, import);
-                }
+                Token::Import => match self.parse_import()? {
+                    (id, Import::Program(import)) => {
+                        imports.insert(id, import);
+                    }
+                    (id, Import::Stub(stub)) => {
+                        stubs.insert(id, stub);
+                    }
+                },
                 Token::Program => {
                     match parsed_program_scope {
                         // Only one program scope is allowed per file.
@@ -56,7 +65,7 @@ impl ParserContext<'_> {
             return Err(ParserError::missing_program_scope(self.token.span).into());
         }
 
-        Ok(Program { imports, program_scopes })
+        Ok(Program { imports, stubs, program_scopes })
     }
 
     fn unexpected_item(token: &SpannedToken, expected: &[Token]) -> ParserError {
@@ -68,39 +77,53 @@ impl ParserContext<'_> {
     }
 
     // TODO: remove import resolution from parser.
-    /// Parses an import statement `import foo.leo;`.
-    pub(super) fn parse_import(&mut self) -> Result<(Symbol, (Program, Span))> {
+    /// Parses an import statement `import foo.leo;` or `import foo.aleo;`.
+    pub(super) fn parse_import(&mut self) -> Result<(Symbol, Import)> {
         // Parse `import`.
         let start = self.expect(&Token::Import)?;
 
         // Parse `foo`.
         let import_name = self.expect_identifier()?;
 
-        // Parse `.leo`.
+        // Parse `.leo` or `.aleo`.
         self.expect(&Token::Dot)?;
-        if !self.eat(&Token::Leo) {
+        let is_aleo = match &self.token.token {
+            Token::Leo => false,
+            Token::Identifier(name) if *name == sym::aleo => true,
             // Throw error for non-leo files.
-            return Err(ParserError::leo_imports_only(self.token.span).into());
-        }
+            _ => return Err(ParserError::leo_imports_only(self.token.span).into()),
+        };
+        self.bump();
 
         let end = self.expect(&Token::Semicolon)?;
 
+        // Aleo programs are only available as bytecode, so the compiler fills in their interface.
+        if is_aleo {
+            let stub_id =
+                ProgramId { name: import_name, network: Identifier::new(sym::aleo, self.node_builder.next_id()) };
+            let stub =
+                Stub { stub_id, structs: Vec::new(), mappings: Vec::new(), functions: Vec::new(), span: start + end };
+            return Ok((import_name.name, Import::Stub(stub)));
+        }
+
         // Tokenize and parse import file.
         // Todo: move this to a different module.
         let mut import_file_path =
-            std::env::current_dir().map_err(|err| CompilerError::cannot_open_cwd(err, self.token.span))?;
+            self.file_system.current_dir().map_err(|err| CompilerError::cannot_open_cwd(err, self.token.span))?;
         import_file_path.push("imports");
         import_file_path.push(format!("{}.leo", import_name.name));
 
         // Throw an error if the import file doesn't exist.
-        if !import_file_path.exists() {
+        if !self.file_system.exists(&import_file_path) {
             return Err(CompilerError::import_not_found(import_file_path.display(), self.prev_token.span).into());
         }
 
         // Read the import file into string.
         // Todo: protect against cyclic imports.
-        let program_string =
-            fs::read_to_string(&import_file_path).map_err(|e| CompilerError::file_read_error(&import_file_path, e))?;
+        let program_string = self
+            .file_system
+            .read_to_string(&import_file_path)
+            .map_err(|e| CompilerError::file_read_error(&import_file_path, e))?;
 
         // Create import file name.
         let name: FileName = FileName::Real(import_file_path);
@@ -109,9 +132,9 @@ impl ParserContext<'_> {
         let prg_sf = with_session_globals(|s| s.source_map.new_source(&program_string, name));
 
         // Use the parser to construct the imported abstract syntax tree (ast).
-        let program_ast = parse_ast(self.handler, self.node_builder, &prg_sf.src, prg_sf.start_pos)?;
+        let program_ast = parse_ast(self.handler, self.node_builder, self.file_system, &prg_sf.src, prg_sf.start_pos)?;
 
-        Ok((import_name.name, (program_ast.into_repr(), start + end)))
+        Ok((import_name.name, Import::Program((program_ast.into_repr(), start + end))))
     }
 
     /// Parsers a program scope `program foo.aleo { ... }`.
@@ -144,22 +167,24 @@ impl ParserContext<'_> {
         let mut mappings: Vec<(Symbol, Mapping)> = Vec::new();
 
         while self.has_next() {
+            // The doc comment before an item documents it.
+            let doc = self.doc_comment();
             match &self.token.token {
                 Token::Const => {
-                    let declaration = self.parse_const_declaration_statement()?;
+                    let declaration = ConstDeclaration { doc, ..self.parse_const_declaration_statement()? };
                     consts.push((Symbol::intern(&declaration.place.to_string()), declaration));
                 }
                 Token::Struct | Token::Record => {
                     let (id, struct_) = self.parse_struct()?;
-                    structs.push((id, struct_));
+                    structs.push((id, Struct { doc, ..struct_ }));
                 }
                 Token::Mapping => {
                     let (id, mapping) = self.parse_mapping()?;
-                    mappings.push((id, mapping));
+                    mappings.push((id, Mapping { doc, ..mapping }));
                 }
                 Token::At | Token::Function | Token::Transition | Token::Inline => {
                     let (id, function) = self.parse_function()?;
-                    functions.push((id, function));
+                    functions.push((id, Function { doc, ..function }));
                 }
                 Token::RightCurly => break,
                 _ => {
@@ -244,6 +269,7 @@ impl ParserContext<'_> {
             identifier: struct_name,
             members,
             is_record,
+            doc: None,
             span: start + end,
             id: self.node_builder.next_id(),
         }))
@@ -258,10 +284,12 @@ impl ParserContext<'_> {
         self.expect(&Token::BigArrow)?;
         let (value_type, _) = self.parse_type()?;
         let end = self.expect(&Token::Semicolon)?;
+        self.mapping_value_types.insert(identifier.name, value_type.clone());
         Ok((identifier.name, Mapping {
             identifier,
             key_type,
             value_type,
+            doc: None,
             span: start + end,
             id: self.node_builder.next_id(),
         }))
@@ -302,9 +330,9 @@ impl ParserContext<'_> {
             let external = self.expect_identifier()?;
             let mut span = name.span + external.span;
 
-            // Parse `.leo/`.
+            // Parse `.leo/` or `.aleo/`.
             self.eat(&Token::Dot);
-            self.eat(&Token::Leo);
+            self.eat_program_suffix();
             self.eat(&Token::Div);
 
             // Parse record name.
@@ -349,9 +377,9 @@ impl ParserContext<'_> {
             let external = self.expect_identifier()?;
             let mut span = external.span;
 
-            // Parse `.leo/`.
+            // Parse `.leo/` or `.aleo/`.
             self.eat(&Token::Dot);
-            self.eat(&Token::Leo);
+            self.eat_program_suffix();
             self.eat(&Token::Div);
 
             // Parse record name.
@@ -41,13 +41,17 @@ impl ParserContext<'_> {
 
     /// Parses particular section in the Input or State file.
     /// `
-    /// [<identifier>]
+    /// [<identifier>] or [<identifier>.<identifier>]
     /// <...definition>
     /// `
     /// Returns [`Section`].
     fn parse_section(&mut self) -> Result<Section> {
         self.expect(&Token::LeftSquare)?;
         let section = self.expect_identifier()?;
+        let case = match self.eat(&Token::Dot) {
+            true => Some(self.expect_identifier()?.name),
+            false => None,
+        };
         self.expect(&Token::RightSquare)?;
 
         let mut definitions = Vec::new();
@@ -55,7 +59,7 @@ impl ParserContext<'_> {
             definitions.push(self.parse_input_definition()?);
         }
 
-        Ok(Section { name: section.name, span: section.span, definitions })
+        Ok(Section { name: section.name, case, span: section.span, definitions })
     }
 
     /// Parses a single parameter definition:
@@ -23,7 +23,7 @@ use crate::{tokenizer::*, Token};
 
 use leo_ast::*;
 use leo_errors::{emitter::Handler, Result};
-use leo_span::Span;
+use leo_span::{FileSystem, RealFileSystem, Span};
 
 use indexmap::IndexMap;
 use leo_span::span::BytePos;
@@ -38,9 +38,15 @@ mod input;
 mod statement;
 pub(super) mod type_;
 
-/// Creates a new program from a given file path and source code text.
-pub fn parse(handler: &Handler, node_builder: &NodeBuilder, source: &str, start_pos: BytePos) -> Result<Program> {
-    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+/// Creates a new program from a given file path and source code text, reading its imports from `file_system`.
+pub fn parse(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    file_system: &dyn FileSystem,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<Program> {
+    let mut tokens = ParserContext::new(handler, node_builder, file_system, crate::tokenize(source, start_pos)?);
 
     tokens.parse_program()
 }
@@ -52,7 +58,8 @@ pub fn parse_input(
     source: &str,
     start_pos: BytePos,
 ) -> Result<InputAst> {
-    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+    // Input files have no imports.
+    let mut tokens = ParserContext::new(handler, node_builder, &RealFileSystem, crate::tokenize(source, start_pos)?);
 
     tokens.parse_input_file()
 }
@@ -48,11 +48,38 @@ impl ParserContext<'_> {
             Token::Const => Ok(Statement::Const(self.parse_const_declaration_statement()?)),
             Token::LeftCurly => Ok(Statement::Block(self.parse_block()?)),
             Token::Console => Err(ParserError::console_statements_are_not_yet_supported(self.token.span).into()),
-            Token::Finalize => Err(ParserError::finalize_statements_are_deprecated(self.token.span).into()),
+            Token::Finalize => {
+                let error = ParserError::finalize_statements_are_deprecated(self.token.span);
+                // `finalize(..);` only becomes `return then finalize(..);` if nothing follows it, e.g., a `return`.
+                Err(match self.is_last_statement() {
+                    true => error.with_fix(self.token.span, "return then finalize"),
+                    false => error,
+                }
+                .into())
+            }
             _ => Ok(self.parse_assign_statement()?),
         }
     }
 
+    /// Returns `true` if the statement starting at the current token is the last one in its block,
+    /// i.e., if its semicolon is followed by the closing brace of the block.
+    fn is_last_statement(&self) -> bool {
+        let mut depth = 0usize;
+        let mut dist = 0;
+        loop {
+            match self.look_ahead(dist, |next| &next.token) {
+                Token::LeftParen | Token::LeftSquare | Token::LeftCurly => depth += 1,
+                Token::RightParen | Token::RightSquare | Token::RightCurly if depth > 0 => depth -= 1,
+                Token::Semicolon if depth == 0 => {
+                    return self.look_ahead(dist + 1, |next| next.token == Token::RightCurly);
+                }
+                Token::RightCurly | Token::Eof => return false,
+                _ => {}
+            }
+            dist += 1;
+        }
+    }
+
     /// Returns a [`AssertStatement`] AST node if the next tokens represent an assertion statement.
     fn parse_assert_statement(&mut self) -> Result<Statement> {
         // Check which variant of the assert statement is being used.
@@ -137,22 +164,22 @@ impl ParserContext<'_> {
         } else {
             // Check for `increment` and `decrement` statements. If found, emit a deprecation warning.
             if let Expression::Call(call_expression) = &place {
-                match *call_expression.function {
-                    Expression::Identifier(Identifier { name: sym::decrement, .. }) => {
-                        self.emit_warning(ParserWarning::deprecated(
-                            "decrement",
-                            "Use `Mapping::{get, get_or_use, set, remove, contains}` for manipulating on-chain mappings.",
-                            place.span(),
-                        ));
-                    }
-                    Expression::Identifier(Identifier { name: sym::increment, .. }) => {
-                        self.emit_warning(ParserWarning::deprecated(
-                            "increment",
-                            "Use `Mapping::{get, get_or_use, set, remove, contains}` for manipulating on-chain mappings.",
-                            place.span(),
-                        ));
-                    }
-                    _ => (),
+                let operation = match *call_expression.function {
+                    Expression::Identifier(Identifier { name: sym::decrement, .. }) => Some(("decrement", "-")),
+                    Expression::Identifier(Identifier { name: sym::increment, .. }) => Some(("increment", "+")),
+                    _ => None,
+                };
+                if let Some((keyword, operator)) = operation {
+                    let warning = ParserWarning::deprecated(
+                        keyword,
+                        "Use `Mapping::{get, get_or_use, set, remove, contains}` for manipulating on-chain mappings.",
+                        place.span(),
+                    );
+                    let warning = match self.mapping_operation_fix(call_expression, operator) {
+                        Some(fix) => warning.with_fix(place.span(), fix),
+                        None => warning,
+                    };
+                    self.emit_warning(warning);
                 }
             }
 
@@ -166,6 +193,28 @@ impl ParserContext<'_> {
         }
     }
 
+    /// Returns the replacement of a deprecated `increment` or `decrement` of a mapping, e.g.,
+    /// `Mapping::set(m, k, Mapping::get_or_use(m, k, 0u64) + v)` for `increment(m, k, v)`.
+    /// The replacement is only known if the value type of the mapping, or of the amount, is an integer type.
+    fn mapping_operation_fix(&self, call: &CallExpression, operator: &str) -> Option<String> {
+        let [mapping, key, amount] = call.arguments.as_slice() else {
+            return None;
+        };
+        let mapping_type = match mapping {
+            Expression::Identifier(mapping) => self.mapping_value_types.get(&mapping.name),
+            _ => None,
+        };
+        let value_type = match (mapping_type, amount) {
+            (Some(Type::Integer(integer_type)), _)
+            | (None, Expression::Literal(Literal::Integer(integer_type, ..))) => integer_type,
+            _ => return None,
+        };
+        let amount = amount.as_operand();
+        Some(format!(
+            "Mapping::set({mapping}, {key}, Mapping::get_or_use({mapping}, {key}, 0{value_type}) {operator} {amount})"
+        ))
+    }
+
     /// Returns a [`Block`] AST node if the next tokens represent a block of statements.
     pub(super) fn parse_block(&mut self) -> Result<Block> {
         self.parse_list(Delimiter::Brace, None, |p| p.parse_statement().map(Some)).map(|(statements, _, span)| Block {
@@ -327,7 +376,14 @@ impl ParserContext<'_> {
         let value = self.parse_expression()?;
         self.expect(&Token::Semicolon)?;
 
-        Ok(ConstDeclaration { span: decl_span + value.span(), place, type_, value, id: self.node_builder.next_id() })
+        Ok(ConstDeclaration {
+            span: decl_span + value.span(),
+            place,
+            type_,
+            value,
+            doc: None,
+            id: self.node_builder.next_id(),
+        })
     }
 
     /// Returns a [`DefinitionStatement`] AST node if the next tokens represent a definition statement.
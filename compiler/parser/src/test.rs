@@ -21,12 +21,15 @@ use leo_errors::{emitter::Handler, LeoError};
 use leo_span::{
     source_map::FileName,
     symbol::{create_session_if_not_set_then, SessionGlobals},
+    RealFileSystem,
     Span,
 };
 use leo_test_framework::{
     runner::{Namespace, ParseType, Runner},
     Test,
 };
+
+use indexmap::IndexMap;
 use serde::Serialize;
 use serde_yaml::Value;
 use tokenizer::Token;
@@ -68,7 +71,7 @@ fn with_handler<T>(
 ) -> Result<T, String> {
     let (handler, buf) = Handler::new_with_buf();
     let node_builder = NodeBuilder::default();
-    let mut tokens = ParserContext::new(&handler, &node_builder, tokens);
+    let mut tokens = ParserContext::new(&handler, &node_builder, &RealFileSystem, tokens);
     let parsed = handler
         .extend_if_error(logic(&mut tokens))
         .map_err(|_| buf.extract_errs().to_string() + &buf.extract_warnings().to_string())?;
@@ -234,3 +237,102 @@ impl Runner for TestRunner {
 pub fn parser_tests() {
     leo_test_framework::run_tests(&TestRunner, "parser");
 }
+
+/// Returns the fixes suggested when parsing the program `source`, as the replaced source code and its replacement.
+fn fixes(s: &SessionGlobals, source: &str) -> Vec<(String, String)> {
+    let sf = s.source_map.new_source(source, FileName::Custom("test".into()));
+    let (handler, buf) = Handler::new_with_buf();
+    let result = crate::parse_ast(&handler, &NodeBuilder::default(), &RealFileSystem, &sf.src, sf.start_pos);
+    let errors = buf.extract_errs().into_inner().into_iter().chain(result.err());
+    let mut diagnostics = errors.filter_map(|error| error.diagnostic()).collect::<Vec<_>>();
+    diagnostics.extend(buf.extract_warnings().into_inner().iter().map(|warning| warning.diagnostic()));
+    diagnostics
+        .into_iter()
+        .flat_map(|diagnostic| diagnostic.fixes)
+        .map(|fix| (s.source_map.contents_of_span(fix.span).unwrap(), fix.replacement))
+        .collect()
+}
+
+#[test]
+fn finalize_statement_fixes() {
+    create_session_if_not_set_then(|s| {
+        let program = |body: &str| {
+            format!("program test.aleo {{ transition foo(a: u8) -> u8 {{ {body} }} finalize foo(a: u8) {{}} }}")
+        };
+        let fix = vec![("finalize".to_string(), "return then finalize".to_string())];
+        assert_eq!(fixes(s, &program("finalize(a);")), fix);
+        assert_eq!(fixes(s, &program("if a == 0u8 { finalize(a); }")), fix);
+
+        // A `return` after the `finalize` statement must be merged with it by hand.
+        assert!(fixes(s, &program("finalize(a); return a;")).is_empty());
+        assert!(fixes(s, &program("finalize({ a }); let b: u8 = a;")).is_empty());
+    })
+}
+
+#[test]
+fn mapping_operation_fixes() {
+    create_session_if_not_set_then(|s| {
+        let program = |body: &str| {
+            format!(
+                "program test.aleo {{ mapping balances: address => u64; mapping counts: u8 => field; \
+                 transition foo() {{}} finalize foo(a: address, b: u64) {{ {body} }} }}"
+            )
+        };
+        assert_eq!(fixes(s, &program("increment(balances, a, b);")), vec![(
+            "increment(balances, a, b)".to_string(),
+            "Mapping::set(balances, a, Mapping::get_or_use(balances, a, 0u64) + b)".to_string()
+        )]);
+        assert_eq!(fixes(s, &program("decrement(others, a, 1u32);")), vec![(
+            "decrement(others, a, 1u32)".to_string(),
+            "Mapping::set(others, a, Mapping::get_or_use(others, a, 0u32) - 1u32)".to_string()
+        )]);
+
+        // Compound amounts are parenthesized, so that they are subtracted as a whole.
+        assert_eq!(fixes(s, &program("decrement(balances, a, b - 1u64);")), vec![(
+            "decrement(balances, a, b - 1u64)".to_string(),
+            "Mapping::set(balances, a, Mapping::get_or_use(balances, a, 0u64) - (b - 1u64))".to_string()
+        )]);
+        assert_eq!(fixes(s, &program("decrement(balances, a, true ? b : 1u64);")), vec![(
+            "decrement(balances, a, true ? b : 1u64)".to_string(),
+            "Mapping::set(balances, a, Mapping::get_or_use(balances, a, 0u64) - (true ? b : 1u64))".to_string()
+        )]);
+
+        // The zero value of a mapping is only known if its value type, or the type of the amount, is an integer type.
+        assert!(fixes(s, &program("increment(counts, 0u8, 1field);")).is_empty());
+        assert!(fixes(s, &program("increment(others, a, b);")).is_empty());
+    })
+}
+
+#[test]
+fn record_inputs() {
+    create_session_if_not_set_then(|s| {
+        let program = "program test.aleo {
+            struct Point { x: u8, y: u8 }
+            record Token { owner: address, public amounts: [u64; 2], origin: Point }
+            transition main(token: Token, point: Point) {}
+        }";
+        let input = "[main]
+            token: Token = Token {
+                owner: aleo1rhgdu77hgyqd3xjj8ucu3jj9r2krwz6mnzyd80gncr5fxcwlh5rsvzp9px,
+                amounts: [1u64, 2u64],
+                origin: Point { x: 3u8, y: 4u8 },
+            };
+            point: Point = Point { x: 5u8, y: 6u8 };";
+
+        let (handler, node_builder) = (Handler::default(), NodeBuilder::default());
+        let program = s.source_map.new_source(program, FileName::Custom("program".into()));
+        let program = crate::parse(&handler, &node_builder, &RealFileSystem, &program.src, program.start_pos).unwrap();
+        let scope = program.program_scopes.values().next().unwrap();
+        let structs = scope.structs.iter().cloned().collect::<IndexMap<_, _>>();
+        let (_, main) = &scope.functions[0];
+        let input = s.source_map.new_source(input, FileName::Custom("input".into()));
+        let input = crate::parse_input(&handler, &node_builder, &input.src, input.start_pos).unwrap();
+
+        // Every literal within a member of a record has the visibility of the member.
+        assert_eq!(input.program_inputs(&handler, "main", None, &main.input, &structs).unwrap(), [
+            "{ owner: aleo1rhgdu77hgyqd3xjj8ucu3jj9r2krwz6mnzyd80gncr5fxcwlh5rsvzp9px.private, \
+             amounts: [1u64.public, 2u64.public], origin: { x: 3u8.private, y: 4u8.private }, _nonce: 0group.public }",
+            "{ x: 5u8, y: 6u8 }",
+        ]);
+    })
+}
@@ -156,22 +156,69 @@ impl Token {
 
     /// Returns a tuple: [(integer length, integer token)] if an integer can be eaten, otherwise returns [`None`].
     /// An integer can be eaten if its bytes are at the front of the given `input` string.
-    fn eat_integer(input: &mut Peekable<impl Iterator<Item = char>>) -> Result<(usize, Token)> {
+    /// Integers are decimal, or hexadecimal, octal, or binary with a `0x`, `0o`, or `0b` prefix.
+    fn eat_integer(input: &mut Peekable<impl Iterator<Item = char> + Clone>) -> Result<(usize, Token)> {
         if input.peek().is_none() {
             return Err(ParserError::lexer_empty_input().into());
         }
 
         let mut int = String::new();
 
+        if let Some(radix) = Self::eat_radix_prefix(input, &mut int) {
+            return Self::eat_radix_digits(input, int, radix);
+        }
+
         // Note that it is still impossible to have a number that starts with an `_` because eat_integer is only called when the first character is a digit.
         while let Some(c) = input.next_if(|c| c.is_ascii_digit() || *c == '_') {
-            if c == '0' && matches!(input.peek(), Some('x')) {
-                int.push(c);
-                int.push(input.next().unwrap());
-                return Err(ParserError::lexer_hex_number_provided(int).into());
-            }
+            int.push(c);
+        }
+
+        Ok((int.len(), Token::Integer(int)))
+    }
+
+    /// Eats a `0x`, `0o`, or `0b` prefix into `int` and returns its radix, if the input starts with one.
+    fn eat_radix_prefix(input: &mut Peekable<impl Iterator<Item = char> + Clone>, int: &mut String) -> Option<u32> {
+        let mut lookahead = input.clone();
+        lookahead.next_if_eq(&'0')?;
+        let (prefix, radix) = match lookahead.next()? {
+            'x' => ('x', 16),
+            'o' => ('o', 8),
+            'b' => ('b', 2),
+            _ => return None,
+        };
+        int.push(input.next()?);
+        int.push(input.next().filter(|c| *c == prefix)?);
+        Some(radix)
+    }
 
+    /// Eats the digits of a hexadecimal, octal, or binary integer, after its prefix in `int`.
+    fn eat_radix_digits(
+        input: &mut Peekable<impl Iterator<Item = char> + Clone>,
+        mut int: String,
+        radix: u32,
+    ) -> Result<(usize, Token)> {
+        let kind = match radix {
+            16 => "hexadecimal",
+            8 => "octal",
+            _ => "binary",
+        };
+
+        while let Some(c) = input.peek().copied().filter(|c| c.is_digit(radix) || *c == '_') {
+            // `f` is a hexadecimal digit, but `0x1field` is the field element `0x1`.
+            if c == 'f' && input.clone().skip(1).take(4).eq("ield".chars()) {
+                break;
+            }
             int.push(c);
+            input.next();
+        }
+
+        // A decimal digit that is not a digit of the radix, e.g., in `0b12u8`, would otherwise start a new integer.
+        if let Some(c) = input.peek().filter(|c| c.is_ascii_digit()) {
+            int.push(*c);
+            return Err(ParserError::lexer_invalid_radix_integer(int, kind).into());
+        }
+        if !int[2..].chars().any(|c| c.is_digit(radix)) {
+            return Err(ParserError::lexer_invalid_radix_integer(int, kind).into());
         }
 
         Ok((int.len(), Token::Integer(int)))
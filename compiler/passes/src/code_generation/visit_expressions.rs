@@ -140,8 +140,10 @@ impl<'a> CodeGenerator<'a> {
         // Increment the register counter.
         self.next_register += 1;
 
-        let cast_instruction =
-            format!("    cast {expression_operand} into {destination_register} as {};\n", input.type_);
+        let cast_instruction = format!(
+            "    cast {expression_operand} into {destination_register} as {};\n",
+            Self::visit_type(&input.type_)
+        );
 
         // Concatenate the instructions.
         instructions.push_str(&cast_instruction);
@@ -287,7 +289,7 @@ impl<'a> CodeGenerator<'a> {
     fn visit_array_access(&mut self, input: &'a ArrayAccess) -> (String, String) {
         let (array_operand, _) = self.visit_expression(&input.array);
         let index_operand = match input.index.as_ref() {
-            Expression::Literal(Literal::Integer(_, string, _, _)) => format!("{}u32", string),
+            Expression::Literal(Literal::Integer(_, string, _, _)) => format!("{}u32", Literal::decimal_digits(string)),
             _ => unreachable!("Array indices must be integer literals"),
         };
         let array_access = format!("{}[{}]", array_operand, index_operand);
@@ -528,24 +530,32 @@ impl<'a> CodeGenerator<'a> {
                     Expression::Identifier(identifier) => identifier.name,
                     _ => unreachable!("Parsing guarantees that a program name is always an identifier."),
                 };
-                // Lookup the imported program scope.
-                let imported_program_scope = match self
-                    .program
-                    .imports
-                    .get(&program_name)
-                    .and_then(|(program, _)| program.program_scopes.get(&program_name))
-                {
-                    Some(program) => program,
-                    None => unreachable!("Type checking guarantees that imported programs are well defined."),
-                };
                 // Check if the external function has a finalize block.
                 let function_name = match *input.function {
                     Expression::Identifier(identifier) => identifier.name,
                     _ => unreachable!("Parsing guarantees that a function name is always an identifier."),
                 };
-                let has_finalize = match imported_program_scope.functions.iter().find(|(sym, _)| *sym == function_name)
-                {
-                    Some((_, function)) => function.finalize.is_some(),
+                // Lookup the function in the imported program scope, or in the stub of an imported Aleo program.
+                let imported_program_scope = self
+                    .program
+                    .imports
+                    .get(&program_name)
+                    .and_then(|(program, _)| program.program_scopes.get(&program_name));
+                let has_finalize = match (imported_program_scope, self.program.stubs.get(&program_name)) {
+                    (Some(program), _) => program
+                        .functions
+                        .iter()
+                        .find(|(sym, _)| *sym == function_name)
+                        .map(|(_, function)| function.finalize.is_some()),
+                    (None, Some(stub)) => stub
+                        .functions
+                        .iter()
+                        .find(|(sym, _)| *sym == function_name)
+                        .map(|(_, function)| function.finalize.is_some()),
+                    (None, None) => unreachable!("Type checking guarantees that imported programs are well defined."),
+                };
+                let has_finalize = match has_finalize {
+                    Some(has_finalize) => has_finalize,
                     None => unreachable!("Type checking guarantees that imported functions are well defined."),
                 };
                 (format!("    call {external}.aleo/{}", input.function), has_finalize)
@@ -28,13 +28,14 @@ impl<'a> CodeGenerator<'a> {
         // Accumulate instructions into a program string.
         let mut program_string = String::new();
 
-        if !input.imports.is_empty() {
+        if !input.imports.is_empty() || !input.stubs.is_empty() {
             // Visit each import statement and produce a Aleo import instruction.
             program_string.push_str(
                 &input
                     .imports
                     .iter()
                     .map(|(identifier, (imported_program, _))| self.visit_import(identifier, imported_program))
+                    .chain(input.stubs.keys().map(|identifier| format!("import {identifier}.aleo;")))
                     .join("\n"),
             );
 
@@ -21,8 +21,8 @@ use leo_ast::{Mode, Type};
 impl<'a> CodeGenerator<'a> {
     pub(crate) fn visit_type(input: &Type) -> String {
         match input {
+            Type::Boolean => "boolean".to_string(),
             Type::Address
-            | Type::Boolean
             | Type::Field
             | Type::Group
             | Type::Scalar
@@ -72,6 +72,31 @@ impl<N: Node> DiGraph<N> {
         self.nodes.contains(&node)
     }
 
+    /// Removes a node and its incoming and outgoing edges from the graph.
+    pub fn remove_node(&mut self, node: N) {
+        if self.nodes.shift_remove(&node) {
+            self.edges.shift_remove(&node);
+            self.edges.values_mut().for_each(|children| {
+                children.shift_remove(&node);
+            });
+        }
+    }
+
+    /// Returns the nodes reachable from `roots`, including the roots themselves, in depth-first order.
+    pub fn reachable_from(&self, roots: impl IntoIterator<Item = N>) -> IndexSet<N> {
+        let mut reachable = IndexSet::new();
+        let mut stack: Vec<N> = roots.into_iter().collect();
+        stack.reverse();
+        while let Some(node) = stack.pop() {
+            if reachable.insert(node) {
+                if let Some(children) = self.edges.get(&node) {
+                    stack.extend(children.iter().rev().filter(|child| !reachable.contains(*child)));
+                }
+            }
+        }
+        reachable
+    }
+
     /// Returns the post-order ordering of the graph.
     /// Detects if there is a cycle in the graph.
     pub fn post_order(&self) -> Result<IndexSet<N>, DiGraphError<N>> {
@@ -214,4 +239,34 @@ mod test {
 
         check_post_order(&graph, &[1, 2, 3, 4, 5]);
     }
+
+    #[test]
+    fn test_reachable_from() {
+        let mut graph = DiGraph::<u32>::new(IndexSet::from([1, 2, 3, 4, 5, 6]));
+
+        graph.add_edge(1, 2);
+        graph.add_edge(1, 3);
+        graph.add_edge(2, 4);
+        graph.add_edge(3, 4);
+        graph.add_edge(5, 6);
+
+        assert_eq!(graph.reachable_from([1]), IndexSet::from([1, 2, 4, 3]));
+        assert_eq!(graph.reachable_from([3, 5]), IndexSet::from([3, 4, 5, 6]));
+        assert!(graph.reachable_from([]).is_empty());
+    }
+
+    #[test]
+    fn test_remove_node() {
+        let mut graph = DiGraph::<u32>::new(IndexSet::new());
+
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 3);
+        graph.add_edge(3, 4);
+
+        graph.remove_node(3);
+
+        assert!(!graph.contains_node(3));
+        assert_eq!(graph.reachable_from([1]), IndexSet::from([1, 2]));
+        check_post_order(&graph, &[2, 1, 4]);
+    }
 }
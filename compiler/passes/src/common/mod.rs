@@ -20,6 +20,9 @@ pub use assigner::*;
 pub mod graph;
 pub use graph::*;
 
+pub mod refresher;
+pub use refresher::*;
+
 pub mod rename_table;
 pub use rename_table::*;
 
@@ -34,3 +37,6 @@ pub use symbol_table::*;
 
 pub mod type_table;
 pub use type_table::*;
+
+pub mod suggestion;
+pub use suggestion::*;
@@ -0,0 +1,255 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::TypeTable;
+
+use leo_ast::*;
+
+/// A `Refresher` gives every node in an AST fragment a fresh `NodeID`, copying its type in the type table.
+/// Passes that copy a fragment, e.g. the body of an unrolled loop or an inlined function, refresh each copy,
+/// so that every node keeps a unique `NodeID`.
+pub struct Refresher<'a> {
+    /// A counter used to generate unique node IDs.
+    node_builder: &'a NodeBuilder,
+    /// A mapping from node IDs to their types.
+    type_table: &'a TypeTable,
+}
+
+impl<'a> Refresher<'a> {
+    pub fn new(node_builder: &'a NodeBuilder, type_table: &'a TypeTable) -> Self {
+        Self { node_builder, type_table }
+    }
+
+    /// Gives `node` a fresh `NodeID`, with the same type as its old one.
+    pub fn refresh<N: Node>(&self, node: &mut N) {
+        let id = self.node_builder.next_id();
+        if let Some(type_) = self.type_table.get(&node.id()) {
+            self.type_table.insert(id, type_);
+        }
+        node.set_id(id);
+    }
+
+    /// Returns a copy of `node`, e.g. an `Identifier`, with a fresh `NodeID`.
+    /// Note that the children of `node` keep their `NodeID`s; use `copy_expression` for compound expressions.
+    pub fn copy<N: Node + Clone>(&self, node: &N) -> N {
+        let mut node = node.clone();
+        self.refresh(&mut node);
+        node
+    }
+
+    /// Returns a copy of `expression`, in which every node has a fresh `NodeID`.
+    pub fn copy_expression(&mut self, expression: &Expression) -> Expression {
+        let mut expression = expression.clone();
+        self.visit_expression_mut(&mut expression);
+        expression
+    }
+
+    /// Refreshes the identifiers in a type.
+    fn refresh_type(&self, type_: &mut Type) {
+        match type_ {
+            Type::Identifier(identifier) => self.refresh(identifier),
+            Type::Mapping(mapping) => {
+                self.refresh_type(&mut mapping.key);
+                self.refresh_type(&mut mapping.value);
+            }
+            Type::Tuple(tuple) => {
+                let mut elements = tuple.elements().to_vec();
+                elements.iter_mut().for_each(|element| self.refresh_type(element));
+                *tuple = TupleType::new(elements);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl ExpressionVisitorMut for Refresher<'_> {
+    type AdditionalOutput = ();
+
+    fn visit_access_mut(&mut self, input: &mut AccessExpression) {
+        match input {
+            AccessExpression::Array(access) => {
+                self.visit_expression_mut(&mut access.array);
+                self.visit_expression_mut(&mut access.index);
+            }
+            AccessExpression::AssociatedConstant(access) => {
+                self.refresh_type(&mut access.ty);
+                self.refresh(&mut access.name);
+            }
+            AccessExpression::AssociatedFunction(access) => {
+                self.refresh_type(&mut access.ty);
+                self.refresh(&mut access.name);
+                access.arguments.iter_mut().for_each(|argument| self.visit_expression_mut(argument));
+            }
+            AccessExpression::Member(access) => {
+                self.visit_expression_mut(&mut access.inner);
+                self.refresh(&mut access.name);
+            }
+            AccessExpression::Tuple(access) => self.visit_expression_mut(&mut access.tuple),
+        }
+        self.refresh(input);
+    }
+
+    fn visit_array_mut(&mut self, input: &mut ArrayExpression) {
+        input.elements.iter_mut().for_each(|element| self.visit_expression_mut(element));
+        self.refresh(input);
+    }
+
+    fn visit_binary_mut(&mut self, input: &mut BinaryExpression) {
+        self.visit_expression_mut(&mut input.left);
+        self.visit_expression_mut(&mut input.right);
+        self.refresh(input);
+    }
+
+    fn visit_call_mut(&mut self, input: &mut CallExpression) {
+        self.visit_expression_mut(&mut input.function);
+        input.arguments.iter_mut().for_each(|argument| self.visit_expression_mut(argument));
+        if let Some(external) = input.external.as_mut() {
+            self.visit_expression_mut(external);
+        }
+        self.refresh(input);
+    }
+
+    fn visit_cast_mut(&mut self, input: &mut CastExpression) {
+        self.visit_expression_mut(&mut input.expression);
+        self.refresh_type(&mut input.type_);
+        self.refresh(input);
+    }
+
+    fn visit_struct_init_mut(&mut self, input: &mut StructExpression) {
+        self.refresh(&mut input.name);
+        for member in input.members.iter_mut() {
+            self.refresh(&mut member.identifier);
+            if let Some(expression) = member.expression.as_mut() {
+                self.visit_expression_mut(expression);
+            }
+            self.refresh(member);
+        }
+        self.refresh(input);
+    }
+
+    fn visit_err_mut(&mut self, input: &mut ErrExpression) {
+        self.refresh(input);
+    }
+
+    fn visit_identifier_mut(&mut self, input: &mut Identifier) {
+        self.refresh(input);
+    }
+
+    fn visit_literal_mut(&mut self, input: &mut Literal) {
+        self.refresh(input);
+    }
+
+    fn visit_ternary_mut(&mut self, input: &mut TernaryExpression) {
+        self.visit_expression_mut(&mut input.condition);
+        self.visit_expression_mut(&mut input.if_true);
+        self.visit_expression_mut(&mut input.if_false);
+        self.refresh(input);
+    }
+
+    fn visit_tuple_mut(&mut self, input: &mut TupleExpression) {
+        input.elements.iter_mut().for_each(|element| self.visit_expression_mut(element));
+        self.refresh(input);
+    }
+
+    fn visit_unary_mut(&mut self, input: &mut UnaryExpression) {
+        self.visit_expression_mut(&mut input.receiver);
+        self.refresh(input);
+    }
+
+    fn visit_unit_mut(&mut self, input: &mut UnitExpression) {
+        self.refresh(input);
+    }
+}
+
+impl StatementVisitorMut for Refresher<'_> {
+    fn visit_assert_mut(&mut self, input: &mut AssertStatement) {
+        match &mut input.variant {
+            AssertVariant::Assert(expression) => self.visit_expression_mut(expression),
+            AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
+                self.visit_expression_mut(left);
+                self.visit_expression_mut(right);
+            }
+        }
+        self.refresh(input);
+    }
+
+    fn visit_assign_mut(&mut self, input: &mut AssignStatement) {
+        self.visit_expression_mut(&mut input.place);
+        self.visit_expression_mut(&mut input.value);
+        self.refresh(input);
+    }
+
+    fn visit_block_mut(&mut self, input: &mut Block) {
+        input.statements.iter_mut().for_each(|statement| self.visit_statement_mut(statement));
+        self.refresh(input);
+    }
+
+    fn visit_conditional_mut(&mut self, input: &mut ConditionalStatement) {
+        self.visit_expression_mut(&mut input.condition);
+        self.visit_block_mut(&mut input.then);
+        if let Some(otherwise) = input.otherwise.as_mut() {
+            self.visit_statement_mut(otherwise);
+        }
+        self.refresh(input);
+    }
+
+    fn visit_console_mut(&mut self, input: &mut ConsoleStatement) {
+        match &mut input.function {
+            ConsoleFunction::Assert(expression) => self.visit_expression_mut(expression),
+            ConsoleFunction::AssertEq(left, right) | ConsoleFunction::AssertNeq(left, right) => {
+                self.visit_expression_mut(left);
+                self.visit_expression_mut(right);
+            }
+        }
+        self.refresh(input);
+    }
+
+    fn visit_const_mut(&mut self, input: &mut ConstDeclaration) {
+        self.refresh(&mut input.place);
+        self.refresh_type(&mut input.type_);
+        self.visit_expression_mut(&mut input.value);
+        self.refresh(input);
+    }
+
+    fn visit_definition_mut(&mut self, input: &mut DefinitionStatement) {
+        self.visit_expression_mut(&mut input.place);
+        self.refresh_type(&mut input.type_);
+        self.visit_expression_mut(&mut input.value);
+        self.refresh(input);
+    }
+
+    fn visit_expression_statement_mut(&mut self, input: &mut ExpressionStatement) {
+        self.visit_expression_mut(&mut input.expression);
+        self.refresh(input);
+    }
+
+    fn visit_iteration_mut(&mut self, input: &mut IterationStatement) {
+        self.refresh(&mut input.variable);
+        self.refresh_type(&mut input.type_);
+        self.visit_expression_mut(&mut input.start);
+        self.visit_expression_mut(&mut input.stop);
+        self.visit_block_mut(&mut input.block);
+        self.refresh(input);
+    }
+
+    fn visit_return_mut(&mut self, input: &mut ReturnStatement) {
+        self.visit_expression_mut(&mut input.expression);
+        if let Some(arguments) = input.finalize_arguments.as_mut() {
+            arguments.iter_mut().for_each(|argument| self.visit_expression_mut(argument));
+        }
+        self.refresh(input);
+    }
+}
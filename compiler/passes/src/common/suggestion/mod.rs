@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::Symbol;
+
+/// Returns the edit distance between `a` and `b`, i.e., the number of single-character insertions, deletions,
+/// substitutions, and transpositions of adjacent characters needed to turn one into the other.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b) = (a.chars().collect::<Vec<_>>(), b.chars().collect::<Vec<_>>());
+    // The distances between the prefixes of `a` of the previous two lengths and each prefix of `b`.
+    let mut before = vec![0; b.len() + 1];
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    for i in 1..=a.len() {
+        let mut current = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let substitution = previous[j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            current[j] = substitution.min(previous[j] + 1).min(current[j - 1] + 1);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before[j - 2] + 1);
+            }
+        }
+        before = std::mem::replace(&mut previous, current);
+    }
+    previous[b.len()]
+}
+
+/// Returns the candidate closest to `name`, if one is close enough to be a likely misspelling.
+/// A candidate is close enough if at most a third of the characters of `name`, and at least one character fewer than
+/// all of them, must be changed to obtain it.
+pub fn closest_match(name: Symbol, candidates: impl IntoIterator<Item = Symbol>) -> Option<Symbol> {
+    let name = name.to_string();
+    let length = name.chars().count();
+    let max_distance = (length / 3).max(1).min(length.saturating_sub(1));
+    candidates
+        .into_iter()
+        .filter(|candidate| candidate.to_string() != name)
+        .map(|candidate| (edit_distance(&name, &candidate.to_string()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Returns the help message suggesting the candidate closest to `name`, if there is one.
+pub fn did_you_mean(name: Symbol, candidates: impl IntoIterator<Item = Symbol>) -> Option<String> {
+    closest_match(name, candidates).map(|candidate| format!("Did you mean `{candidate}`?"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("abc", ""), 3);
+        assert_eq!(edit_distance("hash_to_feild", "hash_to_field"), 1);
+        assert_eq!(edit_distance("Piont", "Point"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_closest_match() {
+        create_session_if_not_set_then(|_| {
+            let candidates = ["hash_to_field", "hash_to_group", "commit_to_field"].map(Symbol::intern);
+            assert_eq!(
+                closest_match(Symbol::intern("hash_to_feild"), candidates),
+                Some(Symbol::intern("hash_to_field"))
+            );
+            assert_eq!(closest_match(Symbol::intern("verify"), candidates), None);
+            assert_eq!(closest_match(Symbol::intern("x"), [Symbol::intern("y")]), None);
+            assert_eq!(
+                did_you_mean(Symbol::intern("ab"), [Symbol::intern("ac")]),
+                Some("Did you mean `ac`?".to_string())
+            );
+        })
+    }
+}
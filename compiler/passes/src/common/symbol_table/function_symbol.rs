@@ -25,7 +25,7 @@ use crate::SymbolTable;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FinalizeData {
     /// The inputs to the finalize block.
-    pub(crate) input: Vec<Input>,
+    pub input: Vec<Input>,
     /// The output type of the finalize block.
     pub(crate) output_type: Type,
 }
@@ -36,15 +36,15 @@ pub struct FunctionSymbol {
     /// The index associated with the scope in the parent symbol table.
     pub(crate) id: usize,
     /// The output type of the function.
-    pub(crate) output_type: Type,
+    pub output_type: Type,
     /// Is this function a transition, inlined, or a regular function?.
     pub variant: Variant,
-    /// The `Span` associated with the function.
-    pub(crate) _span: Span,
+    /// The `Span` of the function's name.
+    pub(crate) span: Span,
     /// The inputs to the function.
-    pub(crate) input: Vec<Input>,
+    pub input: Vec<Input>,
     /// Metadata associated with the finalize block.
-    pub(crate) finalize: Option<FinalizeData>,
+    pub finalize: Option<FinalizeData>,
 }
 
 impl SymbolTable {
@@ -53,7 +53,7 @@ impl SymbolTable {
             id,
             output_type: func.output_type.clone(),
             variant: func.variant,
-            _span: func.span,
+            span: func.identifier.span,
             input: func.input.clone(),
             finalize: func.finalize.as_ref().map(|finalize| FinalizeData {
                 input: finalize.input.clone(),
@@ -56,14 +56,21 @@ impl SymbolTable {
     /// Recursively checks if the symbol table contains an entry for the given symbol.
     /// Leo does not allow any variable shadowing or overlap between different symbols.
     pub fn check_shadowing(&self, symbol: Symbol, span: Span) -> Result<()> {
-        if self.variables.contains_key(&symbol) {
-            Err(AstError::shadowed_variable(symbol, span).into())
-        } else if self.functions.contains_key(&symbol) {
-            Err(AstError::shadowed_function(symbol, span).into())
+        // Points the error at the existing definition of the symbol.
+        let shadowed = |err: AstError, existing: Span| {
+            Err(err
+                .with_label(format!("`{symbol}` is also defined here"), existing)
+                .with_note("Leo does not allow a name to be defined twice, even in a nested scope.")
+                .into())
+        };
+        if let Some(existing) = self.variables.get(&symbol) {
+            shadowed(AstError::shadowed_variable(symbol, span), existing.span)
+        } else if let Some(existing) = self.functions.get(&symbol) {
+            shadowed(AstError::shadowed_function(symbol, span), existing.span)
         } else if let Some(existing) = self.structs.get(&symbol) {
             match existing.is_record {
-                true => Err(AstError::shadowed_record(symbol, span).into()),
-                false => Err(AstError::shadowed_struct(symbol, span).into()),
+                true => shadowed(AstError::shadowed_record(symbol, span), existing.identifier.span),
+                false => shadowed(AstError::shadowed_struct(symbol, span), existing.identifier.span),
             }
         } else if let Some(parent) = self.parent.as_ref() {
             parent.check_shadowing(symbol, span)
@@ -89,6 +96,16 @@ impl SymbolTable {
         Ok(())
     }
 
+    /// Removes a function and its scope from the symbol table.
+    /// The functions inserted after it are given the indices of their scopes after the removal.
+    pub fn remove_fn(&mut self, symbol: Symbol) {
+        if let Some(function) = self.functions.shift_remove(&symbol) {
+            self.scopes.remove(function.id);
+            self.scope_index -= 1;
+            self.functions.values_mut().filter(|other| other.id > function.id).for_each(|other| other.id -= 1);
+        }
+    }
+
     /// Inserts a struct into the symbol table.
     pub fn insert_struct(&mut self, symbol: Symbol, insert: &Struct) -> Result<()> {
         self.check_shadowing(symbol, insert.span)?;
@@ -147,6 +164,30 @@ impl SymbolTable {
         }
     }
 
+    /// Returns the names of the functions visible from this scope.
+    pub fn visible_functions(&self) -> Vec<Symbol> {
+        self.visible_names(|table| table.functions.keys().copied().collect())
+    }
+
+    /// Returns the names of the structs and records visible from this scope.
+    pub fn visible_structs(&self) -> Vec<Symbol> {
+        self.visible_names(|table| table.structs.keys().copied().collect())
+    }
+
+    /// Returns the names of the variables visible from this scope.
+    pub fn visible_variables(&self) -> Vec<Symbol> {
+        self.visible_names(|table| table.variables.keys().copied().collect())
+    }
+
+    /// Returns the names selected by `names` in this scope and its parents.
+    fn visible_names(&self, names: fn(&Self) -> Vec<Symbol>) -> Vec<Symbol> {
+        let mut visible = names(self);
+        if let Some(parent) = self.parent.as_ref() {
+            visible.extend(parent.visible_names(names));
+        }
+        visible
+    }
+
     /// Returns true if the variable exists in the local scope
     pub fn variable_in_local_scope(&self, symbol: Symbol) -> bool {
         self.variables.contains_key(&symbol)
@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{SymbolTable, TypeTable};
+
+use leo_ast::{AccessExpression, CoreFunction, Expression, Identifier, Node, NodeBuilder, Type};
+use leo_span::{sym, Symbol};
+
+use indexmap::IndexMap;
+
+pub struct CommonSubexpressionEliminator<'a> {
+    /// A counter to generate unique node IDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// The symbol table, used to tell records apart from structs.
+    pub(crate) symbol_table: &'a SymbolTable,
+    /// A mapping between node IDs and their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// A mapping from the expressions computed in the current function body to the variables that hold their values.
+    pub(crate) values: IndexMap<String, Identifier>,
+    /// A mapping from variables to the variables they are copies of.
+    pub(crate) copies: IndexMap<Symbol, Symbol>,
+    /// A mapping from mappings to the reads of them in `values`, which are stale once the mapping is updated.
+    pub(crate) mapping_reads: IndexMap<Symbol, Vec<String>>,
+}
+
+impl<'a> CommonSubexpressionEliminator<'a> {
+    /// Initializes a new `CommonSubexpressionEliminator`.
+    pub fn new(node_builder: &'a NodeBuilder, symbol_table: &'a SymbolTable, type_table: &'a TypeTable) -> Self {
+        Self {
+            node_builder,
+            symbol_table,
+            type_table,
+            values: Default::default(),
+            copies: Default::default(),
+            mapping_reads: Default::default(),
+        }
+    }
+
+    /// Clears the state of the eliminator, since values are not shared between function bodies.
+    pub(crate) fn clear(&mut self) {
+        self.values.clear();
+        self.copies.clear();
+        self.mapping_reads.clear();
+    }
+
+    /// Returns the key under which the value of `expression` is stored in `self.values`,
+    /// or `None` if the expression must be evaluated every time, e.g., because it has side effects.
+    /// Note that the variables in `expression` must already be replaced with the ones they are copies of.
+    pub(crate) fn value_key(&self, expression: &Expression) -> Option<String> {
+        // Reusing a record would spend it twice.
+        let is_record = |name: Symbol| self.symbol_table.lookup_struct(name).map_or(true, |struct_| struct_.is_record);
+        if let Some(Type::Identifier(identifier)) = self.type_table.get(&expression.id()) {
+            if is_record(identifier.name) {
+                return None;
+            }
+        }
+
+        match expression {
+            // The operands of commutative operations are ordered, so that `a + b` and `b + a` have the same key.
+            Expression::Binary(binary) => {
+                let (left, right) = (binary.left.to_string(), binary.right.to_string());
+                match binary.op.is_commutative() && left > right {
+                    true => Some(format!("{right} {} {left}", binary.op)),
+                    false => Some(format!("{left} {} {right}", binary.op)),
+                }
+            }
+            Expression::Access(AccessExpression::AssociatedFunction(function)) => {
+                let Type::Identifier(module) = &function.ty else {
+                    return None;
+                };
+                match CoreFunction::from_symbols(module.name, function.name.name) {
+                    // Reads of a mapping are only reused until the mapping is updated.
+                    Some(CoreFunction::MappingGet | CoreFunction::MappingGetOrUse | CoreFunction::MappingContains) => {
+                        match function.arguments.first() {
+                            Some(Expression::Identifier(_)) => Some(function.to_string()),
+                            _ => None,
+                        }
+                    }
+                    // Random values and updates of mappings are never reused.
+                    Some(core_function) if core_function.is_finalize_command() => None,
+                    // Hashes and commitments, among others, are pure.
+                    Some(_) => Some(function.to_string()),
+                    None => None,
+                }
+            }
+            Expression::Access(AccessExpression::Array(_) | AccessExpression::Member(_))
+            | Expression::Array(_)
+            | Expression::Cast(_)
+            | Expression::Ternary(_)
+            | Expression::Unary(_) => Some(expression.to_string()),
+            Expression::Struct(struct_) if !is_record(struct_.name.name) => Some(expression.to_string()),
+            // Calls may have side effects, and the other expressions are not worth reusing.
+            _ => None,
+        }
+    }
+
+    /// Records that `variable` holds the value of `expression`, stored under `key`.
+    pub(crate) fn insert_value(&mut self, key: String, expression: &Expression, variable: Identifier) {
+        if let Expression::Access(AccessExpression::AssociatedFunction(function)) = expression {
+            if let Some(Expression::Identifier(mapping)) = function.arguments.first() {
+                if matches!(function.ty, Type::Identifier(Identifier { name: sym::Mapping, .. })) {
+                    self.mapping_reads.entry(mapping.name).or_default().push(key.clone());
+                }
+            }
+        }
+        self.values.insert(key, variable);
+    }
+
+    /// Forgets the reads of `mapping`, or of all mappings if it is not known, since it is updated.
+    pub(crate) fn invalidate_mapping_reads(&mut self, mapping: Option<Symbol>) {
+        let reads = match mapping {
+            Some(mapping) => self.mapping_reads.remove(&mapping).unwrap_or_default(),
+            None => core::mem::take(&mut self.mapping_reads).into_values().flatten().collect(),
+        };
+        for key in reads {
+            self.values.shift_remove(&key);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::CommonSubexpressionEliminator;
+
+use leo_ast::{AssociatedFunction, CallExpression, Expression, ExpressionVisitorMut, Identifier, Type};
+use leo_span::sym;
+
+impl ExpressionVisitorMut for CommonSubexpressionEliminator<'_> {
+    type AdditionalOutput = ();
+
+    /// Visits the arguments of the associated function, forgetting the reads of a mapping that is updated.
+    fn visit_associated_function_mut(&mut self, input: &mut AssociatedFunction) {
+        input.arguments.iter_mut().for_each(|argument| self.visit_expression_mut(argument));
+
+        if let (Type::Identifier(Identifier { name: sym::Mapping, .. }), sym::remove | sym::set) =
+            (&input.ty, input.name.name)
+        {
+            let mapping = match input.arguments.first() {
+                Some(Expression::Identifier(mapping)) => Some(mapping.name),
+                _ => None,
+            };
+            self.invalidate_mapping_reads(mapping);
+        }
+    }
+
+    /// Visits the arguments of the call, but not the name of the function.
+    fn visit_call_mut(&mut self, input: &mut CallExpression) {
+        input.arguments.iter_mut().for_each(|argument| self.visit_expression_mut(argument));
+    }
+
+    /// Replaces a variable with the one it is a copy of.
+    fn visit_identifier_mut(&mut self, input: &mut Identifier) {
+        if let Some(name) = self.copies.get(&input.name) {
+            input.name = *name;
+        }
+    }
+}
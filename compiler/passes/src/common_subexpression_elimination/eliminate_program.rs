@@ -0,0 +1,32 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::CommonSubexpressionEliminator;
+
+use leo_ast::{Function, ProgramVisitorMut, StatementVisitorMut};
+
+impl ProgramVisitorMut for CommonSubexpressionEliminator<'_> {
+    fn visit_function_mut(&mut self, input: &mut Function) {
+        // Values are not shared between the function body and the finalize block.
+        self.clear();
+        self.visit_block_mut(&mut input.block);
+
+        if let Some(finalize) = input.finalize.as_mut() {
+            self.clear();
+            self.visit_block_mut(&mut finalize.block);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::CommonSubexpressionEliminator;
+
+use leo_ast::{
+    AssignStatement,
+    ConditionalStatement,
+    ConsoleStatement,
+    DefinitionStatement,
+    Expression,
+    ExpressionVisitorMut,
+    Identifier,
+    IterationStatement,
+    Node,
+    StatementVisitorMut,
+};
+
+impl StatementVisitorMut for CommonSubexpressionEliminator<'_> {
+    /// Reuses the value of the right-hand side of the assignment, if it has already been computed.
+    fn visit_assign_mut(&mut self, input: &mut AssignStatement) {
+        self.visit_expression_mut(&mut input.value);
+
+        // Only assignments to variables are considered, e.g. not the tuples assigned by calls.
+        let Expression::Identifier(place) = &input.place else {
+            return;
+        };
+        let place = *place;
+
+        if let Expression::Identifier(value) = &input.value {
+            // Later uses of the variable are replaced with the one it is a copy of.
+            self.copies.insert(place.name, value.name);
+            return;
+        }
+
+        let Some(key) = self.value_key(&input.value) else {
+            return;
+        };
+        match self.values.get(&key) {
+            Some(variable) => {
+                let id = self.node_builder.next_id();
+                if let Some(type_) = self.type_table.get(&input.value.id()) {
+                    self.type_table.insert(id, type_);
+                }
+                input.value = Expression::Identifier(Identifier { name: variable.name, span: input.value.span(), id });
+                self.copies.insert(place.name, variable.name);
+            }
+            None => self.insert_value(key, &input.value, place),
+        }
+    }
+
+    fn visit_conditional_mut(&mut self, _: &mut ConditionalStatement) {
+        unreachable!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
+    }
+
+    fn visit_console_mut(&mut self, _: &mut ConsoleStatement) {
+        unreachable!("`ConsoleStatement`s should not be in the AST at this phase of compilation.")
+    }
+
+    fn visit_definition_mut(&mut self, _: &mut DefinitionStatement) {
+        unreachable!("`DefinitionStatement`s should not exist in the AST at this phase of compilation.")
+    }
+
+    fn visit_iteration_mut(&mut self, _: &mut IterationStatement) {
+        unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
+    }
+}
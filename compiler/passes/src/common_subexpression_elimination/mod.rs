@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Common Subexpression Elimination pass traverses the AST and reuses the values of expressions
+//! that have already been computed, within the boundary of `transition`s, `function`s, and `finalize` blocks.
+//! The pass is run after the Function Inlining pass and before the Dead Code Elimination pass,
+//! which removes the assignments that are no longer used.
+//!
+//! See https://en.wikipedia.org/wiki/Common_subexpression_elimination for more information.
+//!
+//! Consider the following flattened Leo code.
+//! ```leo
+//! function main(a: u8, b: u8) -> u8 {
+//!     $var$0 = a * b;
+//!     $var$1 = $var$0;
+//!     $var$2 = b * a;
+//!     $var$3 = $var$1 + $var$2;
+//!     return $var$3;
+//! }
+//! ```
+//!
+//! The common subexpression elimination pass produces the following code.
+//! ```leo
+//! function main(a: u8, b: u8) -> u8 {
+//!     $var$0 = a * b;
+//!     $var$1 = $var$0;
+//!     $var$2 = $var$0;
+//!     $var$3 = $var$0 + $var$0;
+//!     return $var$3;
+//! }
+//! ```
+//!
+//! Expressions with side effects are never reused, e.g. calls and `ChaCha::rand_*`.
+//! Reads of a mapping, e.g. `Mapping::get`, are reused until the mapping is updated by `Mapping::set` or `Mapping::remove`.
+//!
+//! Note this pass relies on the following invariants:
+//! - No shadowing for all variables, struct names, function names, etc.
+//! - Unique variable names (provided by SSA)
+//! - Flattened code (provided by the flattening pass)
+
+mod eliminate_expression;
+
+mod eliminate_statement;
+
+mod eliminate_program;
+
+pub mod common_subexpression_eliminator;
+pub use common_subexpression_eliminator::*;
+
+use crate::{Pass, SymbolTable, TypeTable};
+
+use leo_ast::{Ast, NodeBuilder, ProgramVisitorMut};
+use leo_errors::Result;
+
+impl<'a> Pass for CommonSubexpressionEliminator<'a> {
+    type Input = (&'a mut Ast, &'a NodeBuilder, &'a SymbolTable, &'a TypeTable);
+    type Output = Result<()>;
+
+    fn do_pass((ast, node_builder, symbol_table, type_table): Self::Input) -> Self::Output {
+        let mut visitor = CommonSubexpressionEliminator::new(node_builder, symbol_table, type_table);
+        visitor.visit_program_mut(&mut ast.ast);
+
+        Ok(())
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::NodeBuilder;
+use leo_ast::{AccessExpression, Expression, NodeBuilder, Statement};
 use leo_span::Symbol;
 
 use indexmap::IndexSet;
@@ -33,4 +33,31 @@ impl<'a> DeadCodeEliminator<'a> {
     pub fn new(node_builder: &'a NodeBuilder) -> Self {
         Self { node_builder, used_variables: Default::default(), is_necessary: false }
     }
+
+    /// Returns whether `statement` is dead code, i.e., an assignment to unused variables or an expression statement without side effects.
+    pub(crate) fn is_dead(&self, statement: &Statement) -> bool {
+        match statement {
+            // Check the lhs of the assignment to see any of variables are used.
+            Statement::Assign(assign) => match &assign.place {
+                Expression::Identifier(identifier) => !self.used_variables.contains(&identifier.name),
+                Expression::Tuple(tuple_expression) => !tuple_expression.elements.iter().any(|element| match element {
+                    Expression::Identifier(identifier) => self.used_variables.contains(&identifier.name),
+                    _ => unreachable!(
+                        "The previous compiler passes guarantee the tuple elements on the lhs are identifiers."
+                    ),
+                }),
+                _ => unreachable!(
+                    "The previous compiler passes guarantee that `place` is either an identifier or tuple of identifiers."
+                ),
+            },
+            // Function calls and associated function calls are preserved because they may have side effects.
+            // Any other expression is dead code, since they do not have side effects.
+            // Note: array access expressions will have side effects and need to be handled here.
+            Statement::Expression(statement) => !matches!(
+                statement.expression,
+                Expression::Call(_) | Expression::Access(AccessExpression::AssociatedFunction(_))
+            ),
+            _ => false,
+        }
+    }
 }
@@ -16,23 +16,14 @@
 
 use crate::DeadCodeEliminator;
 
-use leo_ast::{
-    AccessExpression,
-    AssociatedFunction,
-    Expression,
-    ExpressionReconstructor,
-    Identifier,
-    StructExpression,
-    StructVariableInitializer,
-    Type,
-};
+use leo_ast::{AssociatedFunction, ExpressionVisitorMut, Identifier, StructExpression, Type};
 use leo_span::sym;
 
-impl ExpressionReconstructor for DeadCodeEliminator<'_> {
+impl ExpressionVisitorMut for DeadCodeEliminator<'_> {
     type AdditionalOutput = ();
 
-    /// Reconstructs the associated function access expression.
-    fn reconstruct_associated_function(&mut self, input: AssociatedFunction) -> (Expression, Self::AdditionalOutput) {
+    /// Visits the associated function access expression.
+    fn visit_associated_function_mut(&mut self, input: &mut AssociatedFunction) {
         // If the associated function manipulates a mapping, mark the statement as necessary.
         match (&input.ty, input.name.name) {
             (Type::Identifier(Identifier { name: sym::Mapping, .. }), sym::remove)
@@ -41,57 +32,26 @@ impl ExpressionReconstructor for DeadCodeEliminator<'_> {
             }
             _ => {}
         };
-        // Reconstruct the access expression.
-        let result = (
-            Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
-                ty: input.ty,
-                name: input.name,
-                arguments: input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect(),
-                span: input.span,
-                id: input.id,
-            })),
-            Default::default(),
-        );
+        // Visit the arguments.
+        input.arguments.iter_mut().for_each(|argument| self.visit_expression_mut(argument));
         // Unset `self.is_necessary`.
         self.is_necessary = false;
-        result
     }
 
-    /// Reconstruct the components of the struct init expression.
-    /// This is necessary since the reconstructor does not explicitly visit each component of the expression.
-    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
-        (
-            Expression::Struct(StructExpression {
-                name: input.name,
-                // Reconstruct each of the struct members.
-                members: input
-                    .members
-                    .into_iter()
-                    .map(|member| StructVariableInitializer {
-                        identifier: member.identifier,
-                        expression: match member.expression {
-                            Some(expression) => Some(self.reconstruct_expression(expression).0),
-                            None => unreachable!("Static single assignment ensures that the expression always exists."),
-                        },
-                        span: member.span,
-                        id: member.id,
-                    })
-                    .collect(),
-                span: input.span,
-                id: input.id,
-            }),
-            Default::default(),
-        )
+    /// Visits the components of the struct init expression.
+    fn visit_struct_init_mut(&mut self, input: &mut StructExpression) {
+        input.members.iter_mut().for_each(|member| match member.expression.as_mut() {
+            Some(expression) => self.visit_expression_mut(expression),
+            None => unreachable!("Static single assignment ensures that the expression always exists."),
+        });
     }
 
     /// Marks identifiers as used.
     /// This is necessary to determine which statements can be eliminated from the program.
-    fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
+    fn visit_identifier_mut(&mut self, input: &mut Identifier) {
         // Add the identifier to `self.used_variables`.
         if self.is_necessary {
             self.used_variables.insert(input.name);
         }
-        // Return the identifier as is.
-        (Expression::Identifier(input), Default::default())
     }
 }
@@ -16,48 +16,24 @@
 
 use crate::DeadCodeEliminator;
 
-use leo_ast::{Finalize, Function, ProgramReconstructor, StatementReconstructor};
+use leo_ast::{Function, ProgramVisitorMut, StatementVisitorMut};
 
-impl ProgramReconstructor for DeadCodeEliminator<'_> {
-    fn reconstruct_function(&mut self, input: Function) -> Function {
+impl ProgramVisitorMut for DeadCodeEliminator<'_> {
+    fn visit_function_mut(&mut self, input: &mut Function) {
         // Reset the state of the dead code eliminator.
         self.used_variables.clear();
         self.is_necessary = false;
 
         // Traverse the function body.
-        let block = self.reconstruct_block(input.block).0;
+        self.visit_block_mut(&mut input.block);
 
-        // Reconstruct the finalize block, if it exists.
-        let finalize = input.finalize.map(|finalize| {
+        // Traverse the finalize block, if it exists.
+        if let Some(finalize) = input.finalize.as_mut() {
             // Reset the state of the dead code eliminator.
             self.used_variables.clear();
             self.is_necessary = false;
 
-            // Traverse the finalize block.
-            let block = self.reconstruct_block(finalize.block).0;
-
-            Finalize {
-                identifier: finalize.identifier,
-                input: finalize.input,
-                output: finalize.output,
-                output_type: finalize.output_type,
-                block,
-                span: finalize.span,
-                id: finalize.id,
-            }
-        });
-
-        Function {
-            annotations: input.annotations,
-            variant: input.variant,
-            identifier: input.identifier,
-            input: input.input,
-            output: input.output,
-            output_type: input.output_type,
-            block,
-            finalize,
-            span: input.span,
-            id: input.id,
+            self.visit_block_mut(&mut finalize.block);
         }
     }
 }
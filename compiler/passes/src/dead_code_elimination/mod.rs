@@ -60,17 +60,17 @@ pub use dead_code_eliminator::*;
 
 use crate::Pass;
 
-use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_ast::{Ast, NodeBuilder, ProgramVisitorMut};
 use leo_errors::Result;
 
 impl<'a> Pass for DeadCodeEliminator<'a> {
-    type Input = (Ast, &'a NodeBuilder);
-    type Output = Result<Ast>;
+    type Input = (&'a mut Ast, &'a NodeBuilder);
+    type Output = Result<()>;
 
     fn do_pass((ast, node_builder): Self::Input) -> Self::Output {
-        let mut reconstructor = DeadCodeEliminator::new(node_builder);
-        let program = reconstructor.reconstruct_program(ast.into_repr());
+        let mut visitor = DeadCodeEliminator::new(node_builder);
+        visitor.visit_program_mut(&mut ast.ast);
 
-        Ok(Ast::new(program))
+        Ok(())
     }
 }
@@ -14,26 +14,48 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::Destructurer;
+use crate::{Destructurer, Refresher};
 
-use leo_ast::{Expression, ExpressionReconstructor, Statement, TupleAccess};
+use leo_ast::{AccessExpression, Expression, ExpressionVisitorMut, Statement};
 
-impl ExpressionReconstructor for Destructurer<'_> {
+impl ExpressionVisitorMut for Destructurer<'_> {
     type AdditionalOutput = Vec<Statement>;
 
     /// Replaces a tuple access expression with the appropriate expression.
-    fn reconstruct_tuple_access(&mut self, input: TupleAccess) -> (Expression, Self::AdditionalOutput) {
-        // Lookup the expression in the tuple map.
-        match input.tuple.as_ref() {
-            Expression::Identifier(identifier) => {
-                match self.tuples.get(&identifier.name).and_then(|tuple| tuple.elements.get(input.index.value())) {
-                    Some(element) => (element.clone(), Default::default()),
-                    None => {
-                        unreachable!("SSA guarantees that all tuples are declared and indices are valid.")
+    fn visit_expression_mut(&mut self, input: &mut Expression) -> Self::AdditionalOutput {
+        match input {
+            Expression::Access(AccessExpression::Tuple(access)) => {
+                // Lookup the expression in the tuple map.
+                let mut element = match access.tuple.as_ref() {
+                    Expression::Identifier(identifier) => {
+                        match self.tuples.get(&identifier.name).and_then(|tuple| tuple.elements.get(access.index.value()))
+                        {
+                            Some(element) => element.clone(),
+                            None => {
+                                unreachable!("SSA guarantees that all tuples are declared and indices are valid.")
+                            }
+                        }
                     }
-                }
+                    _ => unreachable!("SSA guarantees that subexpressions are identifiers or literals."),
+                };
+                // The element is copied, so its nodes are given fresh IDs.
+                Refresher::new(self.node_builder, self.type_table).visit_expression_mut(&mut element);
+                *input = element;
+                Default::default()
             }
-            _ => unreachable!("SSA guarantees that subexpressions are identifiers or literals."),
+            Expression::Access(access) => self.visit_access_mut(access),
+            Expression::Array(array) => self.visit_array_mut(array),
+            Expression::Binary(binary) => self.visit_binary_mut(binary),
+            Expression::Call(call) => self.visit_call_mut(call),
+            Expression::Cast(cast) => self.visit_cast_mut(cast),
+            Expression::Struct(struct_) => self.visit_struct_init_mut(struct_),
+            Expression::Err(err) => self.visit_err_mut(err),
+            Expression::Identifier(identifier) => self.visit_identifier_mut(identifier),
+            Expression::Literal(literal) => self.visit_literal_mut(literal),
+            Expression::Ternary(ternary) => self.visit_ternary_mut(ternary),
+            Expression::Tuple(tuple) => self.visit_tuple_mut(tuple),
+            Expression::Unary(unary) => self.visit_unary_mut(unary),
+            Expression::Unit(unit) => self.visit_unit_mut(unit),
         }
     }
 }
@@ -16,6 +16,6 @@
 
 use crate::Destructurer;
 
-use leo_ast::ProgramReconstructor;
+use leo_ast::ProgramVisitorMut;
 
-impl ProgramReconstructor for Destructurer<'_> {}
+impl ProgramVisitorMut for Destructurer<'_> {}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::Destructurer;
+use crate::{Destructurer, Refresher};
 
 use leo_ast::{
     AssignStatement,
@@ -23,37 +23,51 @@ use leo_ast::{
     ConsoleStatement,
     DefinitionStatement,
     Expression,
-    ExpressionReconstructor,
+    ExpressionVisitorMut,
     Identifier,
     IterationStatement,
     Node,
     ReturnStatement,
     Statement,
-    StatementReconstructor,
+    StatementVisitorMut,
     TupleExpression,
     Type,
 };
 
 use itertools::Itertools;
 
-impl StatementReconstructor for Destructurer<'_> {
-    /// Flattens an assign statement, if necessary.
-    /// Marks variables as structs as necessary.
-    /// Note that new statements are only produced if the right hand side is a ternary expression over structs.
-    /// Otherwise, the statement is returned as is.
-    fn reconstruct_assign(&mut self, assign: AssignStatement) -> (Statement, Self::AdditionalOutput) {
-        // Flatten the rhs of the assignment.
-        let value = self.reconstruct_expression(assign.value).0;
-        match (assign.place, value.clone()) {
+impl StatementVisitorMut for Destructurer<'_> {
+    /// Destructures an assign statement, if necessary.
+    /// Note that new statements are only produced if both sides of the assignment are tuples.
+    /// Assignments that only define a tuple are replaced with a dummy statement.
+    /// Otherwise, the statement is updated in place.
+    fn visit_statement_mut(&mut self, input: &mut Statement) -> Self::AdditionalOutput {
+        let assign = match input {
+            Statement::Assign(assign) => assign,
+            Statement::Assert(stmt) => return self.visit_assert_mut(stmt),
+            Statement::Block(stmt) => return self.visit_block_mut(stmt),
+            Statement::Conditional(stmt) => return self.visit_conditional_mut(stmt),
+            Statement::Console(stmt) => return self.visit_console_mut(stmt),
+            Statement::Const(stmt) => return self.visit_const_mut(stmt),
+            Statement::Definition(stmt) => return self.visit_definition_mut(stmt),
+            Statement::Expression(stmt) => return self.visit_expression_statement_mut(stmt),
+            Statement::Iteration(stmt) => return self.visit_iteration_mut(stmt),
+            Statement::Return(stmt) => return self.visit_return_mut(stmt),
+        };
+
+        // Destructure the rhs of the assignment.
+        self.visit_expression_mut(&mut assign.value);
+        match (&assign.place, &assign.value) {
             // If the lhs is an identifier and the rhs is a tuple, then add the tuple to `self.tuples`.
-            // Return a dummy statement in its place.
+            // Replace the statement with a dummy statement.
             (Expression::Identifier(identifier), Expression::Tuple(tuple)) => {
-                self.tuples.insert(identifier.name, tuple);
+                self.tuples.insert(identifier.name, tuple.clone());
                 // Note that tuple assignments are removed from the AST.
-                (Statement::dummy(Default::default(), self.node_builder.next_id()), Default::default())
+                *input = Statement::dummy(Default::default(), self.node_builder.next_id());
+                Default::default()
             }
             // If the lhs is an identifier and the rhs is an identifier that is a tuple, then add it to `self.tuples`.
-            // Return a dummy statement in its place.
+            // Replace the statement with a dummy statement.
             (Expression::Identifier(lhs_identifier), Expression::Identifier(rhs_identifier))
                 if self.tuples.contains_key(&rhs_identifier.name) =>
             {
@@ -61,7 +75,8 @@ impl StatementReconstructor for Destructurer<'_> {
                 // Note that the `unwrap` is safe since the match arm checks that the entry exists.
                 self.tuples.insert(lhs_identifier.name, self.tuples.get(&rhs_identifier.name).unwrap().clone());
                 // Note that tuple assignments are removed from the AST.
-                (Statement::dummy(Default::default(), self.node_builder.next_id()), Default::default())
+                *input = Statement::dummy(Default::default(), self.node_builder.next_id());
+                Default::default()
             }
             // If the lhs is an identifier and the rhs is a function call that produces a tuple, then add it to `self.tuples`.
             (Expression::Identifier(lhs_identifier), Expression::Call(call)) => {
@@ -72,7 +87,7 @@ impl StatementReconstructor for Destructurer<'_> {
                 };
 
                 match &value_type {
-                    // If the function returns a tuple, reconstruct the assignment and add an entry to `self.tuples`.
+                    // If the function returns a tuple, update the assignment and add an entry to `self.tuples`.
                     Type::Tuple(tuple) => {
                         // Create a new tuple expression with unique identifiers for each index of the lhs.
                         let tuple_expression = TupleExpression {
@@ -107,60 +122,31 @@ impl StatementReconstructor for Destructurer<'_> {
                         // Update the type table with the type of the tuple expression.
                         self.type_table.insert(tuple_expression.id, Type::Tuple(tuple.clone()));
 
-                        // Construct a new assignment statement with a tuple expression on the lhs.
-                        (
-                            Statement::Assign(Box::new(AssignStatement {
-                                place: Expression::Tuple(tuple_expression),
-                                value: Expression::Call(call),
-                                span: Default::default(),
-                                id: self.node_builder.next_id(),
-                            })),
-                            Default::default(),
-                        )
+                        // Put the tuple expression on the lhs of the assignment.
+                        assign.place = Expression::Tuple(tuple_expression);
+                        assign.span = Default::default();
+                        assign.id = self.node_builder.next_id();
                     }
-                    // Otherwise, reconstruct the assignment as is.
-                    _ => (self.simple_assign_statement(lhs_identifier, Expression::Call(call)), Default::default()),
+                    // Otherwise, keep the assignment as is.
+                    _ => self.simple_assign_statement(assign),
                 }
+                Default::default()
+            }
+            (Expression::Identifier(_), _) => {
+                self.simple_assign_statement(assign);
+                Default::default()
             }
-            (Expression::Identifier(identifier), expression) => {
-                (self.simple_assign_statement(identifier, expression), Default::default())
+            // If the lhs is a tuple and the rhs is a function call, then keep the statement.
+            (Expression::Tuple(_), Expression::Call(_)) => {
+                assign.span = Default::default();
+                assign.id = self.node_builder.next_id();
+                Default::default()
             }
-            // If the lhs is a tuple and the rhs is a function call, then return the reconstructed statement.
-            (Expression::Tuple(tuple), Expression::Call(call)) => (
-                Statement::Assign(Box::new(AssignStatement {
-                    place: Expression::Tuple(tuple),
-                    value: Expression::Call(call),
-                    span: Default::default(),
-                    id: self.node_builder.next_id(),
-                })),
-                Default::default(),
-            ),
             // If the lhs is a tuple and the rhs is a tuple, create a new assign statement for each tuple element.
             (Expression::Tuple(lhs_tuple), Expression::Tuple(rhs_tuple)) => {
-                let statements = lhs_tuple
-                    .elements
-                    .into_iter()
-                    .zip_eq(rhs_tuple.elements)
-                    .map(|(lhs, rhs)| {
-                        // Get the type of the rhs.
-                        let type_ = match self.type_table.get(&lhs.id()) {
-                            Some(type_) => type_.clone(),
-                            None => {
-                                unreachable!("Type checking guarantees that the type of the lhs is in the type table.")
-                            }
-                        };
-                        // Set the type of the lhs.
-                        self.type_table.insert(rhs.id(), type_);
-                        // Return the assign statement.
-                        Statement::Assign(Box::new(AssignStatement {
-                            place: lhs,
-                            value: rhs,
-                            span: Default::default(),
-                            id: self.node_builder.next_id(),
-                        }))
-                    })
-                    .collect();
-                (Statement::dummy(Default::default(), self.node_builder.next_id()), statements)
+                let statements = self.assign_elements(lhs_tuple, rhs_tuple);
+                *input = Statement::dummy(Default::default(), self.node_builder.next_id());
+                statements
             }
             // If the lhs is a tuple and the rhs is an identifier that is a tuple, create a new assign statement for each tuple element.
             (Expression::Tuple(lhs_tuple), Expression::Identifier(identifier))
@@ -169,31 +155,9 @@ impl StatementReconstructor for Destructurer<'_> {
                 // Lookup the entry in `self.tuples`.
                 // Note that the `unwrap` is safe since the match arm checks that the entry exists.
                 let rhs_tuple = self.tuples.get(&identifier.name).unwrap().clone();
-                // Create a new assign statement for each tuple element.
-                let statements = lhs_tuple
-                    .elements
-                    .into_iter()
-                    .zip_eq(rhs_tuple.elements.into_iter())
-                    .map(|(lhs, rhs)| {
-                        // Get the type of the rhs.
-                        let type_ = match self.type_table.get(&lhs.id()) {
-                            Some(type_) => type_.clone(),
-                            None => {
-                                unreachable!("Type checking guarantees that the type of the lhs is in the type table.")
-                            }
-                        };
-                        // Set the type of the lhs.
-                        self.type_table.insert(rhs.id(), type_);
-                        // Return the assign statement.
-                        Statement::Assign(Box::new(AssignStatement {
-                            place: lhs,
-                            value: rhs,
-                            span: Default::default(),
-                            id: self.node_builder.next_id(),
-                        }))
-                    })
-                    .collect();
-                (Statement::dummy(Default::default(), self.node_builder.next_id()), statements)
+                let statements = self.assign_elements(lhs_tuple, &rhs_tuple);
+                *input = Statement::dummy(Default::default(), self.node_builder.next_id());
+                statements
             }
             // If the lhs of an assignment is a tuple, then the rhs can be one of the following:
             //  - A function call that produces a tuple. (handled above)
@@ -207,58 +171,56 @@ impl StatementReconstructor for Destructurer<'_> {
         }
     }
 
-    fn reconstruct_block(&mut self, block: Block) -> (Block, Self::AdditionalOutput) {
-        let mut statements = Vec::with_capacity(block.statements.len());
+    fn visit_assign_mut(&mut self, _: &mut AssignStatement) -> Self::AdditionalOutput {
+        unreachable!("`visit_statement_mut` destructures assignment statements.")
+    }
+
+    fn visit_block_mut(&mut self, input: &mut Block) -> Self::AdditionalOutput {
+        let mut statements = Vec::with_capacity(input.statements.len());
 
-        // Reconstruct the statements in the block, accumulating any additional statements.
-        for statement in block.statements {
-            let (reconstructed_statement, additional_statements) = self.reconstruct_statement(statement);
+        // Visit the statements in the block, accumulating any additional statements.
+        for mut statement in core::mem::take(&mut input.statements) {
+            let additional_statements = self.visit_statement_mut(&mut statement);
             statements.extend(additional_statements);
-            statements.push(reconstructed_statement);
+            statements.push(statement);
         }
 
-        (Block { span: block.span, statements, id: self.node_builder.next_id() }, Default::default())
+        input.statements = statements;
+        input.id = self.node_builder.next_id();
+
+        Default::default()
     }
 
-    fn reconstruct_conditional(&mut self, _: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+    fn visit_conditional_mut(&mut self, _: &mut ConditionalStatement) -> Self::AdditionalOutput {
         unreachable!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
     }
 
-    fn reconstruct_console(&mut self, _: ConsoleStatement) -> (Statement, Self::AdditionalOutput) {
+    fn visit_console_mut(&mut self, _: &mut ConsoleStatement) -> Self::AdditionalOutput {
         unreachable!("`ConsoleStatement`s should not be in the AST at this phase of compilation.")
     }
 
-    fn reconstruct_definition(&mut self, _: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+    fn visit_definition_mut(&mut self, _: &mut DefinitionStatement) -> Self::AdditionalOutput {
         unreachable!("`DefinitionStatement`s should not exist in the AST at this phase of compilation.")
     }
 
-    fn reconstruct_iteration(&mut self, _: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+    fn visit_iteration_mut(&mut self, _: &mut IterationStatement) -> Self::AdditionalOutput {
         unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
 
-    /// Reconstructs
-    fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
+    /// Replaces a returned tuple with its elements.
+    fn visit_return_mut(&mut self, input: &mut ReturnStatement) -> Self::AdditionalOutput {
         // Note that SSA guarantees that `input.expression` is either a literal, identifier, or unit expression.
-        let expression = match input.expression {
-            // If the input is an identifier that maps to a tuple, use the tuple expression.
-            Expression::Identifier(identifier) if self.tuples.contains_key(&identifier.name) => {
-                // Note that the `unwrap` is safe since the match arm checks that the entry exists in `self.tuples`.
-                let tuple = self.tuples.get(&identifier.name).unwrap().clone();
-                Expression::Tuple(tuple)
+        // If the input is an identifier that maps to a tuple, use the tuple expression.
+        if let Expression::Identifier(identifier) = &input.expression {
+            if let Some(tuple) = self.tuples.get(&identifier.name) {
+                // The tuple is copied, so its nodes are given fresh IDs.
+                let mut expression = Expression::Tuple(tuple.clone());
+                Refresher::new(self.node_builder, self.type_table).visit_expression_mut(&mut expression);
+                input.expression = expression;
             }
-            // Otherwise, use the original expression.
-            _ => input.expression,
-        };
+        }
 
         // TODO: Do finalize args need to be destructured.
-        (
-            Statement::Return(ReturnStatement {
-                expression,
-                finalize_arguments: input.finalize_arguments,
-                span: input.span,
-                id: input.id,
-            }),
-            Default::default(),
-        )
+        Default::default()
     }
 }
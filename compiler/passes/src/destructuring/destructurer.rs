@@ -14,12 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Assigner, TypeTable};
+use crate::{Assigner, Refresher, TypeTable};
 
-use leo_ast::{Expression, Identifier, Node, NodeBuilder, Statement, TupleExpression};
+use leo_ast::{AssignStatement, Node, NodeBuilder, Statement, TupleExpression};
 use leo_span::Symbol;
 
 use indexmap::IndexMap;
+use itertools::Itertools;
 
 pub struct Destructurer<'a> {
     /// A mapping between node IDs and their types.
@@ -37,15 +38,45 @@ impl<'a> Destructurer<'a> {
         Self { type_table, node_builder, assigner, tuples: IndexMap::new() }
     }
 
-    /// A wrapper around `assigner.simple_assign_statement` that tracks the type of the lhs.
-    pub(crate) fn simple_assign_statement(&mut self, lhs: Identifier, rhs: Expression) -> Statement {
+    /// Updates `assign` in place, as if it were constructed by `assigner.simple_assign_statement`, and tracks the type of the lhs.
+    pub(crate) fn simple_assign_statement(&mut self, assign: &mut AssignStatement) {
         // Update the type table.
-        let type_ = match self.type_table.get(&rhs.id()) {
+        let type_ = match self.type_table.get(&assign.value.id()) {
             Some(type_) => type_,
             None => unreachable!("Type checking guarantees that all expressions have a type."),
         };
-        self.type_table.insert(lhs.id(), type_);
-        // Construct the statement.
-        self.assigner.simple_assign_statement(lhs, rhs, self.node_builder.next_id())
+        self.type_table.insert(assign.place.id(), type_);
+        // Update the statement.
+        assign.span = Default::default();
+        assign.id = self.node_builder.next_id();
+    }
+
+    /// Returns an assign statement for each pair of elements of the tuples `lhs` and `rhs`.
+    /// The elements are copied, so their nodes are given fresh IDs.
+    pub(crate) fn assign_elements(&mut self, lhs: &TupleExpression, rhs: &TupleExpression) -> Vec<Statement> {
+        let mut refresher = Refresher::new(self.node_builder, self.type_table);
+        lhs.elements
+            .iter()
+            .zip_eq(rhs.elements.iter())
+            .map(|(lhs, rhs)| {
+                let (place, value) = (refresher.copy_expression(lhs), refresher.copy_expression(rhs));
+                // Get the type of the rhs.
+                let type_ = match self.type_table.get(&place.id()) {
+                    Some(type_) => type_.clone(),
+                    None => {
+                        unreachable!("Type checking guarantees that the type of the lhs is in the type table.")
+                    }
+                };
+                // Set the type of the lhs.
+                self.type_table.insert(value.id(), type_);
+                // Return the assign statement.
+                Statement::Assign(Box::new(AssignStatement {
+                    place,
+                    value,
+                    span: Default::default(),
+                    id: self.node_builder.next_id(),
+                }))
+            })
+            .collect()
     }
 }
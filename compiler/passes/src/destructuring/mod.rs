@@ -28,17 +28,17 @@ pub use destructurer::*;
 
 use crate::{Assigner, Pass, TypeTable};
 
-use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_ast::{Ast, NodeBuilder, ProgramVisitorMut};
 use leo_errors::Result;
 
 impl<'a> Pass for Destructurer<'a> {
-    type Input = (Ast, &'a TypeTable, &'a NodeBuilder, &'a Assigner);
-    type Output = Result<Ast>;
+    type Input = (&'a mut Ast, &'a TypeTable, &'a NodeBuilder, &'a Assigner);
+    type Output = Result<()>;
 
     fn do_pass((ast, tt, node_builder, assigner): Self::Input) -> Self::Output {
-        let mut reconstructor = Destructurer::new(tt, node_builder, assigner);
-        let program = reconstructor.reconstruct_program(ast.into_repr());
+        let mut visitor = Destructurer::new(tt, node_builder, assigner);
+        visitor.visit_program_mut(&mut ast.ast);
 
-        Ok(Ast::new(program))
+        Ok(())
     }
 }
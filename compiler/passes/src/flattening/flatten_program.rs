@@ -62,6 +62,7 @@ impl ProgramReconstructor for Flattener<'_> {
             output_type: function.output_type,
             block,
             finalize,
+            doc: function.doc,
             span: function.span,
             id: function.id,
         }
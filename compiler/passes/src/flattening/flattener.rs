@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Assigner, SymbolTable, TypeTable};
+use crate::{Assigner, Refresher, SymbolTable, TypeTable};
 
 use leo_ast::{
     AccessExpression,
@@ -85,8 +85,11 @@ impl<'a> Flattener<'a> {
         match self.condition_stack.is_empty() {
             true => None,
             false => {
+                // Each guard is a copy of the conditions, so its nodes are given fresh IDs.
+                let mut refresher = Refresher::new(self.node_builder, self.type_table);
                 let (first, rest) = self.condition_stack.split_first().unwrap();
-                Some(rest.iter().cloned().fold(first.clone(), |acc, condition| {
+                let first = refresher.copy_expression(first);
+                Some(rest.iter().map(|condition| refresher.copy_expression(condition)).fold(first, |acc, condition| {
                     // Construct the binary expression.
                     Expression::Binary(BinaryExpression {
                         op: BinaryOperation::And,
@@ -159,7 +162,7 @@ impl<'a> Flattener<'a> {
                             // Otherwise, assign the expression to a variable and return the variable.
                             _ => {
                                 statements.push(self.simple_assign_statement(place, value));
-                                Expression::Identifier(place)
+                                Expression::Identifier(Refresher::new(self.node_builder, self.type_table).copy(&place))
                             }
                         }
                     };
@@ -183,8 +186,10 @@ impl<'a> Flattener<'a> {
         let place = Identifier { name, span: Default::default(), id: self.node_builder.next_id() };
         // Construct the assignment statement.
         let statement = self.simple_assign_statement(place, expr);
+        // The returned identifier is a use of the variable, so it is given an ID of its own.
+        let identifier = Refresher::new(self.node_builder, self.type_table).copy(&place);
 
-        (place, statement)
+        (identifier, statement)
     }
 
     /// A wrapper around `assigner.simple_assign_statement` that tracks the type of the lhs.
@@ -215,14 +220,17 @@ impl<'a> Flattener<'a> {
                 vec![Vec::with_capacity(returns.len()); number_of_finalize_arguments];
 
             // Aggregate the return expressions and finalize arguments and their respective guards.
+            // The finalize arguments use copies of the guards, whose nodes are given fresh IDs.
+            let mut refresher = Refresher::new(self.node_builder, self.type_table);
             for (guard, return_statement) in returns {
-                return_expressions.push((guard.clone(), return_statement.expression));
                 if let Some(arguments) = return_statement.finalize_arguments {
                     for (i, argument) in arguments.into_iter().enumerate() {
                         // Note that the indexing is safe since we initialize `finalize_arguments` with the correct length.
-                        finalize_arguments[i].push((guard.clone(), argument));
+                        let guard = guard.as_ref().map(|guard| refresher.copy_expression(guard));
+                        finalize_arguments[i].push((guard, argument));
                     }
                 }
+                return_expressions.push((guard, return_statement.expression));
             }
 
             // Fold the return expressions into a single expression.
@@ -278,13 +286,15 @@ impl<'a> Flattener<'a> {
     ) -> (Expression, Vec<Statement>) {
         // Initialize a vector to accumulate any statements generated.
         let mut statements = Vec::new();
+        // The operands and the condition are copied for each element, with fresh node IDs.
+        let mut refresher = Refresher::new(self.node_builder, self.type_table);
         // For each array element, construct a new ternary expression.
         let elements = (0..array.length())
             .map(|i| {
                 // Create an assignment statement for the first access expression.
                 let (first, stmt) =
                     self.unique_simple_assign_statement(Expression::Access(AccessExpression::Array(ArrayAccess {
-                        array: Box::new(Expression::Identifier(*first)),
+                        array: Box::new(Expression::Identifier(refresher.copy(first))),
                         index: Box::new(Expression::Literal(Literal::Integer(
                             IntegerType::U32,
                             i.to_string(),
@@ -310,7 +320,7 @@ impl<'a> Flattener<'a> {
                 // Create an assignment statement for the second access expression.
                 let (second, stmt) =
                     self.unique_simple_assign_statement(Expression::Access(AccessExpression::Array(ArrayAccess {
-                        array: Box::new(Expression::Identifier(*second)),
+                        array: Box::new(Expression::Identifier(refresher.copy(second))),
                         index: Box::new(Expression::Literal(Literal::Integer(
                             IntegerType::U32,
                             i.to_string(),
@@ -336,7 +346,7 @@ impl<'a> Flattener<'a> {
 
                 // Recursively reconstruct the ternary expression.
                 let (expression, stmts) = self.reconstruct_ternary(TernaryExpression {
-                    condition: Box::new(condition.clone()),
+                    condition: Box::new(refresher.copy_expression(condition)),
                     // Access the member of the first expression.
                     if_true: Box::new(Expression::Identifier(first)),
                     // Access the member of the second expression.
@@ -391,6 +401,8 @@ impl<'a> Flattener<'a> {
     ) -> (Expression, Vec<Statement>) {
         // Initialize a vector to accumulate any statements generated.
         let mut statements = Vec::new();
+        // The operands, the condition, and the member names are copied for each member, with fresh node IDs.
+        let mut refresher = Refresher::new(self.node_builder, self.type_table);
         // For each struct member, construct a new ternary expression.
         let members = struct_
             .members
@@ -399,8 +411,8 @@ impl<'a> Flattener<'a> {
                 // Create an assignment statement for the first access expression.
                 let (first, stmt) =
                     self.unique_simple_assign_statement(Expression::Access(AccessExpression::Member(MemberAccess {
-                        inner: Box::new(Expression::Identifier(*first)),
-                        name: *identifier,
+                        inner: Box::new(Expression::Identifier(refresher.copy(first))),
+                        name: refresher.copy(identifier),
                         span: Default::default(),
                         id: {
                             // Create a new node ID for the access expression.
@@ -414,8 +426,8 @@ impl<'a> Flattener<'a> {
                 // Create an assignment statement for the second access expression.
                 let (second, stmt) =
                     self.unique_simple_assign_statement(Expression::Access(AccessExpression::Member(MemberAccess {
-                        inner: Box::new(Expression::Identifier(*second)),
-                        name: *identifier,
+                        inner: Box::new(Expression::Identifier(refresher.copy(second))),
+                        name: refresher.copy(identifier),
                         span: Default::default(),
                         id: {
                             // Create a new node ID for the access expression.
@@ -428,7 +440,7 @@ impl<'a> Flattener<'a> {
                 statements.push(stmt);
                 // Recursively reconstruct the ternary expression.
                 let (expression, stmts) = self.reconstruct_ternary(TernaryExpression {
-                    condition: Box::new(condition.clone()),
+                    condition: Box::new(refresher.copy_expression(condition)),
                     // Access the member of the first expression.
                     if_true: Box::new(Expression::Identifier(first)),
                     // Access the member of the second expression.
@@ -447,7 +459,7 @@ impl<'a> Flattener<'a> {
                 statements.extend(stmts);
 
                 StructVariableInitializer {
-                    identifier: *identifier,
+                    identifier: refresher.copy(identifier),
                     expression: Some(expression),
                     span: Default::default(),
                     id: self.node_builder.next_id(),
@@ -456,7 +468,7 @@ impl<'a> Flattener<'a> {
             .collect();
 
         let (expr, stmts) = self.reconstruct_struct_init(StructExpression {
-            name: struct_.identifier,
+            name: refresher.copy(&struct_.identifier),
             members,
             span: Default::default(),
             id: {
@@ -488,6 +500,8 @@ impl<'a> Flattener<'a> {
     ) -> (Expression, Vec<Statement>) {
         // Initialize a vector to accumulate any statements generated.
         let mut statements = Vec::new();
+        // The operands and the condition are copied for each element, with fresh node IDs.
+        let mut refresher = Refresher::new(self.node_builder, self.type_table);
         // For each tuple element, construct a new ternary expression.
         let elements = tuple_type
             .elements()
@@ -497,7 +511,7 @@ impl<'a> Flattener<'a> {
                 // Create an assignment statement for the first access expression.
                 let (first, stmt) =
                     self.unique_simple_assign_statement(Expression::Access(AccessExpression::Tuple(TupleAccess {
-                        tuple: Box::new(Expression::Identifier(*first)),
+                        tuple: Box::new(Expression::Identifier(refresher.copy(first))),
                         index: NonNegativeNumber::from(i),
                         span: Default::default(),
                         id: {
@@ -512,7 +526,7 @@ impl<'a> Flattener<'a> {
                 // Create an assignment statement for the second access expression.
                 let (second, stmt) =
                     self.unique_simple_assign_statement(Expression::Access(AccessExpression::Tuple(TupleAccess {
-                        tuple: Box::new(Expression::Identifier(*second)),
+                        tuple: Box::new(Expression::Identifier(refresher.copy(second))),
                         index: NonNegativeNumber::from(i),
                         span: Default::default(),
                         id: {
@@ -527,7 +541,7 @@ impl<'a> Flattener<'a> {
 
                 // Recursively reconstruct the ternary expression.
                 let (expression, stmts) = self.reconstruct_ternary(TernaryExpression {
-                    condition: Box::new(condition.clone()),
+                    condition: Box::new(refresher.copy_expression(condition)),
                     // Access the member of the first expression.
                     if_true: Box::new(Expression::Identifier(first)),
                     // Access the member of the second expression.
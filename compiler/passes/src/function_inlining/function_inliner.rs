@@ -28,8 +28,8 @@ pub struct FunctionInliner<'a> {
     pub(crate) assignment_renamer: AssignmentRenamer<'a>,
     /// A mapping between node IDs and their types.
     pub(crate) type_table: &'a TypeTable,
-    /// A map of reconstructed functions in the current program scope.
-    pub(crate) reconstructed_functions: Vec<(Symbol, Function)>,
+    /// The functions of the current program scope whose calls have been inlined.
+    pub(crate) inlined_functions: Vec<(Symbol, Function)>,
 }
 
 impl<'a> FunctionInliner<'a> {
@@ -44,7 +44,7 @@ impl<'a> FunctionInliner<'a> {
             node_builder,
             call_graph,
             assignment_renamer: AssignmentRenamer::new(assigner),
-            reconstructed_functions: Default::default(),
+            inlined_functions: Default::default(),
             type_table,
         }
     }
@@ -14,16 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{FunctionInliner, Replacer};
+use crate::{FunctionInliner, Refresher, Replacer};
 
 use leo_ast::{
     CallExpression,
     Expression,
-    ExpressionReconstructor,
+    ExpressionVisitorMut,
     Identifier,
     ReturnStatement,
     Statement,
     StatementReconstructor,
+    StatementVisitorMut,
     Type,
     UnitExpression,
     Variant,
@@ -32,35 +33,65 @@ use leo_ast::{
 use indexmap::IndexMap;
 use itertools::Itertools;
 
-impl ExpressionReconstructor for FunctionInliner<'_> {
+impl ExpressionVisitorMut for FunctionInliner<'_> {
     type AdditionalOutput = Vec<Statement>;
 
-    fn reconstruct_call(&mut self, input: CallExpression) -> (Expression, Self::AdditionalOutput) {
+    /// Replaces calls to `inline` functions with the value they return.
+    fn visit_expression_mut(&mut self, input: &mut Expression) -> Self::AdditionalOutput {
+        match input {
+            Expression::Call(call) => match self.inline_call(call) {
+                Some((expression, statements)) => {
+                    *input = expression;
+                    statements
+                }
+                None => Default::default(),
+            },
+            Expression::Access(access) => self.visit_access_mut(access),
+            Expression::Array(array) => self.visit_array_mut(array),
+            Expression::Binary(binary) => self.visit_binary_mut(binary),
+            Expression::Cast(cast) => self.visit_cast_mut(cast),
+            Expression::Struct(struct_) => self.visit_struct_init_mut(struct_),
+            Expression::Err(err) => self.visit_err_mut(err),
+            Expression::Identifier(identifier) => self.visit_identifier_mut(identifier),
+            Expression::Literal(literal) => self.visit_literal_mut(literal),
+            Expression::Ternary(ternary) => self.visit_ternary_mut(ternary),
+            Expression::Tuple(tuple) => self.visit_tuple_mut(tuple),
+            Expression::Unary(unary) => self.visit_unary_mut(unary),
+            Expression::Unit(unit) => self.visit_unit_mut(unit),
+        }
+    }
+}
+
+impl FunctionInliner<'_> {
+    /// Inlines the call, if the callee is an `inline` function.
+    /// Returns the expression that replaces the call, and the statements of the callee's body, which precede it.
+    fn inline_call(&mut self, input: &mut CallExpression) -> Option<(Expression, Vec<Statement>)> {
         // Type checking guarantees that only functions local to the program scope can be inlined.
         if input.external.is_some() {
-            return (Expression::Call(input), Default::default());
+            return None;
         }
 
         // Get the name of the callee function.
-        let function_name = match *input.function {
+        let function_name = match input.function.as_ref() {
             Expression::Identifier(identifier) => identifier.name,
             _ => unreachable!("Parser guarantees that `input.function` is always an identifier."),
         };
 
-        // Lookup the reconstructed callee function.
-        // Since this pass processes functions in post-order, the callee function is guaranteed to exist in `self.reconstructed_functions`
-        let (_, callee) = self.reconstructed_functions.iter().find(|(symbol, _)| *symbol == function_name).unwrap();
+        // Lookup the callee function.
+        // Since this pass processes functions in post-order, the callee function is guaranteed to exist in `self.inlined_functions`
+        let (_, callee) = self.inlined_functions.iter().find(|(symbol, _)| *symbol == function_name).unwrap();
 
-        // Inline the callee function, if required, otherwise, return the call expression.
+        // Inline the callee function, if required, otherwise, keep the call expression.
         match callee.variant {
-            Variant::Transition | Variant::Standard => (Expression::Call(input), Default::default()),
+            Variant::Transition | Variant::Standard => None,
             Variant::Inline => {
                 // Construct a mapping from input variables of the callee function to arguments passed to the callee.
+                // Note that the call expression is replaced, so its arguments can be moved.
                 let parameter_to_argument = callee
                     .input
                     .iter()
                     .map(|input| input.identifier().name)
-                    .zip_eq(input.arguments)
+                    .zip_eq(core::mem::take(&mut input.arguments))
                     .collect::<IndexMap<_, _>>();
 
                 // Initializer `self.assignment_renamer` with the function parameters.
@@ -83,7 +114,11 @@ impl ExpressionReconstructor for FunctionInliner<'_> {
                     Some(expression) => expression.clone(),
                     None => Expression::Identifier(*identifier),
                 };
-                let mut inlined_statements = Replacer::new(replace).reconstruct_block(unique_block).0.statements;
+                let mut inlined_block = Replacer::new(replace).reconstruct_block(unique_block).0;
+
+                // The inlined block is a copy of the callee's body and arguments, so its nodes are given fresh IDs.
+                Refresher::new(self.node_builder, self.type_table).visit_block_mut(&mut inlined_block);
+                let mut inlined_statements = inlined_block.statements;
 
                 // If the inlined block returns a value, then use the value in place of the call expression, otherwise, use the unit expression.
                 let result = match inlined_statements.last() {
@@ -101,7 +136,7 @@ impl ExpressionReconstructor for FunctionInliner<'_> {
                     }
                 };
 
-                (result, inlined_statements)
+                Some((result, inlined_statements))
             }
         }
     }
@@ -16,45 +16,36 @@
 
 use crate::FunctionInliner;
 
-use leo_ast::{Function, ProgramReconstructor, ProgramScope};
+use leo_ast::{Function, ProgramScope, ProgramVisitorMut};
 use leo_span::Symbol;
 
 use indexmap::IndexMap;
 
-impl ProgramReconstructor for FunctionInliner<'_> {
-    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+impl ProgramVisitorMut for FunctionInliner<'_> {
+    fn visit_program_scope_mut(&mut self, input: &mut ProgramScope) {
         // Get the post-order ordering of the call graph.
         // Note that the post-order always contains all nodes in the call graph.
         // Note that the unwrap is safe since type checking guarantees that the call graph is acyclic.
         let order = self.call_graph.post_order().unwrap();
 
         // Construct map to provide faster lookup of functions
-        let mut function_map: IndexMap<Symbol, Function> = input.functions.into_iter().collect();
+        let mut function_map: IndexMap<Symbol, Function> = core::mem::take(&mut input.functions).into_iter().collect();
 
-        // Reconstruct and accumulate each of the functions in post-order.
+        // Visit and accumulate each of the functions in post-order.
         for function_name in &order {
             // None: If `function_name` is not in `input.functions`, then it must be an external function.
             // TODO: Check that this is indeed an external function. Requires a redesign of the symbol table.
-            if let Some(function) = function_map.remove(function_name) {
-                // Reconstruct the function.
-                let reconstructed_function = self.reconstruct_function(function);
-                // Add the reconstructed function to the mapping.
-                self.reconstructed_functions.push((*function_name, reconstructed_function));
+            if let Some(mut function) = function_map.remove(function_name) {
+                // Inline the calls in the function.
+                self.visit_function_mut(&mut function);
+                // Add the function to the mapping.
+                self.inlined_functions.push((*function_name, function));
             }
         }
         // This is a sanity check to ensure that functions in the program scope have been processed.
         assert!(function_map.is_empty(), "All functions in the program scope should have been processed.");
 
-        // Note that this intentionally clears `self.reconstructed_functions` for the next program scope.
-        let functions = core::mem::take(&mut self.reconstructed_functions).into_iter().collect();
-
-        ProgramScope {
-            program_id: input.program_id,
-            structs: input.structs,
-            mappings: input.mappings,
-            functions,
-            consts: input.consts,
-            span: input.span,
-        }
+        // Note that this intentionally clears `self.inlined_functions` for the next program scope.
+        input.functions = core::mem::take(&mut self.inlined_functions);
     }
 }
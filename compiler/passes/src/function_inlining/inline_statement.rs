@@ -23,84 +23,85 @@ use leo_ast::{
     ConsoleStatement,
     DefinitionStatement,
     Expression,
-    ExpressionReconstructor,
     ExpressionStatement,
+    ExpressionVisitorMut,
     IterationStatement,
     Statement,
-    StatementReconstructor,
+    StatementVisitorMut,
 };
 
-impl StatementReconstructor for FunctionInliner<'_> {
-    /// Reconstruct an assignment statement by inlining any function calls.
-    /// This function also segments tuple assignment statements into multiple assignment statements.
-    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
-        let (value, mut statements) = self.reconstruct_expression(input.value.clone());
-        match (input.place, value) {
-            // If the function call produces a tuple, we need to segment the tuple into multiple assignment statements.
-            (Expression::Tuple(left), Expression::Tuple(right)) if left.elements.len() == right.elements.len() => {
-                statements.extend(left.elements.into_iter().zip(right.elements).map(|(lhs, rhs)| {
-                    Statement::Assign(Box::new(AssignStatement {
-                        place: lhs,
-                        value: rhs,
-                        span: Default::default(),
-                        id: self.node_builder.next_id(),
-                    }))
-                }));
-                (Statement::dummy(Default::default(), self.node_builder.next_id()), statements)
-            }
-
-            (place, value) => (
-                Statement::Assign(Box::new(AssignStatement { place, value, span: input.span, id: input.id })),
-                statements,
-            ),
-        }
+impl StatementVisitorMut for FunctionInliner<'_> {
+    /// Inlines any function calls in an assignment statement.
+    fn visit_assign_mut(&mut self, input: &mut AssignStatement) -> Self::AdditionalOutput {
+        self.visit_expression_mut(&mut input.value)
     }
 
-    /// Reconstructs the statements inside a basic block, accumulating any statements produced by function inlining.
-    fn reconstruct_block(&mut self, block: Block) -> (Block, Self::AdditionalOutput) {
-        let mut statements = Vec::with_capacity(block.statements.len());
-
-        for statement in block.statements {
-            let (reconstructed_statement, additional_statements) = self.reconstruct_statement(statement);
+    /// Visits the statements inside a basic block, accumulating any statements produced by function inlining.
+    /// This function also segments tuple assignment statements into multiple assignment statements,
+    /// and removes expression statements whose inlined call returns nothing.
+    fn visit_block_mut(&mut self, input: &mut Block) -> Self::AdditionalOutput {
+        let mut statements = Vec::with_capacity(input.statements.len());
+
+        for mut statement in core::mem::take(&mut input.statements) {
+            let mut additional_statements = self.visit_statement_mut(&mut statement);
+            match &mut statement {
+                // If the function call produces a tuple, we need to segment the tuple into multiple assignment statements.
+                Statement::Assign(assign) => match (&mut assign.place, &mut assign.value) {
+                    (Expression::Tuple(left), Expression::Tuple(right))
+                        if left.elements.len() == right.elements.len() =>
+                    {
+                        let elements =
+                            core::mem::take(&mut left.elements).into_iter().zip(core::mem::take(&mut right.elements));
+                        additional_statements.extend(elements.map(|(lhs, rhs)| {
+                            Statement::Assign(Box::new(AssignStatement {
+                                place: lhs,
+                                value: rhs,
+                                span: Default::default(),
+                                id: self.node_builder.next_id(),
+                            }))
+                        }));
+                        statement = Statement::dummy(Default::default(), self.node_builder.next_id());
+                    }
+                    _ => {}
+                },
+                // If the resulting expression is a unit expression, replace the statement with a dummy statement.
+                Statement::Expression(ExpressionStatement { expression: Expression::Unit(_), .. }) => {
+                    statement = Statement::dummy(Default::default(), self.node_builder.next_id());
+                }
+                _ => {}
+            }
             statements.extend(additional_statements);
-            statements.push(reconstructed_statement);
+            statements.push(statement);
         }
 
-        (Block { span: block.span, statements, id: block.id }, Default::default())
+        input.statements = statements;
+
+        Default::default()
     }
 
     /// Flattening removes conditional statements from the program.
-    fn reconstruct_conditional(&mut self, _: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+    fn visit_conditional_mut(&mut self, _: &mut ConditionalStatement) -> Self::AdditionalOutput {
         unreachable!("`ConditionalStatement`s should not be in the AST at this phase of compilation.")
     }
 
     /// Parsing guarantees that console statements are not present in the program.
-    fn reconstruct_console(&mut self, _: ConsoleStatement) -> (Statement, Self::AdditionalOutput) {
+    fn visit_console_mut(&mut self, _: &mut ConsoleStatement) -> Self::AdditionalOutput {
         unreachable!("`ConsoleStatement`s should not be in the AST at this phase of compilation.")
     }
 
     /// Static single assignment replaces definition statements with assignment statements.
-    fn reconstruct_definition(&mut self, _: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+    fn visit_definition_mut(&mut self, _: &mut DefinitionStatement) -> Self::AdditionalOutput {
         unreachable!("`DefinitionStatement`s should not exist in the AST at this phase of compilation.")
     }
 
-    /// Reconstructs expression statements by inlining any function calls.
-    fn reconstruct_expression_statement(&mut self, input: ExpressionStatement) -> (Statement, Self::AdditionalOutput) {
-        // Reconstruct the expression.
+    /// Inlines any function calls in an expression statement.
+    fn visit_expression_statement_mut(&mut self, input: &mut ExpressionStatement) -> Self::AdditionalOutput {
         // Note that type checking guarantees that the expression is a function call.
-        let (expression, additional_statements) = self.reconstruct_expression(input.expression);
-
-        // If the resulting expression is a unit expression, return a dummy statement.
-        let statement = match expression {
-            Expression::Unit(_) => Statement::dummy(Default::default(), self.node_builder.next_id()),
-            _ => Statement::Expression(ExpressionStatement { expression, span: input.span, id: input.id }),
-        };
-
-        (statement, additional_statements)
+        self.visit_expression_mut(&mut input.expression)
     }
 
     /// Loop unrolling unrolls and removes iteration statements from the program.
-    fn reconstruct_iteration(&mut self, _: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+    fn visit_iteration_mut(&mut self, _: &mut IterationStatement) -> Self::AdditionalOutput {
         unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
 }
@@ -66,17 +66,17 @@ pub use function_inliner::*;
 
 use crate::{Assigner, CallGraph, Pass, TypeTable};
 
-use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_ast::{Ast, NodeBuilder, ProgramVisitorMut};
 use leo_errors::Result;
 
 impl<'a> Pass for FunctionInliner<'a> {
-    type Input = (Ast, &'a NodeBuilder, &'a CallGraph, &'a Assigner, &'a TypeTable);
-    type Output = Result<Ast>;
+    type Input = (&'a mut Ast, &'a NodeBuilder, &'a CallGraph, &'a Assigner, &'a TypeTable);
+    type Output = Result<()>;
 
     fn do_pass((ast, node_builder, call_graph, assigner, tt): Self::Input) -> Self::Output {
-        let mut reconstructor = FunctionInliner::new(node_builder, call_graph, assigner, tt);
-        let program = reconstructor.reconstruct_program(ast.into_repr());
+        let mut visitor = FunctionInliner::new(node_builder, call_graph, assigner, tt);
+        visitor.visit_program_mut(&mut ast.ast);
 
-        Ok(Ast::new(program))
+        Ok(())
     }
 }
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The IR validation pass checks the invariants that the later compiler passes rely on.
+//! Each invariant is established by a pass and holds for the rest of the pipeline:
+//!   - `UniqueNodeIds`: every node has a unique `NodeID`. This holds after parsing, and the passes that copy nodes
+//!     give the copies fresh IDs with a `Refresher`.
+//!   - `SsaForm`: every variable is assigned once, with no definitions. This holds after `StaticSingleAssigner`.
+//!   - `NoConditionals`: there are no conditional statements. This holds after `Flattener`.
+//!   - `NoTuples`: tuples only remain as the outputs of calls and functions. This holds after `Destructurer`.
+//!   - `NoInlineCalls`: there are no calls to `inline` functions. This holds after `FunctionInliner`.
+//!
+//! A violated invariant is a bug in the compiler, and is reported with the pass that broke it and the offending node.
+
+pub mod validator;
+pub use validator::*;
+
+mod validate_expression;
+
+mod validate_program;
+
+mod validate_statement;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for IrValidator<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a [Invariant], &'a str);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, invariants, pass): Self::Input) -> Self::Output {
+        let mut visitor = IrValidator::new(handler, invariants, pass);
+        visitor.visit_program(ast.as_repr());
+        handler.last_err().map_err(|e| *e)
+    }
+}
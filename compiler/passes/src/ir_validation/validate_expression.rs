@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Invariant, IrValidator};
+
+use leo_ast::*;
+
+impl<'a> IrValidator<'a> {
+    /// Checks the `NodeID`s of the identifiers in a type.
+    pub(crate) fn check_type(&mut self, type_: &'a Type) {
+        match type_ {
+            Type::Identifier(identifier) => self.visit_identifier(identifier, &Default::default()),
+            Type::Mapping(mapping) => {
+                self.check_type(&mapping.key);
+                self.check_type(&mapping.value);
+            }
+            Type::Tuple(tuple) => tuple.elements().iter().for_each(|type_| self.check_type(type_)),
+            _ => {}
+        }
+    }
+
+    /// Checks a tuple in a position where tuples remain after destructuring.
+    pub(crate) fn visit_destructured_tuple(&mut self, input: &'a TupleExpression) {
+        input.elements.iter().for_each(|element| self.visit_expression(element, &Default::default()));
+        self.check_node(input);
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for IrValidator<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(access) => {
+                self.visit_expression(&access.array, &Default::default());
+                self.visit_expression(&access.index, &Default::default());
+            }
+            AccessExpression::AssociatedConstant(access) => {
+                self.check_type(&access.ty);
+                self.visit_identifier(&access.name, &Default::default());
+            }
+            AccessExpression::AssociatedFunction(access) => {
+                self.check_type(&access.ty);
+                self.visit_identifier(&access.name, &Default::default());
+                access.arguments.iter().for_each(|argument| self.visit_expression(argument, &Default::default()));
+            }
+            AccessExpression::Member(access) => {
+                self.visit_expression(&access.inner, &Default::default());
+                self.visit_identifier(&access.name, &Default::default());
+            }
+            AccessExpression::Tuple(access) => {
+                self.visit_expression(&access.tuple, &Default::default());
+                if self.checks(Invariant::NoTuples) {
+                    self.violated_by(Invariant::NoTuples, access);
+                }
+            }
+        }
+        self.check_node(input);
+    }
+
+    fn visit_array(&mut self, input: &'a ArrayExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        input.elements.iter().for_each(|element| self.visit_expression(element, &Default::default()));
+        self.check_node(input);
+    }
+
+    fn visit_binary(&mut self, input: &'a BinaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.left, &Default::default());
+        self.visit_expression(&input.right, &Default::default());
+        self.check_node(input);
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.function, &Default::default());
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, &Default::default()));
+        if let Some(external) = &input.external {
+            self.visit_expression(external, &Default::default());
+        }
+        // Calls to other programs are never inlined, since `inline` functions cannot be imported.
+        if let (Expression::Identifier(function), None) = (input.function.as_ref(), &input.external) {
+            if self.checks(Invariant::NoInlineCalls) && self.inline_functions.contains(&function.name) {
+                self.violated_by(Invariant::NoInlineCalls, input);
+            }
+        }
+        self.check_node(input);
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.expression, &Default::default());
+        self.check_type(&input.type_);
+        self.check_node(input);
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_identifier(&input.name, &Default::default());
+        for member in input.members.iter() {
+            self.visit_identifier(&member.identifier, &Default::default());
+            if let Some(expression) = &member.expression {
+                self.visit_expression(expression, &Default::default());
+            }
+            self.check_node(member);
+        }
+        self.check_node(input);
+    }
+
+    fn visit_err(&mut self, input: &'a ErrExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.check_node(input);
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.check_node(input);
+    }
+
+    fn visit_literal(&mut self, input: &'a Literal, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.check_node(input);
+    }
+
+    fn visit_ternary(&mut self, input: &'a TernaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.condition, &Default::default());
+        self.visit_expression(&input.if_true, &Default::default());
+        self.visit_expression(&input.if_false, &Default::default());
+        self.check_node(input);
+    }
+
+    fn visit_tuple(&mut self, input: &'a TupleExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        if self.checks(Invariant::NoTuples) {
+            self.violated_by(Invariant::NoTuples, input);
+        }
+        self.visit_destructured_tuple(input);
+    }
+
+    fn visit_unary(&mut self, input: &'a UnaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.receiver, &Default::default());
+        self.check_node(input);
+    }
+
+    fn visit_unit(&mut self, input: &'a UnitExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.check_node(input);
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::IrValidator;
+
+use leo_ast::*;
+
+impl<'a> IrValidator<'a> {
+    /// Checks the `NodeID`s of the inputs of a function or finalize block.
+    fn check_inputs(&mut self, inputs: &'a [Input]) {
+        for input in inputs {
+            match input {
+                Input::Internal(internal) => {
+                    self.visit_identifier(&internal.identifier, &Default::default());
+                    self.check_type(&internal.type_);
+                }
+                Input::External(external) => self.check_external(external),
+            }
+            self.check_node(input);
+        }
+    }
+
+    /// Checks the `NodeID`s of the outputs of a function or finalize block.
+    fn check_outputs(&mut self, outputs: &'a [Output]) {
+        for output in outputs {
+            match output {
+                Output::Internal(internal) => self.check_type(&internal.type_),
+                Output::External(external) => self.check_external(external),
+            }
+            self.check_node(output);
+        }
+    }
+
+    fn check_external(&mut self, external: &'a External) {
+        self.visit_identifier(&external.identifier, &Default::default());
+        self.visit_identifier(&external.program_name, &Default::default());
+        self.visit_identifier(&external.record, &Default::default());
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for IrValidator<'a> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        self.inline_functions = input
+            .functions
+            .iter()
+            .filter(|(_, function)| function.variant == Variant::Inline)
+            .map(|(name, _)| *name)
+            .collect();
+
+        input.structs.iter().for_each(|(_, struct_)| self.visit_struct(struct_));
+        input.mappings.iter().for_each(|(_, mapping)| self.visit_mapping(mapping));
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+        input.consts.iter().for_each(|(_, const_)| self.visit_const(const_));
+    }
+
+    fn visit_struct(&mut self, input: &'a Struct) {
+        self.visit_identifier(&input.identifier, &Default::default());
+        for member in input.members.iter() {
+            self.visit_identifier(&member.identifier, &Default::default());
+            self.check_type(&member.type_);
+            self.check_node(member);
+        }
+        self.check_id(input.id, input.identifier, input.span);
+    }
+
+    fn visit_mapping(&mut self, input: &'a Mapping) {
+        self.visit_identifier(&input.identifier, &Default::default());
+        self.check_type(&input.key_type);
+        self.check_type(&input.value_type);
+        self.check_node(input);
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        for annotation in input.annotations.iter() {
+            self.visit_identifier(&annotation.identifier, &Default::default());
+            self.check_node(annotation);
+        }
+        self.visit_identifier(&input.identifier, &Default::default());
+        self.check_inputs(&input.input);
+        self.check_outputs(&input.output);
+        // Variables are local to the function body and the finalize block.
+        self.assigned.clear();
+        self.visit_block(&input.block);
+
+        if let Some(finalize) = &input.finalize {
+            self.visit_identifier(&finalize.identifier, &Default::default());
+            self.check_inputs(&finalize.input);
+            self.check_outputs(&finalize.output);
+            self.assigned.clear();
+            self.visit_block(&finalize.block);
+            self.check_id(finalize.id, finalize.identifier, finalize.span);
+        }
+        self.check_id(input.id, input.identifier, input.span);
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Invariant, IrValidator};
+
+use leo_ast::*;
+
+impl<'a> IrValidator<'a> {
+    /// Checks that a variable assigned by `input` has not been assigned before.
+    fn check_single_assignment(&mut self, place: &'a Expression, input: &'a AssignStatement) {
+        match place {
+            Expression::Identifier(identifier) if !self.assigned.insert(identifier.name) => {
+                self.violated_by(Invariant::SsaForm, input);
+            }
+            Expression::Tuple(tuple) => {
+                tuple.elements.iter().for_each(|element| self.check_single_assignment(element, input))
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for IrValidator<'a> {
+    fn visit_assert(&mut self, input: &'a AssertStatement) {
+        match &input.variant {
+            AssertVariant::Assert(expression) => self.visit_expression(expression, &Default::default()),
+            AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
+                self.visit_expression(left, &Default::default());
+                self.visit_expression(right, &Default::default());
+            }
+        }
+        self.check_node(input);
+    }
+
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        if self.checks(Invariant::SsaForm) {
+            self.check_single_assignment(&input.place, input);
+        }
+        // The outputs of a call remain a tuple after destructuring.
+        match (&input.place, &input.value) {
+            (Expression::Tuple(tuple), Expression::Call(_)) => self.visit_destructured_tuple(tuple),
+            (place, _) => self.visit_expression(place, &Default::default()),
+        }
+        self.visit_expression(&input.value, &Default::default());
+        self.check_node(input);
+    }
+
+    fn visit_block(&mut self, input: &'a Block) {
+        input.statements.iter().for_each(|statement| self.visit_statement(statement));
+        self.check_id(input.id, "{ ... }", input.span);
+    }
+
+    fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
+        if self.checks(Invariant::NoConditionals) {
+            self.violated(Invariant::NoConditionals, input.id, format!("if {} {{ ... }}", input.condition), input.span);
+        }
+        self.visit_expression(&input.condition, &Default::default());
+        self.visit_block(&input.then);
+        if let Some(otherwise) = &input.otherwise {
+            self.visit_statement(otherwise);
+        }
+        self.check_id(input.id, format!("if {} {{ ... }}", input.condition), input.span);
+    }
+
+    fn visit_console(&mut self, input: &'a ConsoleStatement) {
+        match &input.function {
+            ConsoleFunction::Assert(expression) => self.visit_expression(expression, &Default::default()),
+            ConsoleFunction::AssertEq(left, right) | ConsoleFunction::AssertNeq(left, right) => {
+                self.visit_expression(left, &Default::default());
+                self.visit_expression(right, &Default::default());
+            }
+        }
+        self.check_node(input);
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.visit_identifier(&input.place, &Default::default());
+        self.check_type(&input.type_);
+        self.visit_expression(&input.value, &Default::default());
+        self.check_node(input);
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        // The SSA pass replaces definitions with assignments.
+        if self.checks(Invariant::SsaForm) {
+            self.violated_by(Invariant::SsaForm, input);
+        }
+        self.visit_expression(&input.place, &Default::default());
+        self.check_type(&input.type_);
+        self.visit_expression(&input.value, &Default::default());
+        self.check_node(input);
+    }
+
+    fn visit_expression_statement(&mut self, input: &'a ExpressionStatement) {
+        self.visit_expression(&input.expression, &Default::default());
+        self.check_node(input);
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        self.visit_identifier(&input.variable, &Default::default());
+        self.check_type(&input.type_);
+        self.visit_expression(&input.start, &Default::default());
+        self.visit_expression(&input.stop, &Default::default());
+        self.visit_block(&input.block);
+        self.check_id(
+            input.id,
+            format!("for {} in {}..{} {{ ... }}", input.variable, input.start, input.stop),
+            input.span,
+        );
+    }
+
+    fn visit_return(&mut self, input: &'a ReturnStatement) {
+        // Multiple outputs remain a tuple after destructuring.
+        match &input.expression {
+            Expression::Tuple(tuple) => self.visit_destructured_tuple(tuple),
+            expression => self.visit_expression(expression, &Default::default()),
+        }
+        if let Some(arguments) = &input.finalize_arguments {
+            arguments.iter().for_each(|argument| self.visit_expression(argument, &Default::default()));
+        }
+        self.check_node(input);
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Node, NodeID};
+use leo_errors::{emitter::Handler, CompilerError};
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexSet;
+use std::fmt;
+
+/// An invariant of the AST that the later compiler passes rely on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Invariant {
+    /// Every node has a unique `NodeID`.
+    UniqueNodeIds,
+    /// Every variable is assigned exactly once, and there are no definition statements.
+    SsaForm,
+    /// There are no conditional statements.
+    NoConditionals,
+    /// Tuples only appear as the destination of a call or in a return statement.
+    NoTuples,
+    /// There are no calls to `inline` functions.
+    NoInlineCalls,
+}
+
+impl fmt::Display for Invariant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Invariant::UniqueNodeIds => write!(f, "every node has a unique `NodeID`"),
+            Invariant::SsaForm => write!(f, "every variable is assigned exactly once"),
+            Invariant::NoConditionals => write!(f, "there are no conditional statements"),
+            Invariant::NoTuples => write!(f, "tuples are destructured"),
+            Invariant::NoInlineCalls => write!(f, "there are no calls to `inline` functions"),
+        }
+    }
+}
+
+/// A compiler pass that checks the invariants of the AST.
+pub struct IrValidator<'a> {
+    /// The error handler.
+    handler: &'a Handler,
+    /// The invariants to check.
+    invariants: &'a [Invariant],
+    /// The name of the pass that last transformed the AST.
+    pass: &'a str,
+    /// The `NodeID`s that have been seen.
+    pub(crate) seen: IndexSet<NodeID>,
+    /// The variables assigned in the current function body.
+    pub(crate) assigned: IndexSet<Symbol>,
+    /// The `inline` functions in the current program scope.
+    pub(crate) inline_functions: IndexSet<Symbol>,
+}
+
+impl<'a> IrValidator<'a> {
+    /// Initializes a new `IrValidator`.
+    pub fn new(handler: &'a Handler, invariants: &'a [Invariant], pass: &'a str) -> Self {
+        Self {
+            handler,
+            invariants,
+            pass,
+            seen: Default::default(),
+            assigned: Default::default(),
+            inline_functions: Default::default(),
+        }
+    }
+
+    /// Returns whether the given invariant is checked.
+    pub(crate) fn checks(&self, invariant: Invariant) -> bool {
+        self.invariants.contains(&invariant)
+    }
+
+    /// Reports that the node with the given id violates the invariant.
+    pub(crate) fn violated(&self, invariant: Invariant, id: NodeID, node: impl fmt::Display, span: Span) {
+        self.handler.emit_err(CompilerError::ir_invariant_violated(self.pass, invariant, id, node, span));
+    }
+
+    /// Reports that `node` violates the invariant.
+    pub(crate) fn violated_by<N: Node + fmt::Display>(&self, invariant: Invariant, node: &N) {
+        self.violated(invariant, node.id(), node, node.span());
+    }
+
+    /// Checks that the `NodeID` of a node has not been seen before.
+    pub(crate) fn check_id(&mut self, id: NodeID, node: impl fmt::Display, span: Span) {
+        if self.checks(Invariant::UniqueNodeIds) && !self.seen.insert(id) {
+            self.violated(Invariant::UniqueNodeIds, id, node, span);
+        }
+    }
+
+    /// Checks that the `NodeID` of `node` has not been seen before.
+    pub(crate) fn check_node<N: Node + fmt::Display>(&mut self, node: &N) {
+        self.check_id(node.id(), node, node.span());
+    }
+}
@@ -23,6 +23,9 @@ pub use code_generation::*;
 pub mod common;
 pub use common::*;
 
+pub mod common_subexpression_elimination;
+pub use common_subexpression_elimination::*;
+
 pub mod dead_code_elimination;
 pub use dead_code_elimination::*;
 
@@ -35,12 +38,18 @@ pub use flattening::*;
 pub mod function_inlining;
 pub use function_inlining::*;
 
+pub mod ir_validation;
+pub use ir_validation::*;
+
 pub mod loop_unrolling;
 pub use self::loop_unrolling::*;
 
 pub mod pass;
 pub use self::pass::*;
 
+pub mod pruning;
+pub use pruning::*;
+
 pub mod static_single_assignment;
 pub use static_single_assignment::*;
 
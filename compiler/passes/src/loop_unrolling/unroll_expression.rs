@@ -17,7 +17,7 @@
 use leo_ast::*;
 use leo_errors::LoopUnrollerError;
 
-use crate::Unroller;
+use crate::{Refresher, Unroller};
 
 impl ExpressionReconstructor for Unroller<'_> {
     type AdditionalOutput = bool;
@@ -43,9 +43,40 @@ impl ExpressionReconstructor for Unroller<'_> {
 
     fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
         // Substitute the identifier with the constant value if it is a constant.
-        if let Some(expr) = self.constant_propagation_table.borrow().lookup_constant(input.name) {
-            return (expr.clone(), Default::default());
+        // Each substitution is a copy of the value, so its nodes are given fresh IDs.
+        if let Some(mut expr) = self.constant_propagation_table.borrow().lookup_constant(input.name).cloned() {
+            Refresher::new(self.node_builder, self.type_table).visit_expression_mut(&mut expr);
+            return (expr, Default::default());
         }
         (Expression::Identifier(input), Default::default())
     }
+
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                name: input.name,
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| StructVariableInitializer {
+                        identifier: member.identifier,
+                        // The shorthand `x` is expanded to `x: x`, where the value is a new node.
+                        expression: match member.expression {
+                            Some(expression) => Some(self.reconstruct_expression(expression).0),
+                            None => {
+                                let mut identifier = member.identifier;
+                                Refresher::new(self.node_builder, self.type_table).refresh(&mut identifier);
+                                Some(self.reconstruct_identifier(identifier).0)
+                            }
+                        },
+                        span: member.span,
+                        id: member.id,
+                    })
+                    .collect(),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
 }
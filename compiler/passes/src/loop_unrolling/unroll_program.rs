@@ -76,6 +76,7 @@ impl ProgramReconstructor for Unroller<'_> {
             output_type: function.output_type,
             block,
             finalize,
+            doc: function.doc,
             span: function.span,
             id: function.id,
         };
@@ -67,6 +67,7 @@ impl StatementReconstructor for Unroller<'_> {
                 place: input.place,
                 type_: input.type_,
                 value: reconstructed_value_expression,
+                doc: input.doc,
                 span: input.span,
                 id: input.id,
             }),
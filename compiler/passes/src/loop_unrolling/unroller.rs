@@ -23,6 +23,7 @@ use leo_ast::{
     NodeBuilder,
     Statement,
     StatementReconstructor,
+    StatementVisitorMut,
     Type,
     Value,
 };
@@ -35,6 +36,7 @@ use crate::{
     Clusivity,
     LoopBound,
     RangeIterator,
+    Refresher,
     SymbolTable,
     TypeTable,
 };
@@ -250,13 +252,15 @@ impl<'a> Unroller<'a> {
             })
             .collect();
 
-        let block = Statement::Block(Block { statements, span: input.block.span, id: input.block.id });
+        // Each iteration is a copy of the loop body, so its nodes are given fresh IDs.
+        let mut block = Block { statements, span: input.block.span, id: input.block.id };
+        Refresher::new(self.node_builder, self.type_table).visit_block_mut(&mut block);
 
         self.is_unrolling = prior_is_unrolling;
 
         // Exit the scope.
         self.exit_scope(previous_scope_index);
 
-        block
+        Statement::Block(block)
     }
 }
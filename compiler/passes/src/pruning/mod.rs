@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Pruning pass removes the functions, structs, and constants that the program does not use, and warns about each of them.
+//! The pass is run after the Type Checking pass, which builds the call graph and the struct dependency graph.
+//!
+//! The transitions are the entry points of the program, so a function is used if a transition calls it, directly or indirectly.
+//! A struct is used if a record, a mapping, or a used function refers to it, or if it is a member of a used struct.
+//! Records are part of the state of the program, so they are always kept.
+//! A constant is used if a used function refers to it.
+//! The pruned items are also removed from the symbol table and the graphs, so that they are not emitted by code generation.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! program test.aleo {
+//!     struct Point {
+//!         x: u8,
+//!         y: u8,
+//!     }
+//!
+//!     const ZERO: u8 = 0u8;
+//!
+//!     function double(a: u8) -> u8 {
+//!         return a + a;
+//!     }
+//!
+//!     transition main(a: u8) -> u8 {
+//!         return a + 1u8;
+//!     }
+//! }
+//! ```
+//!
+//! The pruning pass produces the following code, and warns about `Point`, `ZERO`, and `double`.
+//! ```leo
+//! program test.aleo {
+//!     transition main(a: u8) -> u8 {
+//!         return a + 1u8;
+//!     }
+//! }
+//! ```
+
+mod prune_expression;
+
+mod prune_statement;
+
+mod prune_program;
+
+pub mod pruner;
+pub use pruner::*;
+
+use crate::{CallGraph, Pass, StructGraph, SymbolTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, PrunerWarning, Result};
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+impl<'a> Pass for Pruner<'a> {
+    type Input = (&'a mut Ast, &'a Handler, &'a mut SymbolTable, &'a mut StructGraph, &'a mut CallGraph);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, symbol_table, struct_graph, call_graph): Self::Input) -> Self::Output {
+        // Find the used items of each program scope.
+        let mut used_items = Vec::with_capacity(ast.ast.program_scopes.len());
+        for scope in ast.ast.program_scopes.values() {
+            let mut visitor = Pruner::new(call_graph, struct_graph);
+            visitor.visit_program_scope(scope);
+            used_items.push((visitor.used_functions, visitor.used_structs, visitor.used_names));
+        }
+
+        // Remove the unused items, in the order in which they are declared.
+        let mut pruned_functions = IndexSet::<Symbol>::new();
+        let mut pruned_structs = IndexSet::<Symbol>::new();
+        for (scope, (used_functions, used_structs, used_names)) in ast.ast.program_scopes.values_mut().zip(used_items) {
+            scope.structs.retain(|(name, struct_)| {
+                let is_used = used_structs.contains(name);
+                if !is_used {
+                    handler.emit_warning(PrunerWarning::unused_struct(name, struct_.identifier.span).into());
+                    pruned_structs.insert(*name);
+                }
+                is_used
+            });
+            scope.consts.retain(|(name, const_)| {
+                let is_used = used_names.contains(name);
+                if !is_used {
+                    handler.emit_warning(PrunerWarning::unused_const(name, const_.place.span).into());
+                }
+                is_used
+            });
+            scope.functions.retain(|(name, function)| {
+                let is_used = used_functions.contains(name);
+                if !is_used {
+                    handler.emit_warning(PrunerWarning::unused_function(name, function.identifier.span).into());
+                    pruned_functions.insert(*name);
+                }
+                is_used
+            });
+        }
+
+        // Code generation emits the structs in the struct graph, so the pruned items are removed from the graphs too.
+        for name in pruned_structs {
+            symbol_table.structs.shift_remove(&name);
+            struct_graph.remove_node(name);
+        }
+        // The scopes of the pruned functions are removed as well, so that the scopes of the remaining functions
+        // are numbered in the order in which the later passes visit them.
+        for name in pruned_functions {
+            symbol_table.remove_fn(name);
+            call_graph.remove_node(name);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Pruner;
+
+use leo_ast::{ExpressionVisitor, Identifier, StructExpression};
+
+impl<'a> ExpressionVisitor<'a> for Pruner<'_> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    /// Marks the struct or record as used, and visits its members.
+    fn visit_struct_init(&mut self, input: &'a StructExpression, _additional: &Self::AdditionalInput) {
+        self.used_structs.insert(input.name.name);
+        input.members.iter().for_each(|member| match &member.expression {
+            Some(expression) => self.visit_expression(expression, &Default::default()),
+            // The member is initialized with the variable of the same name.
+            None => {
+                self.used_names.insert(member.identifier.name);
+            }
+        });
+    }
+
+    /// Marks the name as used. This is how uses of constants are found.
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) {
+        self.used_names.insert(input.name);
+    }
+}
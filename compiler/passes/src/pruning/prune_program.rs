@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Pruner;
+
+use leo_ast::{Function, Mapping, Program, ProgramScope, ProgramVisitor, StatementVisitor, Variant};
+
+impl<'a> ProgramVisitor<'a> for Pruner<'_> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        // The transitions are the entry points of the program, so every other function is only used if a transition calls it.
+        let transitions = input
+            .functions
+            .iter()
+            .filter(|(_, function)| function.variant == Variant::Transition)
+            .map(|(name, _)| *name);
+        self.used_functions = self.call_graph.reachable_from(transitions);
+
+        // Records are part of the state of the program, so they are kept even if no transition uses them.
+        input.structs.iter().filter(|(_, struct_)| struct_.is_record).for_each(|(name, _)| {
+            self.used_structs.insert(*name);
+        });
+
+        input.mappings.iter().for_each(|(_, mapping)| self.visit_mapping(mapping));
+
+        for (name, function) in input.functions.iter() {
+            if self.used_functions.contains(name) {
+                self.visit_function(function);
+            }
+        }
+
+        // A struct is also used if it is a member of a used struct.
+        self.used_structs = self.struct_graph.reachable_from(std::mem::take(&mut self.used_structs));
+    }
+
+    /// Imported programs are compiled separately, so they are not pruned.
+    fn visit_import(&mut self, _input: &'a Program) {}
+
+    fn visit_mapping(&mut self, input: &'a Mapping) {
+        self.use_type(&input.key_type);
+        self.use_type(&input.value_type);
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        input.input.iter().for_each(|input| self.use_type(&input.type_()));
+        self.use_type(&input.output_type);
+        self.visit_block(&input.block);
+
+        if let Some(finalize) = &input.finalize {
+            finalize.input.iter().for_each(|input| self.use_type(&input.type_()));
+            self.use_type(&finalize.output_type);
+            self.visit_block(&finalize.block);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{CallGraph, StructGraph};
+
+use leo_ast::Type;
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+pub struct Pruner<'a> {
+    /// The call graph of the program.
+    pub(crate) call_graph: &'a CallGraph,
+    /// The struct dependency graph of the program.
+    pub(crate) struct_graph: &'a StructGraph,
+    /// The functions called, directly or indirectly, by a transition, including the transitions themselves.
+    pub(crate) used_functions: IndexSet<Symbol>,
+    /// The records, and the structs used by the records, mappings, and used functions.
+    pub(crate) used_structs: IndexSet<Symbol>,
+    /// The names used by the used functions.
+    /// Since names are not shadowed, these include the names of the used constants.
+    pub(crate) used_names: IndexSet<Symbol>,
+}
+
+impl<'a> Pruner<'a> {
+    /// Initializes a new `Pruner`.
+    pub fn new(call_graph: &'a CallGraph, struct_graph: &'a StructGraph) -> Self {
+        Self {
+            call_graph,
+            struct_graph,
+            used_functions: Default::default(),
+            used_structs: Default::default(),
+            used_names: Default::default(),
+        }
+    }
+
+    /// Marks the structs and records in `type_` as used.
+    pub(crate) fn use_type(&mut self, type_: &Type) {
+        match type_ {
+            Type::Array(array_type) => self.use_type(array_type.element_type()),
+            Type::Identifier(identifier) => {
+                self.used_structs.insert(identifier.name);
+            }
+            Type::Mapping(mapping_type) => {
+                self.use_type(&mapping_type.key);
+                self.use_type(&mapping_type.value);
+            }
+            Type::Tuple(tuple_type) => tuple_type.elements().iter().for_each(|element| self.use_type(element)),
+            _ => {}
+        }
+    }
+}
@@ -69,8 +69,10 @@ impl FunctionConsumer for StaticSingleAssigner<'_> {
 
         // There is no need to reconstruct `function.inputs`.
         // However, for each input, we must add each symbol to the rename table.
+        // Its type is also recorded, since the phi functions copy it, along with its type.
         for input_variable in function.input.iter() {
             let identifier = input_variable.identifier();
+            self.type_table.insert(identifier.id, input_variable.type_());
             self.rename_table.update(identifier.name, identifier.name, identifier.id);
         }
 
@@ -88,6 +90,7 @@ impl FunctionConsumer for StaticSingleAssigner<'_> {
             // However, for each input, we must add each symbol to the rename table.
             for input_variable in finalize.input.iter() {
                 let identifier = input_variable.identifier();
+                self.type_table.insert(identifier.id, input_variable.type_());
                 self.rename_table.update(identifier.name, identifier.name, identifier.id);
             }
 
@@ -120,6 +123,7 @@ impl FunctionConsumer for StaticSingleAssigner<'_> {
             output_type: function.output_type,
             block,
             finalize,
+            doc: function.doc,
             span: function.span,
             id: function.id,
         }
@@ -151,6 +155,7 @@ impl ProgramConsumer for StaticSingleAssigner<'_> {
                 .into_iter()
                 .map(|(name, (import, span))| (name, (self.consume_program(import), span)))
                 .collect(),
+            stubs: input.stubs,
             program_scopes: input
                 .program_scopes
                 .into_iter()
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{RenameTable, StaticSingleAssigner};
+use crate::{Refresher, RenameTable, StaticSingleAssigner};
 
 use leo_ast::{
     AccessExpression,
@@ -144,7 +144,7 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
             },
             Statement::Conditional(conditional) => Block {
                 span: conditional.span,
-                id: conditional.id,
+                id: self.node_builder.next_id(),
                 statements: self.consume_conditional(conditional),
             },
             _ => unreachable!("Type checking guarantees that the otherwise-block of a conditional statement is a block or another conditional statement."),
@@ -167,6 +167,9 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         let else_write_set: IndexSet<&Symbol> = IndexSet::from_iter(else_table.local_names());
         let write_set = if_write_set.union(&else_write_set);
 
+        // The phi functions copy the condition and the variables, so each copy is given fresh node IDs.
+        let mut refresher = Refresher::new(self.node_builder, self.type_table);
+
         // For each variable in the write set, instantiate and add a phi function to the list of produced statements.
         for symbol in write_set {
             // Note that phi functions only need to be instantiated if the variable exists before the `ConditionalStatement`.
@@ -178,7 +181,9 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
                     let id = *table
                         .lookup_id(&name)
                         .unwrap_or_else(|| panic!("Symbol {name} should exist in the rename table."));
-                    Box::new(Expression::Identifier(Identifier { name, span: Default::default(), id }))
+                    let mut identifier = Identifier { name, span: Default::default(), id };
+                    refresher.refresh(&mut identifier);
+                    Box::new(Expression::Identifier(identifier))
                 };
 
                 // Create a new name for the variable written to in the `ConditionalStatement`.
@@ -199,7 +204,7 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
 
                 // Construct a ternary expression for the phi function.
                 let (value, stmts) = self.consume_ternary(TernaryExpression {
-                    condition: Box::new(condition.clone()),
+                    condition: Box::new(refresher.copy_expression(&condition)),
                     if_true,
                     if_false,
                     span: Default::default(),
@@ -216,11 +221,14 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
                     }
                 };
 
+                // Create an identifier for the new name, with a node ID of its own.
+                let mut identifier = Identifier { name: new_name, span: Default::default(), id };
+                refresher.refresh(&mut identifier);
+
                 // Update the `RenameTable` with the new name of the variable.
-                self.rename_table.update(*(*symbol), new_name, id);
+                self.rename_table.update(*(*symbol), new_name, identifier.id);
 
                 // Create a new `AssignStatement` for the phi function.
-                let identifier = Identifier { name: new_name, span: Default::default(), id };
                 let assignment = self.simple_assign_statement(identifier, value);
 
                 // Store the generated phi function.
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Assigner, RenameTable, SymbolTable, TypeTable};
+use crate::{Assigner, Refresher, RenameTable, SymbolTable, TypeTable};
 
 use leo_ast::{Expression, Identifier, Node, NodeBuilder, Statement};
 
@@ -81,7 +81,9 @@ impl<'a> StaticSingleAssigner<'a> {
 
         // Construct the statement.
         let statement = self.simple_assign_statement(place, expr);
+        // The returned identifier is a use of the variable, so it is given an ID of its own.
+        let identifier = Refresher::new(self.node_builder, self.type_table).copy(&place);
 
-        (place, statement)
+        (identifier, statement)
     }
 }
@@ -43,10 +43,29 @@ impl<'a> ExpressionVisitor<'a> for SymbolTableCreator<'a> {
 impl<'a> StatementVisitor<'a> for SymbolTableCreator<'a> {}
 
 impl<'a> ProgramVisitor<'a> for SymbolTableCreator<'a> {
+    fn visit_program(&mut self, input: &'a Program) {
+        input.imports.values().for_each(|import| self.visit_import(&import.0));
+        input.program_scopes.values().for_each(|scope| self.visit_program_scope(scope));
+        // Stubs are entered last, since their functions have no bodies and are skipped by later passes.
+        // This keeps the scope indices of the other functions aligned with the order in which they are visited.
+        input.stubs.values().for_each(|stub| self.visit_stub(stub));
+    }
+
     fn visit_import(&mut self, input: &'a Program) {
         self.visit_program(input)
     }
 
+    fn visit_stub(&mut self, input: &'a Stub) {
+        input.structs.iter().for_each(|(_, struct_)| self.visit_struct(struct_));
+        input.mappings.iter().for_each(|(_, mapping)| self.visit_mapping(mapping));
+        // Stub functions are entered with an empty body, since only their signatures are known.
+        for (_, function) in input.functions.iter() {
+            if let Err(err) = self.symbol_table.insert_fn(function.name(), &Function::from(function.clone())) {
+                self.handler.emit_err(err);
+            }
+        }
+    }
+
     fn visit_struct(&mut self, input: &'a Struct) {
         if let Err(err) = self.symbol_table.insert_struct(input.name(), input) {
             self.handler.emit_err(err);
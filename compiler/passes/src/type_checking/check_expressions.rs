@@ -137,11 +137,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                                 if let Some(expected) = expected {
                                     // Emit error for mismatched types.
                                     if !actual.eq_flat(expected) {
-                                        self.emit_err(TypeCheckerError::type_should_be(
-                                            &actual,
-                                            expected,
-                                            access.span(),
-                                        ))
+                                        self.emit_err(self.type_mismatch(&actual, expected, access.span()))
                                     }
                                 }
 
@@ -220,11 +216,13 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                                         }
                                         // Case where `access.name` is not a member of the struct.
                                         None => {
-                                            self.emit_err(TypeCheckerError::invalid_struct_variable(
+                                            let error = TypeCheckerError::invalid_struct_variable(
                                                 access.name,
                                                 &struct_,
                                                 access.name.span(),
-                                            ));
+                                            );
+                                            let members = struct_.members.iter().map(|member| member.name());
+                                            self.emit_err(self.suggest(error, access.name.name, members));
                                         }
                                     }
                                 } else {
@@ -606,7 +604,12 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
 
                     // Check function argument types.
                     func.input.iter().zip(input.arguments.iter()).for_each(|(expected, argument)| {
-                        self.visit_expression(argument, &Some(expected.type_()));
+                        self.visit_expression_with_origin(
+                            argument,
+                            &Some(expected.type_()),
+                            format!("expected `{}` because of this parameter", expected.type_()),
+                            expected.span(),
+                        );
                     });
 
                     // Add the call to the call graph.
@@ -618,7 +621,8 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
 
                     Some(ret)
                 } else {
-                    self.emit_err(TypeCheckerError::unknown_sym("function", ident.name, ident.span()));
+                    let error = TypeCheckerError::unknown_sym("function", ident.name, ident.span());
+                    self.emit_err(self.suggest(error, ident.name, self.symbol_table.borrow().visible_functions()));
                     None
                 }
             }
@@ -654,14 +658,19 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             }
 
             // Check struct member types.
-            struct_.members.iter().for_each(|Member { identifier, type_, .. }| {
+            struct_.members.iter().for_each(|Member { identifier, type_, span, .. }| {
                 // Lookup struct variable name.
                 if let Some(actual) = input.members.iter().find(|member| member.identifier.name == identifier.name) {
                     match &actual.expression {
                         // If `expression` is None, then the member uses the identifier shorthand, e.g. `Foo { a }`
                         None => self.visit_identifier(&actual.identifier, &Some(type_.clone())),
                         // Otherwise, visit the associated expression.
-                        Some(expr) => self.visit_expression(expr, &Some(type_.clone())),
+                        Some(expr) => self.visit_expression_with_origin(
+                            expr,
+                            &Some(type_.clone()),
+                            format!("expected `{type_}` because of this member"),
+                            *span,
+                        ),
                     };
                 } else {
                     self.emit_err(TypeCheckerError::missing_struct_member(
@@ -674,7 +683,8 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
 
             Some(ret)
         } else {
-            self.emit_err(TypeCheckerError::unknown_sym("struct", input.name.name, input.name.span()));
+            let error = TypeCheckerError::unknown_sym("struct", input.name.name, input.name.span());
+            self.emit_err(self.suggest(error, input.name.name, self.symbol_table.borrow().visible_structs()));
             None
         }
     }
@@ -688,14 +698,15 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         if let Some(var) = self.symbol_table.borrow().lookup_variable(input.name) {
             Some(self.assert_and_return_type(var.type_.clone(), expected, input.span()))
         } else {
-            self.emit_err(TypeCheckerError::unknown_sym("variable", input.name, input.span()));
+            let error = TypeCheckerError::unknown_sym("variable", input.name, input.span());
+            self.emit_err(self.suggest(error, input.name, self.symbol_table.borrow().visible_variables()));
             None
         }
     }
 
     fn visit_literal(&mut self, input: &'a Literal, expected: &Self::AdditionalInput) -> Self::Output {
         fn parse_integer_literal<I: FromStr>(handler: &Handler, raw_string: &str, span: Span, type_string: &str) {
-            let string = raw_string.replace('_', "");
+            let string = Literal::to_decimal(raw_string).unwrap_or_else(|| raw_string.to_string());
             if string.parse::<I>().is_err() {
                 handler.emit_err(TypeCheckerError::invalid_int_value(string, type_string, span));
             }
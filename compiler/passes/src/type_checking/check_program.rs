@@ -22,7 +22,7 @@ use leo_span::sym;
 
 use snarkvm::console::network::{Network, Testnet3};
 
-use std::collections::HashSet;
+use std::collections::HashMap;
 
 // TODO: Cleanup logic for tuples.
 
@@ -34,8 +34,12 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                 input.imports.values().for_each(|(_, span)| {
                     self.emit_err(TypeCheckerError::imported_program_cannot_import_program(*span))
                 });
+                input.stubs.values().for_each(|stub| {
+                    self.emit_err(TypeCheckerError::imported_program_cannot_import_program(stub.span))
+                });
             }
             // Otherwise, typecheck the imported programs.
+            // Note that stubs are not typechecked, since snarkVM has already validated their bytecode.
             false => {
                 // Set `self.is_imported`.
                 let previous_is_imported = core::mem::replace(&mut self.is_imported, true);
@@ -105,18 +109,22 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
     fn visit_struct(&mut self, input: &'a Struct) {
         // Check for conflicting struct/record member names.
-        let mut used = HashSet::new();
+        let mut used = HashMap::new();
         // TODO: Better span to target duplicate member.
-        if !input.members.iter().all(|Member { identifier, type_, span, .. }| {
+        if let Some((duplicate, first)) = input.members.iter().find_map(|Member { identifier, type_, span, .. }| {
             // Check that the member types are defined.
             self.assert_type_is_valid(type_, *span);
-            used.insert(identifier.name)
+            used.insert(identifier.name, identifier.span).map(|first| (identifier, first))
         }) {
-            self.emit_err(if input.is_record {
+            let err = if input.is_record {
                 TypeCheckerError::duplicate_record_variable(input.name(), input.span())
             } else {
                 TypeCheckerError::duplicate_struct_member(input.name(), input.span())
-            });
+            };
+            self.emit_err(
+                err.with_label(format!("`{duplicate}` first declared here"), first)
+                    .with_label("declared again here", duplicate.span),
+            );
         }
 
         // For records, enforce presence of the `owner: Address` member.
@@ -304,6 +312,9 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             }
         });
 
+        // Store the span of the function's outputs, which explains the type expected of its return statements.
+        self.output_span = function.output.iter().map(|output| output.span()).reduce(|a, b| a + b).unwrap_or_default();
+
         self.visit_block(&function.block);
 
         // If the function has a return type, then check that it has a return.
@@ -413,6 +424,10 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                 self.emit_err(TypeCheckerError::finalize_block_must_not_be_empty(finalize.span));
             }
 
+            // Store the span of the finalize block's outputs.
+            self.output_span =
+                finalize.output.iter().map(|output| output.span()).reduce(|a, b| a + b).unwrap_or_default();
+
             // Type check the finalize block.
             self.visit_block(&finalize.block);
 
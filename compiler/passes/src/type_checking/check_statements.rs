@@ -68,7 +68,7 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             }
         };
 
-        let var_type = if let Some(var) = self.symbol_table.borrow_mut().lookup_variable(var_name.name) {
+        let var = if let Some(var) = self.symbol_table.borrow().lookup_variable(var_name.name) {
             match &var.declaration {
                 VariableType::Const => self.emit_err(TypeCheckerError::cannot_assign_to_const_var(var_name, var.span)),
                 VariableType::Input(Mode::Constant) => {
@@ -77,15 +77,17 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
                 _ => {}
             }
 
-            Some(var.type_.clone())
+            Some((var.type_.clone(), var.span))
         } else {
-            self.emit_err(TypeCheckerError::unknown_sym("variable", var_name.name, var_name.span));
+            let error = TypeCheckerError::unknown_sym("variable", var_name.name, var_name.span);
+            self.emit_err(self.suggest(error, var_name.name, self.symbol_table.borrow().visible_variables()));
 
             None
         };
 
-        if var_type.is_some() {
-            self.visit_expression(&input.value, &var_type);
+        if let Some((var_type, declaration)) = var {
+            let message = format!("expected `{var_type}` because `{var_name}` is declared here");
+            self.visit_expression_with_origin(&input.value, &Some(var_type), message, declaration);
         }
     }
 
@@ -189,7 +191,12 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         }
 
         // Check the expression on the right-hand side.
-        self.visit_expression(&input.value, &Some(input.type_.clone()));
+        self.visit_expression_with_origin(
+            &input.value,
+            &Some(input.type_.clone()),
+            format!("expected `{}` because of the type of `{}`", input.type_, input.place),
+            input.place.span(),
+        );
 
         // Add constants to symbol table so that any references to them in later statements will pass TC
         if let Err(err) = self.symbol_table.borrow_mut().insert_variable(input.place.name, VariableSymbol {
@@ -226,7 +233,12 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         }
 
         // Check the expression on the right-hand side.
-        self.visit_expression(&input.value, &Some(input.type_.clone()));
+        self.visit_expression_with_origin(
+            &input.value,
+            &Some(input.type_.clone()),
+            format!("expected `{}` because of the type of `{}`", input.type_, input.place),
+            input.place.span(),
+        );
 
         // TODO: Dedup with unrolling pass.
         // Helper to insert the variables into the symbol table.
@@ -394,7 +406,15 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         // Set the `is_return` flag. This is necessary to allow unit expressions in the return statement.
         self.is_return = true;
         // Type check the associated expression.
-        self.visit_expression(&input.expression, return_type);
+        match return_type {
+            Some(type_) => self.visit_expression_with_origin(
+                &input.expression,
+                return_type,
+                format!("expected `{type_}` because of the return type"),
+                self.output_span,
+            ),
+            None => self.visit_expression(&input.expression, return_type),
+        };
         // Unset the `is_return` flag.
         self.is_return = false;
 
@@ -425,7 +445,12 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
 
                     // Check function argument types.
                     finalize.input.iter().zip(arguments.iter()).for_each(|(expected, argument)| {
-                        self.visit_expression(argument, &Some(expected.type_()));
+                        self.visit_expression_with_origin(
+                            argument,
+                            &Some(expected.type_()),
+                            format!("expected `{}` because of this parameter", expected.type_()),
+                            expected.span(),
+                        );
                     });
                 }
             }
@@ -14,16 +14,37 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CallGraph, StructGraph, SymbolTable, TypeTable};
-
-use leo_ast::{CoreConstant, CoreFunction, Identifier, IntegerType, MappingType, Node, Type, Variant};
+use crate::{did_you_mean, CallGraph, StructGraph, SymbolTable, TypeTable};
+
+use leo_ast::{
+    CoreConstant,
+    CoreFunction,
+    Expression,
+    ExpressionVisitor,
+    Identifier,
+    IntegerType,
+    MappingType,
+    Node,
+    Type,
+    Variant,
+};
 use leo_errors::{emitter::Handler, TypeCheckerError};
 use leo_span::{Span, Symbol};
 
 use snarkvm::console::network::{Network, Testnet3};
 
 use itertools::Itertools;
-use std::cell::RefCell;
+use std::{cell::RefCell, fmt::Display};
+
+/// The origin of the type expected of an expression, e.g., the declaration of the variable it is assigned to.
+pub(crate) struct ExpectedTypeOrigin {
+    /// The span of the expression.
+    expression: Span,
+    /// The label explaining why the type is expected.
+    message: String,
+    /// The span of the origin.
+    span: Span,
+}
 
 pub struct TypeChecker<'a> {
     /// The symbol table for the program.
@@ -51,6 +72,10 @@ pub struct TypeChecker<'a> {
     pub(crate) is_imported: bool,
     /// Whether or not we are currently traversing a return statement.
     pub(crate) is_return: bool,
+    /// The span of the outputs of the function or finalize block that we are currently traversing.
+    pub(crate) output_span: Span,
+    /// The origin of the type expected of the expression that we are currently traversing, if it is known.
+    pub(crate) expected_type_origin: Option<ExpectedTypeOrigin>,
 }
 
 const ADDRESS_TYPE: Type = Type::Address;
@@ -118,6 +143,8 @@ impl<'a> TypeChecker<'a> {
             is_finalize: false,
             is_imported: false,
             is_return: false,
+            output_span: Default::default(),
+            expected_type_origin: None,
         }
     }
 
@@ -150,6 +177,47 @@ impl<'a> TypeChecker<'a> {
         self.handler.emit_err(err);
     }
 
+    /// Type checks `expression` against the expected type.
+    /// If the types do not match, the error is labelled with `message` at `origin`, which explains why the type is expected.
+    pub(crate) fn visit_expression_with_origin(
+        &mut self,
+        expression: &'a Expression,
+        expected: &Option<Type>,
+        message: String,
+        origin: Span,
+    ) -> Option<Type> {
+        let origin = ExpectedTypeOrigin { expression: expression.span(), message, span: origin };
+        let previous = self.expected_type_origin.replace(origin);
+        let type_ = self.visit_expression(expression, expected);
+        self.expected_type_origin = previous;
+        type_
+    }
+
+    /// Returns the error for an expression at `span` whose type does not match the expected type.
+    /// The origin of the expected type is labelled, if it is known.
+    pub(crate) fn type_mismatch(&self, actual: impl Display, expected: impl Display, span: Span) -> TypeCheckerError {
+        let err = TypeCheckerError::type_should_be(actual, expected, span);
+        match &self.expected_type_origin {
+            Some(origin) if origin.expression == span && !origin.span.is_dummy() => {
+                err.with_label(&origin.message, origin.span)
+            }
+            _ => err,
+        }
+    }
+
+    /// Adds a suggestion to the help message of `err`, if one of `candidates` is close to the unknown `name`.
+    pub(crate) fn suggest(
+        &self,
+        err: TypeCheckerError,
+        name: Symbol,
+        candidates: impl IntoIterator<Item = Symbol>,
+    ) -> TypeCheckerError {
+        match did_you_mean(name, candidates) {
+            Some(help) => err.with_help(help),
+            None => err,
+        }
+    }
+
     /// Emits an error to the handler if the given type is invalid.
     fn check_type(&self, is_valid: impl Fn(&Type) -> bool, error_string: String, type_: &Option<Type>, span: Span) {
         if let Some(type_) = type_ {
@@ -177,7 +245,7 @@ impl<'a> TypeChecker<'a> {
     pub(crate) fn assert_and_return_type(&self, actual: Type, expected: &Option<Type>, span: Span) -> Type {
         if let Some(expected) = expected {
             if !actual.eq_flat(expected) {
-                self.emit_err(TypeCheckerError::type_should_be(actual.clone(), expected, span));
+                self.emit_err(self.type_mismatch(actual.clone(), expected, span));
             }
         }
         actual
@@ -368,7 +436,13 @@ impl<'a> TypeChecker<'a> {
             match CoreFunction::from_symbols(ident.name, function.name) {
                 None => {
                     // Not a core library struct.
-                    self.emit_err(TypeCheckerError::invalid_core_function(ident.name, function.name, ident.span()));
+                    let error = TypeCheckerError::invalid_core_function(ident.name, function.name, ident.span());
+                    // Suggest a function of the module if the module exists, and a module otherwise.
+                    let error = match CoreFunction::MODULES.contains(&ident.name) {
+                        true => self.suggest(error, function.name, CoreFunction::functions_of(ident.name)),
+                        false => self.suggest(error, ident.name, CoreFunction::MODULES.iter().copied()),
+                    };
+                    self.emit_err(error);
                 }
                 Some(core_instruction) => return Some(core_instruction),
             }
@@ -1032,7 +1106,7 @@ impl<'a> TypeChecker<'a> {
     pub(crate) fn check_expected_struct(&mut self, struct_: Identifier, expected: &Option<Type>, span: Span) -> Type {
         if let Some(expected) = expected {
             if !Type::Identifier(struct_).eq_flat(expected) {
-                self.emit_err(TypeCheckerError::type_should_be(struct_.name, expected, span));
+                self.emit_err(self.type_mismatch(struct_.name, expected, span));
             }
         }
         Type::Identifier(struct_)
@@ -1071,7 +1145,8 @@ impl<'a> TypeChecker<'a> {
             // Check that the named composite type has been defined.
             Type::Identifier(identifier) if self.symbol_table.borrow().lookup_struct(identifier.name).is_none() => {
                 is_valid = false;
-                self.emit_err(TypeCheckerError::undefined_type(identifier.name, span));
+                let error = TypeCheckerError::undefined_type(identifier.name, span);
+                self.emit_err(self.suggest(error, identifier.name, self.symbol_table.borrow().visible_structs()));
             }
             // Check that the constituent types of the tuple are valid.
             Type::Tuple(tuple_type) => {
@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The file systems that the compiler reads sources from and writes its outputs to.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    io,
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+/// The file access of the compiler.
+/// Embedders such as editors and test harnesses implement it to compile sources that are not on disk.
+pub trait FileSystem: Send + Sync {
+    /// Returns the contents of the file at `path`.
+    fn read_to_string(&self, path: &Path) -> io::Result<String>;
+
+    /// Returns `true` if there is a file at `path`.
+    fn exists(&self, path: &Path) -> bool;
+
+    /// Writes `contents` to the file at `path`, replacing the file if it exists.
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()>;
+
+    /// Returns the directory that imports are resolved against.
+    fn current_dir(&self) -> io::Result<PathBuf>;
+}
+
+/// The file system of the operating system.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RealFileSystem;
+
+impl FileSystem for RealFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        fs::read_to_string(path)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()> {
+        fs::write(path, contents)
+    }
+
+    fn current_dir(&self) -> io::Result<PathBuf> {
+        std::env::current_dir()
+    }
+}
+
+/// A file system held in memory, e.g., `main.leo` and `imports/token.leo`.
+/// Its current directory is the empty path, so the paths of its files are relative.
+#[derive(Debug, Default)]
+pub struct MemoryFileSystem {
+    files: RwLock<BTreeMap<PathBuf, String>>,
+}
+
+impl MemoryFileSystem {
+    /// Returns an empty file system.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the file `path` with the given `contents`, replacing the file if it exists.
+    pub fn insert(&self, path: impl Into<PathBuf>, contents: impl Into<String>) {
+        self.files.write().unwrap().insert(path.into(), contents.into());
+    }
+
+    /// Returns the contents of the file at `path`, if it exists.
+    pub fn get(&self, path: impl AsRef<Path>) -> Option<String> {
+        self.files.read().unwrap().get(path.as_ref()).cloned()
+    }
+
+    /// Returns all the files, e.g., to collect the outputs of a compilation.
+    pub fn into_files(self) -> BTreeMap<PathBuf, String> {
+        self.files.into_inner().unwrap()
+    }
+}
+
+impl<P: Into<PathBuf>, S: Into<String>> FromIterator<(P, S)> for MemoryFileSystem {
+    fn from_iter<I: IntoIterator<Item = (P, S)>>(files: I) -> Self {
+        let files = files.into_iter().map(|(path, contents)| (path.into(), contents.into())).collect();
+        Self { files: RwLock::new(files) }
+    }
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.get(path).ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("{} not found", path.display())))
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.files.read().unwrap().contains_key(path)
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()> {
+        self.insert(path, contents);
+        Ok(())
+    }
+
+    fn current_dir(&self) -> io::Result<PathBuf> {
+        Ok(PathBuf::new())
+    }
+}
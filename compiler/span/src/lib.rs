@@ -26,3 +26,6 @@ pub use span::Span;
 pub mod span_json;
 
 pub mod source_map;
+
+pub mod file_system;
+pub use file_system::{FileSystem, MemoryFileSystem, RealFileSystem};
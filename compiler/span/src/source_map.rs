@@ -16,12 +16,11 @@
 
 use crate::span::{BytePos, CharPos, Pos, Span};
 use std::{
-    cell::RefCell,
     fmt,
     fs,
     io,
     path::{Path, PathBuf},
-    rc::Rc,
+    sync::{Arc, RwLock},
 };
 
 /// The source map containing all recorded sources,
@@ -30,11 +29,11 @@ use std::{
 #[derive(Default)]
 pub struct SourceMap {
     /// The actual source map data.
-    inner: RefCell<SourceMapInner>,
+    inner: RwLock<SourceMapInner>,
 }
 
 /// Actual data of the source map.
-/// We use this setup for purposes of interior mutability.
+/// We use this setup for purposes of interior mutability, shared by the threads of a session.
 #[derive(Default)]
 struct SourceMapInner {
     /// The address space below this value is currently used by the files in the source map.
@@ -44,37 +43,31 @@ struct SourceMapInner {
     ///
     /// The list is append-only with mappings from the start byte position
     /// for fast lookup from a `Span` to its `SourceFile`.
-    source_files: Vec<Rc<SourceFile>>,
+    source_files: Vec<Arc<SourceFile>>,
 }
 
 impl SourceMap {
     /// Loads the given `path` and returns a `SourceFile` for it.
-    pub fn load_file(&self, path: &Path) -> io::Result<Rc<SourceFile>> {
+    pub fn load_file(&self, path: &Path) -> io::Result<Arc<SourceFile>> {
         Ok(self.new_source(&fs::read_to_string(path)?, FileName::Real(path.to_owned())))
     }
 
     /// Registers `source` under the given file `name`, returning a `SourceFile` back.
-    pub fn new_source(&self, source: &str, name: FileName) -> Rc<SourceFile> {
+    pub fn new_source(&self, source: &str, name: FileName) -> Arc<SourceFile> {
         let len = u32::try_from(source.len()).unwrap();
-        let mut inner = self.inner.borrow_mut();
+        let mut inner = self.inner.write().unwrap();
         let start_pos = inner.try_allocate_address_space(len).unwrap();
-        let source_file = Rc::new(SourceFile::new(name, source.to_owned(), start_pos));
+        let source_file = Arc::new(SourceFile::new(name, source.to_owned(), start_pos));
         inner.source_files.push(source_file.clone());
         source_file
     }
 
-    /// Find the index for the source file containing `pos`.
-    fn find_source_file_index(&self, pos: BytePos) -> Option<usize> {
-        self.inner
-            .borrow()
-            .source_files
-            .binary_search_by_key(&pos, |file| file.start_pos)
-            .map_or_else(|p| p.checked_sub(1), Some)
-    }
-
     /// Find the source file containing `pos`.
-    fn find_source_file(&self, pos: BytePos) -> Option<Rc<SourceFile>> {
-        Some(self.inner.borrow().source_files[self.find_source_file_index(pos)?].clone())
+    fn find_source_file(&self, pos: BytePos) -> Option<Arc<SourceFile>> {
+        let source_files = &self.inner.read().unwrap().source_files;
+        let index =
+            source_files.binary_search_by_key(&pos, |file| file.start_pos).map_or_else(|p| p.checked_sub(1), Some)?;
+        Some(source_files[index].clone())
     }
 
     /// Finds line column info about a given `pos`.
@@ -270,7 +263,7 @@ impl SourceFile {
 
 /// Detailed information on a `Span`.
 pub struct SpanLocation {
-    pub source_file: Rc<SourceFile>,
+    pub source_file: Arc<SourceFile>,
     pub line_start: usize,
     pub line_stop: usize,
     pub col_start: usize,
@@ -283,7 +276,7 @@ impl SpanLocation {
         let dummy = "<dummy>".to_owned();
         let span = Span::dummy();
         Self {
-            source_file: Rc::new(SourceFile {
+            source_file: Arc::new(SourceFile {
                 name: FileName::Custom(dummy.clone()),
                 src: dummy,
                 start_pos: span.lo,
@@ -302,7 +295,7 @@ impl SpanLocation {
 /// File / Line / Column information on a `BytePos`.
 pub struct LineCol {
     /// Information on the original source.
-    pub source_file: Rc<SourceFile>,
+    pub source_file: Arc<SourceFile>,
     /// The 1-based line number.
     pub line: usize,
     /// The (0-based) column offset into the line.
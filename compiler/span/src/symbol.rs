@@ -28,7 +28,7 @@ use core::{
 use fxhash::FxBuildHasher;
 use indexmap::IndexSet;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::cell::RefCell;
+use std::sync::{Arc, RwLock};
 
 /// A helper for `symbols` defined below.
 /// The macro's job is to bind conveniently  usable `const` items to the symbol names provided.
@@ -330,6 +330,8 @@ impl fmt::Display for Symbol {
 }
 
 /// All the globals for a compiler sessions.
+/// They are thread-safe, so the threads of a session can share them with [`set_session_globals_then`],
+/// e.g., to compile programs in parallel and share their ASTs.
 pub struct SessionGlobals {
     /// The interner for `Symbol`s used in the compiler.
     symbol_interner: Interner,
@@ -362,15 +364,21 @@ pub fn with_session_globals<R>(f: impl FnOnce(&SessionGlobals) -> R) -> R {
     SESSION_GLOBALS.with(f)
 }
 
+/// Runs the closure `f` in the session `session_globals`, e.g., on a worker thread of the session.
+#[inline]
+pub fn set_session_globals_then<R>(session_globals: &SessionGlobals, f: impl FnOnce() -> R) -> R {
+    SESSION_GLOBALS.set(session_globals, f)
+}
+
 /// An interned string,
 /// either prefilled "at compile time" (`Static`),
 /// or created at runtime (`Owned`).
-#[derive(Eq)]
+#[derive(Clone, Eq)]
 enum InternedStr {
     /// String is stored "at compile time", i.e. prefilled.
     Static(&'static str),
     /// String is constructed and stored during runtime.
-    Owned(Box<str>),
+    Owned(Arc<str>),
 }
 
 impl Borrow<str> for InternedStr {
@@ -412,8 +420,9 @@ struct InnerInterner {
 }
 
 /// A symbol-to-string interner.
+/// Interning takes a write lock only for strings that are not interned yet.
 struct Interner {
-    inner: RefCell<InnerInterner>,
+    inner: RwLock<InnerInterner>,
 }
 
 impl Interner {
@@ -428,24 +437,58 @@ impl Interner {
             // arena: <_>::default(),
             set: init.iter().copied().map(InternedStr::Static).collect(),
         };
-        Self { inner: RefCell::new(inner) }
+        Self { inner: RwLock::new(inner) }
     }
 
     /// Interns `string`, returning a `Symbol` corresponding to it.
     fn intern(&self, string: &str) -> Symbol {
-        let InnerInterner { set } = &mut *self.inner.borrow_mut();
-
-        if let Some(sym) = set.get_index_of(string) {
+        if let Some(sym) = self.inner.read().unwrap().set.get_index_of(string) {
             // Already interned, return that symbol.
             return Symbol::new(sym as u32);
         }
 
-        Symbol::new(set.insert_full(InternedStr::Owned(string.into())).0 as u32)
+        // Another thread may have interned `string` since the read lock was released.
+        let InnerInterner { set } = &mut *self.inner.write().unwrap();
+        match set.get_index_of(string) {
+            Some(sym) => Symbol::new(sym as u32),
+            None => Symbol::new(set.insert_full(InternedStr::Owned(string.into())).0 as u32),
+        }
     }
 
     /// Returns the corresponding string for the given symbol.
     fn get<R>(&self, symbol: Symbol, with: impl FnOnce(&str) -> R) -> R {
-        let set = &self.inner.borrow().set;
-        with(set.get_index(symbol.as_u32() as usize).unwrap())
+        // The lock is released before calling `with`, which may intern other strings.
+        let string = self.inner.read().unwrap().set.get_index(symbol.as_u32() as usize).unwrap().clone();
+        with(&string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intern_on_threads_of_a_session() {
+        create_session_if_not_set_then(|session_globals| {
+            let main = Symbol::intern("main");
+            let symbols = std::thread::scope(|scope| {
+                let threads = (0..4).map(|i| {
+                    scope.spawn(move || {
+                        set_session_globals_then(session_globals, || {
+                            (Symbol::intern("main"), Symbol::intern(&format!("thread_{i}")).to_string())
+                        })
+                    })
+                });
+                threads.collect::<Vec<_>>().into_iter().map(|thread| thread.join().unwrap()).collect::<Vec<_>>()
+            });
+
+            // The threads share the interner, so a string has the same symbol on every thread.
+            for (i, (symbol, name)) in symbols.into_iter().enumerate() {
+                assert_eq!(symbol, main);
+                assert_eq!(name, format!("thread_{i}"));
+            }
+            assert_eq!(Symbol::intern("thread_3").to_string(), "thread_3");
+            assert_eq!(sym::aleo.to_string(), "aleo");
+        });
     }
 }
@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+// The directory containing one `<CODE>.md` explanation per error and warning code.
+const EXPLANATIONS_DIR: &str = "explanations";
+
+// The directory containing the `create_messages!` invocations.
+const ERRORS_DIR: &str = "src/errors";
+
+// The identifier that prefixes the code of every Leo error, see `LeoMessageCode::code_identifier`.
+const CODE_IDENTIFIER: i32 = 37;
+
+// The headings that separate the description from the examples in an explanation.
+const FAILING_EXAMPLE_HEADING: &str = "## Failing example";
+const FIXED_EXAMPLE_HEADING: &str = "## Fixed example";
+
+// Collects the codes and names of the messages defined in `path`, in the same order as `create_messages!`.
+fn collect_codes(path: &Path, codes: &mut BTreeMap<String, String>) {
+    let source = fs::read_to_string(path).unwrap();
+    let Some((_, body)) = source.split_once("create_messages!(") else {
+        return;
+    };
+
+    let mut lines = body.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with("///"));
+    let type_ = lines.next().unwrap().trim_end_matches(',').to_string();
+    let field = |line: Option<&str>, name: &str| {
+        line.and_then(|line| line.strip_prefix(name)).unwrap().trim_end_matches(',').trim().to_string()
+    };
+    let code_mask: i32 = field(lines.next(), "code_mask:").trim_end_matches("i32").parse().unwrap();
+    let code_prefix = field(lines.next(), "code_prefix:").trim_matches('"').to_string();
+    let kind = if type_.contains("Error") { 'E' } else { 'W' };
+
+    let mut index = 0;
+    while let Some(line) = lines.next() {
+        if line == "@formatted" || line == "@backtraced" {
+            let name = lines.next().unwrap().trim_end_matches('{').trim().to_string();
+            let code = format!("{kind}{code_prefix}{CODE_IDENTIFIER:0>3}{:0>4}", code_mask + index);
+            assert!(codes.insert(code.clone(), name).is_none(), "The code {code} is defined twice.");
+            index += 1;
+        }
+    }
+}
+
+// Returns the contents of the single code block in `section`.
+fn code_block(section: &str, path: &Path) -> String {
+    let mut parts = section.split("```");
+    let (Some(_), Some(block), Some(_)) = (parts.next(), parts.next(), parts.next()) else {
+        panic!("Each example in \"{}\" must be a fenced code block.", path.display());
+    };
+    // Skip the language of the code block.
+    block.split_once('\n').map_or("", |(_, code)| code).to_string()
+}
+
+// Writes the explanation of `code` in `contents` as an `Explanation` to `generated`.
+fn write_explanation(generated: &mut String, code: &str, name: &str, contents: &str, path: &Path) {
+    let (Some((description, examples)), true) =
+        (contents.split_once(FAILING_EXAMPLE_HEADING), contents.contains(FIXED_EXAMPLE_HEADING))
+    else {
+        panic!(
+            "\"{}\" must have a description, followed by \"{FAILING_EXAMPLE_HEADING}\" and \"{FIXED_EXAMPLE_HEADING}\".",
+            path.display()
+        );
+    };
+    let (failing, fixed) = examples.split_once(FIXED_EXAMPLE_HEADING).unwrap();
+
+    writeln!(
+        generated,
+        "    Explanation {{ code: {code:?}, name: {name:?}, description: {:?}, failing_example: {:?}, \
+         fixed_example: {:?} }},",
+        description.trim(),
+        code_block(failing, path),
+        code_block(fixed, path),
+    )
+    .unwrap();
+}
+
+// The build script; it checks that every error and warning code has an explanation and embeds the explanations.
+fn main() {
+    let mut codes = BTreeMap::new();
+    let mut error_files = fs::read_dir(ERRORS_DIR)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .flat_map(|dir| fs::read_dir(dir).unwrap())
+        .map(|file| file.unwrap().path())
+        .collect::<Vec<_>>();
+    error_files.sort();
+    for path in error_files.iter() {
+        collect_codes(path, &mut codes);
+    }
+
+    // Every code must be explained, and every explanation must be of a code.
+    // This is checked here rather than by `errcov`, which is disabled and only reports which codes the tests cover:
+    // the explanations are embedded at build time anyway, so a missing one is caught before `leo explain` is built.
+    let mut missing = Vec::new();
+    let mut generated = String::from("&[\n");
+    for (code, name) in codes.iter() {
+        let path = Path::new(EXPLANATIONS_DIR).join(format!("{code}.md"));
+        match fs::read_to_string(&path) {
+            Ok(contents) => write_explanation(&mut generated, code, name, &contents, &path),
+            Err(_) => missing.push(format!("{code} ({name})")),
+        }
+    }
+    generated.push(']');
+    assert!(
+        missing.is_empty(),
+        "The following codes have no explanation in \"{EXPLANATIONS_DIR}/\": {}",
+        missing.join(", ")
+    );
+    for entry in fs::read_dir(EXPLANATIONS_DIR).unwrap() {
+        let path = entry.unwrap().path();
+        let code = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+        assert!(codes.contains_key(code), "\"{}\" explains a code that is not defined.", path.display());
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("explanations.rs"), generated).unwrap();
+
+    // Re-run upon any changes to the errors or their explanations.
+    println!("cargo:rerun-if-changed={ERRORS_DIR}");
+    println!("cargo:rerun-if-changed={EXPLANATIONS_DIR}");
+}
@@ -36,6 +36,8 @@ pub struct Backtraced {
     pub message: String,
     /// The error help message if it exists.
     pub help: Option<String>,
+    /// The notes shown after the help message.
+    pub notes: Vec<String>,
     /// The error exit code.
     pub code: i32,
     /// The error leading digits identifier.
@@ -47,7 +49,7 @@ pub struct Backtraced {
     #[derivative(PartialEq = "ignore")]
     #[derivative(Hash = "ignore")]
     /// The backtrace representing where the error occured in Leo.
-    pub backtrace: Backtrace,
+    pub backtrace: Box<Backtrace>,
 }
 
 impl Backtraced {
@@ -64,7 +66,28 @@ impl Backtraced {
     where
         S: ToString,
     {
-        Self { message: message.to_string(), help, code, code_identifier, type_, error, backtrace }
+        Self {
+            message: message.to_string(),
+            help,
+            notes: Vec::new(),
+            code,
+            code_identifier,
+            type_,
+            error,
+            backtrace: Box::new(backtrace),
+        }
+    }
+
+    /// Sets the help message, replacing the default one.
+    pub fn with_help(mut self, help: impl ToString) -> Self {
+        self.help = Some(help.to_string());
+        self
+    }
+
+    /// Adds a note, shown after the help message.
+    pub fn with_note(mut self, note: impl ToString) -> Self {
+        self.notes.push(note.to_string());
+        self
     }
 
     /// Gets the backtraced error exit code.
@@ -119,12 +142,14 @@ impl fmt::Display for Backtraced {
             write!(f, "{message}")?;
         };
 
+        if self.help.is_some() || !self.notes.is_empty() {
+            write!(f, "\n{INDENT     } |")?;
+        }
         if let Some(help) = &self.help {
-            write!(
-                f,
-                "\n{INDENT     } |\n\
-            {INDENT     } = {help}",
-            )?;
+            write!(f, "\n{INDENT     } = {help}")?;
+        }
+        for note in self.notes.iter() {
+            write!(f, "\n{INDENT     } = note: {note}")?;
         }
 
         let leo_backtrace = std::env::var("LEO_BACKTRACE").unwrap_or_default().trim().to_owned();
@@ -23,21 +23,50 @@ use color_backtrace::{BacktracePrinter, Verbosity};
 use colored::Colorize;
 use std::fmt;
 
+/// A secondary span of a formatted message, with a label explaining how it relates to the message.
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub struct Label {
+    /// The label, e.g. `first defined here`.
+    pub message: String,
+    /// The span the label refers to, possibly in another file.
+    pub span: Span,
+}
+
+/// A machine-applicable fix for a formatted message: replacing the source code at `span` with `replacement`.
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub struct Fix {
+    /// The span of the source code to replace.
+    pub span: Span,
+    /// The source code to replace it with.
+    pub replacement: String,
+}
+
 /// Formatted compiler error type
-///     undefined value `x`
-///     --> file.leo: 2:8
+///     variable `x` shadowed by
+///     --> file.leo: 3:8
+///      |
+///    3 | let x = 2u8;
+///      |     ^
+///      |
+///     ::: file.leo: 2:8
 ///      |
-///    2 | let a = x;
-///      |         ^
+///    2 | let x = 1u8;
+///      |     - first defined here
 ///      |
-///      = help: Initialize a variable `x` first.
+///      = help: Rename one of the variables.
+///      = note: Leo does not allow shadowing.
 /// Makes use of the same fields as a BacktracedError.
 #[derive(Clone, Debug, Default, Hash, PartialEq)]
 pub struct Formatted {
     /// The formatted error span information.
     pub span: Span,
+    /// The secondary spans of the message, shown after the primary span.
+    pub labels: Vec<Label>,
+    /// The fixes that `leo fix` applies to resolve the message.
+    pub fixes: Vec<Fix>,
     /// The backtrace to track where the Leo error originated.
-    pub backtrace: Backtraced,
+    /// Boxed, together with the backtrace within it, to keep `Result<T, LeoError>` small.
+    pub backtrace: Box<Backtraced>,
 }
 
 impl Formatted {
@@ -58,7 +87,9 @@ impl Formatted {
     {
         Self {
             span,
-            backtrace: Backtraced::new_from_backtrace(
+            labels: Vec::new(),
+            fixes: Vec::new(),
+            backtrace: Box::new(Backtraced::new_from_backtrace(
                 message.to_string(),
                 help,
                 code,
@@ -66,10 +97,34 @@ impl Formatted {
                 type_,
                 error,
                 backtrace,
-            ),
+            )),
         }
     }
 
+    /// Adds a secondary span, with a label explaining it.
+    pub fn with_label(mut self, message: impl ToString, span: Span) -> Self {
+        self.labels.push(Label { message: message.to_string(), span });
+        self
+    }
+
+    /// Adds a fix that replaces the source code at `span` with `replacement`.
+    pub fn with_fix(mut self, span: Span, replacement: impl ToString) -> Self {
+        self.fixes.push(Fix { span, replacement: replacement.to_string() });
+        self
+    }
+
+    /// Sets the help message, replacing the default one.
+    pub fn with_help(mut self, help: impl ToString) -> Self {
+        self.backtrace.help = Some(help.to_string());
+        self
+    }
+
+    /// Adds a note, shown after the help message.
+    pub fn with_note(mut self, note: impl ToString) -> Self {
+        self.backtrace.notes.push(note.to_string());
+        self
+    }
+
     /// Calls the backtraces error exit code.
     pub fn exit_code(&self) -> i32 {
         self.backtrace.exit_code()
@@ -86,36 +141,68 @@ impl Formatted {
     }
 }
 
-impl fmt::Display for Formatted {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let underline = |mut start: usize, mut end: usize| -> String {
-            if start > end {
-                std::mem::swap(&mut start, &mut end)
-            }
+/// Writes the lines of source code covered by `span`, underlined with `marker`.
+/// The underline is followed by `label`, if there is one.
+fn write_snippet(f: &mut fmt::Formatter, span: Span, marker: char, label: Option<&str>) -> fmt::Result {
+    let underline = |mut start: usize, mut end: usize| -> String {
+        if start > end {
+            std::mem::swap(&mut start, &mut end)
+        }
 
-            let mut underline = String::new();
+        let mut underline = String::new();
 
-            for _ in 0..start {
-                underline.push(' ');
-                end -= 1;
-            }
+        for _ in 0..start {
+            underline.push(' ');
+            end -= 1;
+        }
 
-            for _ in 0..end {
-                underline.push('^');
-            }
+        for _ in 0..end {
+            underline.push(marker);
+        }
 
-            underline
-        };
+        underline
+    };
 
-        let (loc, contents) = with_session_globals(|s| {
-            (
-                s.source_map.span_to_location(self.span).unwrap_or_else(SpanLocation::dummy),
-                s.source_map.line_contents_of_span(self.span).unwrap_or_else(|| "<contents unavailable>".to_owned()),
-            )
-        });
+    let (loc, contents) = with_session_globals(|s| {
+        (
+            s.source_map.span_to_location(span).unwrap_or_else(SpanLocation::dummy),
+            s.source_map.line_contents_of_span(span).unwrap_or_else(|| "<contents unavailable>".to_owned()),
+        )
+    });
+
+    let underlined = underline(loc.col_start, loc.col_stop);
+
+    // The primary span is introduced with an arrow, secondary spans with colons.
+    write!(
+        f,
+        "\n{indent     }{arrow} {path}:{line_start}:{start}\n\
+        {indent     } |\n",
+        indent = INDENT,
+        arrow = if label.is_none() { "-->" } else { ":::" },
+        path = &loc.source_file.name,
+        line_start = loc.line_start,
+        start = loc.col_start,
+    )?;
+
+    for (line_no, line) in contents.lines().enumerate() {
+        writeln!(
+            f,
+            "{line_no:width$} | {text}",
+            width = INDENT.len(),
+            line_no = loc.line_start + line_no,
+            text = line,
+        )?;
+    }
 
-        let underlined = underline(loc.col_start, loc.col_stop);
+    write!(f, "{INDENT     } |{underlined}")?;
+    if let Some(label) = label {
+        write!(f, " {label}")?;
+    }
+    Ok(())
+}
 
+impl fmt::Display for Formatted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let (kind, code) =
             if self.backtrace.error { ("Error", self.error_code()) } else { ("Warning", self.warning_code()) };
 
@@ -132,34 +219,21 @@ impl fmt::Display for Formatted {
             write!(f, "{message}")?;
         };
 
-        write!(
-            f,
-            "\n{indent     }--> {path}:{line_start}:{start}\n\
-            {indent     } |\n",
-            indent = INDENT,
-            path = &loc.source_file.name,
-            line_start = loc.line_start,
-            start = loc.col_start,
-        )?;
+        write_snippet(f, self.span, '^', None)?;
 
-        for (line_no, line) in contents.lines().enumerate() {
-            writeln!(
-                f,
-                "{line_no:width$} | {text}",
-                width = INDENT.len(),
-                line_no = loc.line_start + line_no,
-                text = line,
-            )?;
+        for label in self.labels.iter() {
+            write!(f, "\n{INDENT     } |")?;
+            write_snippet(f, label.span, '-', Some(&label.message))?;
         }
 
-        write!(f, "{INDENT     } |{underlined}",)?;
-
+        if self.backtrace.help.is_some() || !self.backtrace.notes.is_empty() {
+            write!(f, "\n{INDENT     } |")?;
+        }
         if let Some(help) = &self.backtrace.help {
-            write!(
-                f,
-                "\n{INDENT     } |\n\
-            {INDENT     } = {help}",
-            )?;
+            write!(f, "\n{INDENT     } = {help}")?;
+        }
+        for note in self.backtrace.notes.iter() {
+            write!(f, "\n{INDENT     } = note: {note}")?;
         }
 
         let leo_backtrace = std::env::var("LEO_BACKTRACE").unwrap_or_default().trim().to_owned();
@@ -86,6 +86,52 @@ macro_rules! create_messages {
         }
 
 
+        /// Converts the message into a structured diagnostic.
+        impl From<&$type_> for $crate::emitter::Diagnostic {
+            fn from(message: &$type_) -> Self {
+                match message {
+                    $type_::Formatted(formatted) => formatted.into(),
+                    $type_::Backtraced(backtraced) => backtraced.into(),
+                }
+            }
+        }
+
+        impl $type_ {
+            /// Adds a secondary span, with a label explaining it.
+            /// Labels are only shown for messages with a span.
+            pub fn with_label(self, message: impl ToString, span: leo_span::Span) -> Self {
+                match self {
+                    Self::Formatted(formatted) => Self::Formatted(formatted.with_label(message, span)),
+                    Self::Backtraced(backtraced) => Self::Backtraced(backtraced),
+                }
+            }
+
+            /// Adds a fix that replaces the source code at `span` with `replacement`.
+            /// Fixes are only applied to messages with a span.
+            pub fn with_fix(self, span: leo_span::Span, replacement: impl ToString) -> Self {
+                match self {
+                    Self::Formatted(formatted) => Self::Formatted(formatted.with_fix(span, replacement)),
+                    Self::Backtraced(backtraced) => Self::Backtraced(backtraced),
+                }
+            }
+
+            /// Sets the help message, replacing the default one.
+            pub fn with_help(self, help: impl ToString) -> Self {
+                match self {
+                    Self::Formatted(formatted) => Self::Formatted(formatted.with_help(help)),
+                    Self::Backtraced(backtraced) => Self::Backtraced(backtraced.with_help(help)),
+                }
+            }
+
+            /// Adds a note, shown after the help message.
+            pub fn with_note(self, note: impl ToString) -> Self {
+                match self {
+                    Self::Formatted(formatted) => Self::Formatted(formatted.with_note(note)),
+                    Self::Backtraced(backtraced) => Self::Backtraced(backtraced.with_note(note)),
+                }
+            }
+        }
+
         // Steps over the list of functions with an initial code of 0.
         impl $type_ {
             create_messages!(@step 0i32, $(($(#[$docs])* $formatted_or_backtraced_list, $names($($arg_names: $arg_types,)*), $messages, $helps),)*);
@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Backtraced, Formatted};
+
+use leo_span::{source_map::FileName, symbol::with_session_globals, Span};
+
+use serde::Serialize;
+
+/// The severity of a diagnostic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The message is an error.
+    Error,
+    /// The message is a warning.
+    Warning,
+}
+
+/// The range of source code a diagnostic refers to.
+/// Lines and columns start at 1, and the column range is exclusive of `col_stop`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Location {
+    /// The path or name of the source file.
+    pub file: String,
+    /// The first line of the range.
+    pub line_start: usize,
+    /// The first column of the range.
+    pub col_start: usize,
+    /// The last line of the range.
+    pub line_stop: usize,
+    /// The column after the end of the range.
+    pub col_stop: usize,
+}
+
+impl Location {
+    /// Returns the location of `span` in the source map of the current session. Dummy spans have no location.
+    pub fn of_span(span: Span) -> Option<Self> {
+        let location = match span.is_dummy() {
+            true => None,
+            false => with_session_globals(|s| s.source_map.span_to_location(span)),
+        };
+        location.map(|loc| Self {
+            file: match &loc.source_file.name {
+                FileName::Real(path) => path.display().to_string(),
+                FileName::Custom(name) => name.clone(),
+            },
+            line_start: loc.line_start,
+            col_start: loc.col_start,
+            line_stop: loc.line_stop,
+            col_stop: loc.col_stop,
+        })
+    }
+}
+
+/// A secondary location of a diagnostic, with a label explaining how it relates to the diagnostic.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RelatedLocation {
+    /// The label, e.g. `first declared here`.
+    pub message: String,
+    /// The source code the label refers to.
+    pub location: Location,
+}
+
+/// A machine-applicable fix of a diagnostic, replacing the source code at a location.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Replacement {
+    /// The source code to replace.
+    pub location: Location,
+    /// The source code to replace it with.
+    pub replacement: String,
+    /// The span of the source code to replace, for applying the fix in place.
+    #[serde(skip)]
+    pub span: Span,
+}
+
+/// A structured view of an error or warning, for emitters that write machine-readable output.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    /// The error or warning code, e.g. `EPAR0370005`, if the message has one.
+    pub code: Option<String>,
+    /// Whether this is an error or a warning.
+    pub severity: Severity,
+    /// The message, without the code or the source snippet.
+    pub message: String,
+    /// The help message, if it exists.
+    pub help: Option<String>,
+    /// The source code the message refers to, if it is known.
+    pub location: Option<Location>,
+    /// The secondary locations of the message, whose source code is known.
+    pub related: Vec<RelatedLocation>,
+    /// The notes shown after the help message.
+    pub notes: Vec<String>,
+    /// The fixes that resolve the message, whose source code is known.
+    pub fixes: Vec<Replacement>,
+}
+
+impl Diagnostic {
+    /// Returns the diagnostic as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("a diagnostic always serializes to JSON")
+    }
+}
+
+impl From<&Backtraced> for Diagnostic {
+    fn from(message: &Backtraced) -> Self {
+        let (severity, code) = match message.error {
+            true => (Severity::Error, message.error_code()),
+            false => (Severity::Warning, message.warning_code()),
+        };
+        Self {
+            code: Some(code),
+            severity,
+            message: message.message.clone(),
+            help: message.help.clone(),
+            location: None,
+            related: Vec::new(),
+            notes: message.notes.clone(),
+            fixes: Vec::new(),
+        }
+    }
+}
+
+impl From<&Formatted> for Diagnostic {
+    fn from(message: &Formatted) -> Self {
+        let related = message
+            .labels
+            .iter()
+            .filter_map(|label| {
+                Location::of_span(label.span)
+                    .map(|location| RelatedLocation { message: label.message.clone(), location })
+            })
+            .collect();
+        let fixes = message
+            .fixes
+            .iter()
+            .filter_map(|fix| {
+                Location::of_span(fix.span).map(|location| Replacement {
+                    location,
+                    replacement: fix.replacement.clone(),
+                    span: fix.span,
+                })
+            })
+            .collect();
+        Self { location: Location::of_span(message.span), related, fixes, ..Self::from(&*message.backtrace) }
+    }
+}
@@ -20,6 +20,14 @@ use super::LeoError;
 use core::{default::Default, fmt};
 use std::{cell::RefCell, rc::Rc};
 
+/// Contains the structured form of errors and warnings.
+mod diagnostic;
+pub use diagnostic::*;
+
+/// Contains the emitter that writes a SARIF log.
+mod sarif;
+pub use sarif::*;
+
 /// Types that are sinks for compiler errors.
 pub trait Emitter {
     /// Emit the error `err`.
@@ -53,6 +61,30 @@ impl Emitter for StderrEmitter {
     }
 }
 
+/// An `Emitter` that writes each error and warning to the standard error as a line of JSON.
+#[derive(Default)]
+pub struct JsonEmitter {
+    /// Exit code of the last emitted error.
+    last_error_code: Option<i32>,
+}
+
+impl Emitter for JsonEmitter {
+    fn emit_err(&mut self, err: LeoError) {
+        self.last_error_code = Some(err.exit_code());
+        if let Some(diagnostic) = err.diagnostic() {
+            eprintln!("{}", diagnostic.to_json());
+        }
+    }
+
+    fn last_emitted_err_code(&self) -> Option<i32> {
+        self.last_error_code
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        eprintln!("{}", warning.diagnostic().to_json());
+    }
+}
+
 /// A buffer of `T`s.
 #[derive(Debug)]
 pub struct Buffer<T>(Vec<T>);
@@ -141,6 +173,8 @@ struct HandlerInner {
     err_count: usize,
     /// Number of warnings emitted thus far.
     warn_count: usize,
+    /// The warnings emitted thus far.
+    warnings: Vec<LeoWarning>,
     /// The sink through which errors will be emitted.
     emitter: Box<dyn Emitter>,
 }
@@ -160,6 +194,7 @@ impl HandlerInner {
     /// Emit the error `err`.
     fn emit_warning(&mut self, warning: LeoWarning) {
         self.warn_count = self.warn_count.saturating_add(1);
+        self.warnings.push(warning.clone());
         self.emitter.emit_warning(warning);
     }
 }
@@ -180,7 +215,7 @@ impl Default for Handler {
 impl Handler {
     /// Construct a `Handler` using the given `emitter`.
     pub fn new(emitter: Box<dyn Emitter>) -> Self {
-        let inner = RefCell::new(HandlerInner { err_count: 0, warn_count: 0, emitter });
+        let inner = RefCell::new(HandlerInner { err_count: 0, warn_count: 0, warnings: Vec::new(), emitter });
         Self { inner }
     }
 
@@ -226,6 +261,11 @@ impl Handler {
         self.inner.borrow().warn_count
     }
 
+    /// The warnings emitted thus far.
+    pub fn warnings(&self) -> Vec<LeoWarning> {
+        self.inner.borrow().warnings.clone()
+    }
+
     /// Did we have any errors thus far?
     pub fn had_errors(&self) -> bool {
         self.err_count() > 0
@@ -259,7 +299,7 @@ impl Handler {
 mod tests {
     use super::*;
     use crate::ParserError;
-    use leo_span::{symbol::create_session_if_not_set_then, Span};
+    use leo_span::{source_map::FileName, span::BytePos, symbol::create_session_if_not_set_then, Span};
 
     #[test]
     fn fresh_no_errors() {
@@ -296,4 +336,98 @@ mod tests {
             Handler::with(|_| Ok(())).unwrap();
         })
     }
+
+    #[test]
+    fn structured_diagnostics() {
+        create_session_if_not_set_then(|s| {
+            let file = s.source_map.new_source("let a = x;\nlet b = y;", FileName::Custom("test.leo".into()));
+            let span = Span::new(file.start_pos + BytePos(19), file.start_pos + BytePos(20));
+
+            let diagnostic = LeoError::from(ParserError::unexpected_eof(span)).diagnostic().unwrap();
+            assert_eq!(diagnostic.severity, Severity::Error);
+            assert_eq!(diagnostic.code.as_deref(), Some("EPAR0370003"));
+            assert_eq!(
+                diagnostic.location,
+                Some(Location {
+                    file: "test.leo".to_string(),
+                    line_start: 2,
+                    col_start: 9,
+                    line_stop: 2,
+                    col_stop: 10,
+                })
+            );
+            assert!(diagnostic.to_json().starts_with(r#"{"code":"EPAR0370003","severity":"error","#));
+            assert!(LeoError::LastErrorCode(1).diagnostic().is_none());
+
+            let sarif = SarifEmitter::default();
+            let handler = Handler::new(Box::new(sarif.clone()));
+            handler.emit_err(ParserError::unexpected_eof(span));
+            handler.emit_err(ParserError::unexpected_eof(Span::default()));
+            let log = sarif.to_sarif();
+            assert_eq!(log["version"], "2.1.0");
+            assert_eq!(log["runs"][0]["tool"]["driver"]["rules"].as_array().unwrap().len(), 1);
+            let results = log["runs"][0]["results"].as_array().unwrap();
+            assert_eq!(results.len(), 2);
+            assert_eq!(results[0]["ruleId"], "EPAR0370003");
+            assert_eq!(results[0]["locations"][0]["physicalLocation"]["region"]["startColumn"], 9);
+            assert!(results[1]["locations"].as_array().unwrap().is_empty());
+            assert_eq!(handler.last_err().unwrap_err().exit_code(), 370003);
+        })
+    }
+
+    #[test]
+    fn labels_notes_and_fixes() {
+        create_session_if_not_set_then(|s| {
+            let file = s.source_map.new_source("let a = x;\nlet b = y;", FileName::Custom("test.leo".into()));
+            let first = Span::new(file.start_pos + BytePos(4), file.start_pos + BytePos(5));
+            let second = Span::new(file.start_pos + BytePos(15), file.start_pos + BytePos(16));
+            let error = ParserError::unexpected_eof(second)
+                .with_label("declared here", first)
+                .with_note("a note")
+                .with_fix(first, "b");
+
+            let rendered = error.to_string();
+            assert!(rendered.contains("::: test.leo:1:5"), "{rendered}");
+            assert!(rendered.contains("- declared here"), "{rendered}");
+            assert!(rendered.ends_with("= note: a note"), "{rendered}");
+
+            let diagnostic = LeoError::from(error.clone()).diagnostic().unwrap();
+            assert_eq!(diagnostic.related.len(), 1);
+            assert_eq!(diagnostic.related[0].message, "declared here");
+            assert_eq!(diagnostic.related[0].location.line_start, 1);
+            assert_eq!(diagnostic.notes, vec!["a note".to_string()]);
+            assert_eq!(diagnostic.fixes.len(), 1);
+            assert_eq!((diagnostic.fixes[0].span, diagnostic.fixes[0].replacement.as_str()), (first, "b"));
+            assert!(diagnostic.to_json().contains(r#""fixes":[{"location":{"file":"test.leo","line_start":1"#));
+
+            let sarif = SarifEmitter::default();
+            let handler = Handler::new(Box::new(sarif.clone()));
+            handler.emit_err(error);
+            let result = &sarif.to_sarif()["runs"][0]["results"][0];
+            assert_eq!(result["relatedLocations"][0]["message"]["text"], "declared here");
+            assert_eq!(result["relatedLocations"][0]["physicalLocation"]["region"]["startColumn"], 5);
+            let change = &result["fixes"][0]["artifactChanges"][0];
+            assert_eq!(change["artifactLocation"]["uri"], "test.leo");
+            assert_eq!(change["replacements"][0]["deletedRegion"]["startColumn"], 5);
+            assert_eq!(change["replacements"][0]["insertedContent"]["text"], "b");
+        })
+    }
+
+    #[test]
+    fn snarkvm_errors() {
+        let error = || LeoError::Anyhow(anyhow::anyhow!("invalid program"));
+        assert_eq!(error().exit_code(), 1);
+        assert_eq!(error().error_code(), "");
+        assert_eq!(error().diagnostic().unwrap().code, None);
+
+        let sarif = SarifEmitter::default();
+        let handler = Handler::new(Box::new(sarif.clone()));
+        handler.emit_err(error());
+        assert_eq!(sarif.last_emitted_err_code(), Some(1));
+        assert_eq!(sarif.to_sarif()["runs"][0]["results"][0]["message"]["text"], "invalid program");
+
+        let mut json = JsonEmitter::default();
+        json.emit_err(error());
+        assert_eq!(json.last_emitted_err_code(), Some(1));
+    }
 }
@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{Diagnostic, Emitter, Location, Severity};
+use crate::{LeoError, LeoWarning};
+
+use serde_json::{json, Value};
+use std::{
+    cell::{Cell, RefCell},
+    path::Path,
+    rc::Rc,
+};
+
+/// The version of the SARIF format that is written.
+const SARIF_VERSION: &str = "2.1.0";
+
+/// The schema of the SARIF format that is written.
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// An `Emitter` that collects errors and warnings into a SARIF log, for code scanning tools.
+/// Clones of the emitter share the collected diagnostics, so a single log can cover several handlers.
+#[derive(Clone, Default)]
+pub struct SarifEmitter {
+    /// The diagnostics emitted thus far.
+    diagnostics: Rc<RefCell<Vec<Diagnostic>>>,
+    /// Exit code of the last emitted error.
+    last_error_code: Rc<Cell<Option<i32>>>,
+}
+
+impl SarifEmitter {
+    /// Returns the SARIF log of the diagnostics emitted thus far.
+    pub fn to_sarif(&self) -> Value {
+        let diagnostics = self.diagnostics.borrow();
+        // Each code is a rule, listed once in the order in which it was first reported.
+        let mut rules = Vec::new();
+        for code in diagnostics.iter().filter_map(|diagnostic| diagnostic.code.as_deref()) {
+            if !rules.contains(&code) {
+                rules.push(code);
+            }
+        }
+        json!({
+            "$schema": SARIF_SCHEMA,
+            "version": SARIF_VERSION,
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "leo",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                        "rules": rules.iter().map(|id| json!({ "id": id })).collect::<Vec<_>>(),
+                    }
+                },
+                "results": diagnostics.iter().map(sarif_result).collect::<Vec<_>>(),
+            }],
+        })
+    }
+}
+
+/// Returns the SARIF physical location of a location.
+fn physical_location(location: &Location) -> Value {
+    // Absolute paths are turned into `file` URIs, while relative paths are relative to the package.
+    let uri = match Path::new(&location.file).is_absolute() {
+        true => format!("file://{}", location.file),
+        false => location.file.clone(),
+    };
+    json!({
+        "artifactLocation": { "uri": uri },
+        "region": {
+            "startLine": location.line_start,
+            "startColumn": location.col_start,
+            "endLine": location.line_stop,
+            "endColumn": location.col_stop,
+        },
+    })
+}
+
+/// Returns the SARIF result of a diagnostic.
+fn sarif_result(diagnostic: &Diagnostic) -> Value {
+    let mut text = diagnostic.message.clone();
+    for line in diagnostic.help.iter().chain(diagnostic.notes.iter()) {
+        text.push('\n');
+        text.push_str(line);
+    }
+    let locations =
+        diagnostic.location.iter().map(|location| json!({ "physicalLocation": physical_location(location) }));
+    let related = diagnostic.related.iter().enumerate().map(|(id, related)| {
+        json!({
+            "id": id,
+            "message": { "text": related.message },
+            "physicalLocation": physical_location(&related.location),
+        })
+    });
+
+    // Each fix replaces a single region, which SARIF describes as an artifact change.
+    let fixes = diagnostic.fixes.iter().map(|fix| {
+        let location = physical_location(&fix.location);
+        json!({
+            "description": { "text": format!("Replace with `{}`", fix.replacement) },
+            "artifactChanges": [{
+                "artifactLocation": location["artifactLocation"],
+                "replacements": [{
+                    "deletedRegion": location["region"],
+                    "insertedContent": { "text": fix.replacement },
+                }],
+            }],
+        })
+    });
+
+    let mut result = json!({
+        "level": match diagnostic.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        },
+        "message": { "text": text },
+        "locations": locations.collect::<Vec<_>>(),
+        "relatedLocations": related.collect::<Vec<_>>(),
+        "fixes": fixes.collect::<Vec<_>>(),
+    });
+    if let Some(code) = &diagnostic.code {
+        result["ruleId"] = json!(code);
+    }
+    result
+}
+
+impl Emitter for SarifEmitter {
+    fn emit_err(&mut self, err: LeoError) {
+        self.last_error_code.set(Some(err.exit_code()));
+        if let Some(diagnostic) = err.diagnostic() {
+            self.diagnostics.borrow_mut().push(diagnostic);
+        }
+    }
+
+    fn last_emitted_err_code(&self) -> Option<i32> {
+        self.last_error_code.get()
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        self.diagnostics.borrow_mut().push(warning.diagnostic());
+    }
+}
@@ -178,4 +178,88 @@ create_messages!(
         msg: format!("Failed to write file.\nIO Error: {error}"),
         help: None,
     }
+
+    @backtraced
+    unknown_error_code {
+        args: (code: impl Display),
+        msg: format!("`{code}` is not a Leo error or warning code."),
+        help: Some("Codes look like `EPAR0370005`: `E` or `W`, the kind of message, and seven digits.".to_string()),
+    }
+
+    @backtraced
+    failed_to_parse_private_key {
+        args: (error: impl Display),
+        msg: format!("Failed to parse the private key for account.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_parse_address {
+        args: (error: impl Display),
+        msg: format!("Failed to parse the address for account.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_parse_view_key {
+        args: (error: impl Display),
+        msg: format!("Failed to parse the view key for account.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_parse_signature {
+        args: (error: impl Display),
+        msg: format!("Failed to parse the signature for account.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_parse_message {
+        args: (error: impl Display),
+        msg: format!("Failed to parse the message for account.\nSnarkVM Error: {error}"),
+        help: Some("The message is a value such as `1field` or `{ a: 1u8 }`; pass `--raw` to use the text of the message instead.".to_string()),
+    }
+
+    @backtraced
+    failed_to_parse_record {
+        args: (error: impl Display),
+        msg: format!("Failed to parse the record ciphertext for account.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_sign_message {
+        args: (error: impl Display),
+        msg: format!("Failed to sign the message for account.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    invalid_signature {
+        args: (address: impl Display),
+        msg: format!("The signature is not a signature of the message by `{address}`."),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_decrypt_record {
+        args: (error: impl Display),
+        msg: format!("Failed to decrypt the record for account.\nSnarkVM Error: {error}"),
+        help: Some("A record can only be decrypted with the view key of its owner.".to_string()),
+    }
+
+    @backtraced
+    failed_to_read_abi {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to read the ABI of the program from `{path}`.\nError: {error}"),
+        help: Some("The ABI is written by `leo build`. Try `leo clean` and build the program again.".to_string()),
+    }
+
+    @backtraced
+    sarif_output_required {
+        args: (),
+        msg: "`--message-format sarif` requires a file to write the SARIF log to.".to_string(),
+        help: Some("Pass the file with `--sarif-output <PATH>`.".to_string()),
+    }
 );
@@ -70,4 +70,46 @@ create_messages!(
         msg: format!("The program scope name `{program_scope_name}` must match `{file_name}`."),
         help: None,
     }
+
+    @backtraced
+    unknown_pass {
+        args: (pass: impl Display, passes: impl Display),
+        msg: format!("Unknown compiler pass `{pass}`."),
+        help: Some(format!("The available passes are: {passes}.")),
+    }
+
+    @backtraced
+    pass_precondition_not_met {
+        args: (pass: impl Display, precondition: impl Display),
+        msg: format!("Cannot run `{pass}`: {precondition}."),
+        help: Some("Check the passes given to `--passes` and `--skip-pass`.".to_string()),
+    }
+
+    @backtraced
+    pass_has_no_snapshot {
+        args: (pass: impl Display, kind: impl Display),
+        msg: format!("The pass `{pass}` does not produce {kind} snapshots."),
+        help: None,
+    }
+
+    @formatted
+    ir_invariant_violated {
+        args: (pass: impl Display, invariant: impl Display, id: impl Display, node: impl Display),
+        msg: format!("Internal compiler error: `{pass}` broke the invariant that {invariant}, at node {id} `{node}`."),
+        help: Some("This is a bug in the Leo compiler. Please report it along with this program.".to_string()),
+    }
+
+    @backtraced
+    failed_to_write_abi {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to write the ABI of the program to {path:?}.\nIO Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_json {
+        args: (path: impl Debug, error: impl ErrorArg),
+        msg: format!("Failed to write the JSON output {path:?}.\nError: {error}"),
+        help: None,
+    }
 );
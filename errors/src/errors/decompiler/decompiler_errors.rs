@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+use std::fmt::{Debug, Display};
+
+create_messages!(
+    /// DecompilerError enum that represents all the errors for the `leo-decompiler` crate.
+    DecompilerError,
+    code_mask: 8000i32,
+    code_prefix: "DEC",
+
+    /// For when the bytecode cannot be parsed as an Aleo program.
+    @backtraced
+    failed_to_parse_bytecode {
+        args: (error: impl Display),
+        msg: format!("Failed to parse Aleo bytecode: {error}"),
+        help: None,
+    }
+
+    /// For when an instruction has no Leo equivalent.
+    @backtraced
+    unsupported_instruction {
+        args: (instruction: impl Display, function: impl Display),
+        msg: format!("Cannot decompile `{instruction}` in `{function}`: the instruction has no Leo equivalent."),
+        help: None,
+    }
+
+    /// For when a finalize command has no Leo equivalent.
+    @backtraced
+    unsupported_command {
+        args: (command: impl Display, function: impl Display),
+        msg: format!("Cannot decompile `{command}` in the finalize block of `{function}`: the command has no Leo equivalent."),
+        help: Some("Leo does not generate branches or `position` commands in finalize blocks.".to_string()),
+    }
+
+    /// For when a register is read before it is assigned.
+    @backtraced
+    undefined_register {
+        args: (register: impl Display, function: impl Display),
+        msg: format!("Register `{register}` in `{function}` is used before it is defined."),
+        help: None,
+    }
+
+    /// For when the bytecode calls into a program whose bytecode was not provided.
+    @backtraced
+    missing_import {
+        args: (program: impl Display),
+        msg: format!("The bytecode calls into `{program}.aleo`, but its bytecode was not provided."),
+        help: Some(format!("Provide the bytecode of `{program}.aleo` so that the types of its outputs can be recovered.")),
+    }
+
+    /// For when a type cannot be expressed in Leo.
+    @backtraced
+    unsupported_type {
+        args: (type_: impl Display),
+        msg: format!("The type `{type_}` has no Leo equivalent."),
+        help: None,
+    }
+
+    /// For when the bytecode calls a function that its program does not define.
+    @backtraced
+    unknown_function {
+        args: (function: impl Display, program: impl Display),
+        msg: format!("The bytecode calls `{function}`, but `{program}.aleo` does not define it."),
+        help: None,
+    }
+);
@@ -55,4 +55,92 @@ create_messages!(
         ),
         help: None,
     }
+
+    /// For when an input case is requested that the input file does not have.
+    @backtraced
+    unknown_input_case {
+        args: (function: impl Display, case: impl Display, cases: impl Display),
+        msg: format!("There is no input case `{case}` for `{function}`."),
+        help: Some(format!("The input cases for `{function}` are: {cases}.")),
+    }
+
+    /// For when a section does not give one value for each input of its transition.
+    @formatted
+    unexpected_number_of_inputs {
+        args: (function: impl Display, expected: impl Display, received: impl Display),
+        msg: format!("`{function}` expects {expected} inputs, but {received} are given."),
+        help: None,
+    }
+
+    /// For when an input value is a struct or record that is not declared.
+    @formatted
+    undefined_struct {
+        args: (name: impl Display),
+        msg: format!("The struct or record `{name}` is not declared in the program or its imports."),
+        help: None,
+    }
+
+    /// For when a struct or record value does not initialize a member.
+    @formatted
+    missing_struct_member {
+        args: (struct_: impl Display, member: impl Display),
+        msg: format!("The value of `{struct_}` is missing the member `{member}`."),
+        help: None,
+    }
+
+    /// For when a struct or record value initializes a member that is not declared.
+    @formatted
+    unknown_struct_member {
+        args: (struct_: impl Display, member: impl Display),
+        msg: format!("`{struct_}` has no member `{member}`."),
+        help: None,
+    }
+
+    /// For when a JSON input file is not well-formed.
+    @backtraced
+    failed_to_parse_json_input {
+        args: (error: impl Display),
+        msg: format!("Failed to parse the JSON input file: {error}"),
+        help: Some("A JSON input file maps section names, such as `main` or `main.large`, to objects of input names and values.".to_string()),
+    }
+
+    /// For when a JSON input value does not have the type of its input.
+    @backtraced
+    unexpected_json_value {
+        args: (name: impl Display, expected: impl Display, value: impl Display),
+        msg: format!("The input `{name}` must be a `{expected}`, but `{value}` is given."),
+        help: None,
+    }
+
+    /// For when a JSON input file does not give a value for an input.
+    @backtraced
+    missing_json_input {
+        args: (function: impl Display, name: impl Display),
+        msg: format!("The JSON input file does not give a value for the input `{name}` of `{function}`."),
+        help: None,
+    }
+
+    /// For when a JSON input file gives a value for an input that does not exist.
+    @backtraced
+    unknown_json_input {
+        args: (function: impl Display, name: impl Display),
+        msg: format!("The JSON input file gives a value for `{name}`, but `{function}` has no such input."),
+        help: None,
+    }
+
+    /// For when an input is given with a mode other than the mode of the transition's input.
+    @formatted
+    unexpected_mode {
+        args: (name: impl Display, expected: impl Display, received: impl Display),
+        msg: format!("The input `{name}` is `{expected}`, but it is given as `{received}`."),
+        help: Some(format!("Declare the input as `{expected} {name}`, as the transition does.")),
+    }
+
+    /// For when an output of a transition does not have the type of the transition's output.
+    @backtraced
+    unexpected_output {
+        args: (index: impl Display, expected: impl Display, value: impl Display),
+        msg: format!("The output {index} of the transition should be a `{expected}`, but snarkVM returned `{value}`."),
+        help: Some("The build may be out of date; rebuild the program with `leo build`.".to_string()),
+    }
 );
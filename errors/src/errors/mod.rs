@@ -15,7 +15,10 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 /// Contains the ASG error definitions.
-use crate::LeoMessageCode;
+use crate::{
+    emitter::{Diagnostic, Severity},
+    LeoMessageCode,
+};
 
 /// Contains the AST error definitions.
 pub mod ast;
@@ -29,6 +32,10 @@ pub use self::cli::*;
 pub mod compiler;
 pub use self::compiler::*;
 
+/// Contains the Decompiler error definitions.
+pub mod decompiler;
+pub use self::decompiler::*;
+
 /// Contains the Flattener error definitions.
 pub mod flattener;
 pub use self::flattener::*;
@@ -48,6 +55,10 @@ pub use self::package::*;
 pub mod parser;
 pub use self::parser::*;
 
+/// Contains the Pruner warning definitions.
+pub mod pruner;
+pub use self::pruner::*;
+
 /// Contains the Type Checker error definitions.
 pub mod type_checker;
 
@@ -66,6 +77,9 @@ pub enum LeoError {
     /// Represents an Compiler Error in a Leo Error.
     #[error(transparent)]
     CompilerError(#[from] CompilerError),
+    /// Represents a Decompiler Error in a Leo Error.
+    #[error(transparent)]
+    DecompilerError(#[from] DecompilerError),
     /// Represents an Input Error in a Leo Error.
     #[error(transparent)]
     InputError(#[from] InputError),
@@ -102,6 +116,7 @@ impl LeoError {
             AstError(error) => error.error_code(),
             CompilerError(error) => error.error_code(),
             CliError(error) => error.error_code(),
+            DecompilerError(error) => error.error_code(),
             InputError(error) => error.error_code(),
             ParserError(error) => error.error_code(),
             PackageError(error) => error.error_code(),
@@ -109,7 +124,8 @@ impl LeoError {
             LoopUnrollerError(error) => error.error_code(),
             FlattenError(error) => error.error_code(),
             LastErrorCode(_) => unreachable!(),
-            Anyhow(_) => unimplemented!(), // todo: implement error codes for snarkvm errors.
+            // snarkVM errors have no Leo error code.
+            Anyhow(_) => String::new(),
         }
     }
 
@@ -121,6 +137,7 @@ impl LeoError {
             AstError(error) => error.exit_code(),
             CompilerError(error) => error.exit_code(),
             CliError(error) => error.exit_code(),
+            DecompilerError(error) => error.exit_code(),
             InputError(error) => error.exit_code(),
             ParserError(error) => error.exit_code(),
             PackageError(error) => error.exit_code(),
@@ -128,18 +145,51 @@ impl LeoError {
             LoopUnrollerError(error) => error.exit_code(),
             FlattenError(error) => error.exit_code(),
             LastErrorCode(code) => *code,
-            Anyhow(_) => unimplemented!(), // todo: implement exit codes for snarkvm errors.
+            // snarkVM errors have no Leo exit code, so they exit with a generic failure.
+            Anyhow(_) => 1,
         }
     }
+
+    /// Returns the error as a structured diagnostic, unless it only carries an exit code.
+    pub fn diagnostic(&self) -> Option<Diagnostic> {
+        use LeoError::*;
+
+        Some(match self {
+            AstError(error) => error.into(),
+            CompilerError(error) => error.into(),
+            CliError(error) => error.into(),
+            DecompilerError(error) => error.into(),
+            InputError(error) => error.into(),
+            ParserError(error) => error.into(),
+            PackageError(error) => error.into(),
+            TypeCheckerError(error) => error.into(),
+            LoopUnrollerError(error) => error.into(),
+            FlattenError(error) => error.into(),
+            LastErrorCode(_) => return None,
+            Anyhow(error) => Diagnostic {
+                code: None,
+                severity: Severity::Error,
+                message: error.to_string(),
+                help: None,
+                location: None,
+                related: Vec::new(),
+                notes: Vec::new(),
+                fixes: Vec::new(),
+            },
+        })
+    }
 }
 
 /// The LeoWarning type that contains all sub error types.
 /// This allows a unified error type throughout the Leo crates.
-#[derive(Debug, Error)]
+#[derive(Clone, Debug, Error)]
 pub enum LeoWarning {
     /// Represents an Parser Error in a Leo Error.
     #[error(transparent)]
     ParserWarning(#[from] ParserWarning),
+    /// Represents a Pruner Warning in a Leo Warning.
+    #[error(transparent)]
+    PrunerWarning(#[from] PrunerWarning),
 }
 
 impl LeoWarning {
@@ -149,6 +199,17 @@ impl LeoWarning {
 
         match self {
             ParserWarning(warning) => warning.warning_code(),
+            PrunerWarning(warning) => warning.warning_code(),
+        }
+    }
+
+    /// Returns the warning as a structured diagnostic.
+    pub fn diagnostic(&self) -> Diagnostic {
+        use LeoWarning::*;
+
+        match self {
+            ParserWarning(warning) => warning.into(),
+            PrunerWarning(warning) => warning.into(),
         }
     }
 }
@@ -313,4 +313,20 @@ create_messages!(
         msg: format!("IO error env file from the provided file path - {error}"),
         help: None,
     }
+
+    /// For when a package targets a network that Leo does not support.
+    @backtraced
+    unknown_network {
+        args: (network: impl Display, supported: impl Display),
+        msg: format!("The network `{network}` is not supported."),
+        help: Some(format!("The supported networks are: {supported}.")),
+    }
+
+    /// For when the environment file does not set a valid private key.
+    @backtraced
+    invalid_env_private_key {
+        args: (error: impl Display),
+        msg: format!("The `.env` file does not set a valid `PRIVATE_KEY`: {error}"),
+        help: Some("Set `PRIVATE_KEY` to a private key in the `.env` file, e.g. one created with `leo account new`.".to_string()),
+    }
 );
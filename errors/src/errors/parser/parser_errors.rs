@@ -218,7 +218,7 @@ create_messages!(
     leo_imports_only {
         args: (),
         msg: "Invalid import call to non-leo file.",
-        help: Some("Only imports of Leo `.leo` files are currently supported.".to_string()),
+        help: Some("Only imports of Leo `.leo` files and Aleo `.aleo` files are currently supported.".to_string()),
     }
 
     @formatted
@@ -298,4 +298,12 @@ create_messages!(
         msg: format!("An array {kind} must have at least one element."),
         help: None,
     }
+
+    /// For when a hexadecimal, octal, or binary integer has no digits, or a digit of another base.
+    @backtraced
+    lexer_invalid_radix_integer {
+        args: (input: impl Display, kind: impl Display),
+        msg: format!("`{input}` is not a valid {kind} integer."),
+        help: Some("Hexadecimal, octal, and binary integers are written `0x1f`, `0o17`, and `0b11`, with at least one digit.".to_string()),
+    }
 );
@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// PrunerWarning enum that represents all the warnings for the pruning pass in the `leo-passes` crate.
+    PrunerWarning,
+    code_mask: 0000i32,
+    code_prefix: "PRU",
+
+    /// For when a function is not called, directly or indirectly, by any transition.
+    @formatted
+    unused_function {
+        args: (name: impl Display),
+        msg: format!("The function `{name}` is never called by a transition, so it is removed from the program."),
+        help: Some("Remove the function, or call it from a transition.".to_string()),
+    }
+
+    /// For when a struct is not used by any record, mapping, or function called by a transition.
+    @formatted
+    unused_struct {
+        args: (name: impl Display),
+        msg: format!("The struct `{name}` is never used, so it is removed from the program."),
+        help: Some("Remove the struct, or use it in a transition.".to_string()),
+    }
+
+    /// For when a constant is not used by any function called by a transition.
+    @formatted
+    unused_const {
+        args: (name: impl Display),
+        msg: format!("The constant `{name}` is never used, so it is removed from the program."),
+        help: Some("Remove the constant, or use it in a transition.".to_string()),
+    }
+);
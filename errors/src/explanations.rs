@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fmt;
+
+/// The long-form explanation of an error or warning code, for `leo explain`.
+/// The explanations are written in `explanations/<CODE>.md` and embedded by the build script,
+/// which checks that every code defined with `create_messages!` is explained.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    /// The code, e.g. `EPAR0370005`.
+    pub code: &'static str,
+    /// The name of the function that creates the message, e.g. `unexpected`.
+    pub name: &'static str,
+    /// What the message means and how to address it.
+    pub description: &'static str,
+    /// An example that produces the message.
+    pub failing_example: &'static str,
+    /// The example, changed so that it no longer produces the message.
+    pub fixed_example: &'static str,
+}
+
+/// The explanations of all error and warning codes, sorted by code.
+pub static EXPLANATIONS: &[Explanation] = include!(concat!(env!("OUT_DIR"), "/explanations.rs"));
+
+/// Returns the explanation of the given code, ignoring case.
+pub fn explain(code: &str) -> Option<&'static Explanation> {
+    let code = code.trim().to_uppercase();
+    EXPLANATIONS.binary_search_by(|explanation| explanation.code.cmp(&code)).ok().map(|index| &EXPLANATIONS[index])
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} ({})\n", self.code, self.name)?;
+        writeln!(f, "{}\n", self.description)?;
+        writeln!(f, "Failing example:\n")?;
+        write_indented(f, self.failing_example)?;
+        writeln!(f, "\nFixed example:\n")?;
+        write_indented(f, self.fixed_example)
+    }
+}
+
+/// Writes the lines of `example`, indented.
+fn write_indented(f: &mut fmt::Formatter, example: &str) -> fmt::Result {
+    for line in example.lines() {
+        match line.is_empty() {
+            true => writeln!(f)?,
+            false => writeln!(f, "    {line}")?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    /// Returns the codes of the messages of type `T`, the same way as `LeoMessageCode` computes them.
+    fn codes<T: LeoMessageCode>(num_exit_codes: i32) -> Vec<String> {
+        let kind = if T::is_error() { "E" } else { "W" };
+        (0..num_exit_codes)
+            .map(|code| format!("{kind}{}{:0>3}{:0>4}", T::message_type(), T::code_identifier(), T::code_mask() + code))
+            .collect()
+    }
+
+    #[test]
+    fn every_code_is_explained() {
+        let codes = [
+            codes::<AstError>(AstError::num_exit_codes()),
+            codes::<CliError>(CliError::num_exit_codes()),
+            codes::<CompilerError>(CompilerError::num_exit_codes()),
+            codes::<DecompilerError>(DecompilerError::num_exit_codes()),
+            codes::<FlattenError>(FlattenError::num_exit_codes()),
+            codes::<InputError>(InputError::num_exit_codes()),
+            codes::<LoopUnrollerError>(LoopUnrollerError::num_exit_codes()),
+            codes::<PackageError>(PackageError::num_exit_codes()),
+            codes::<ParserError>(ParserError::num_exit_codes()),
+            codes::<ParserWarning>(ParserWarning::num_exit_codes()),
+            codes::<PrunerWarning>(PrunerWarning::num_exit_codes()),
+            codes::<TypeCheckerError>(TypeCheckerError::num_exit_codes()),
+        ]
+        .concat();
+        assert_eq!(codes.len(), EXPLANATIONS.len());
+        for code in codes.iter() {
+            assert!(explain(code).is_some(), "{code} is not explained");
+        }
+
+        assert_eq!(explain("epar0370005").map(|explanation| explanation.name), Some("unexpected"));
+        assert!(explain("EPAR0379999").is_none());
+    }
+}
@@ -16,6 +16,7 @@
 
 use crate::cli::{commands::*, context::*, helpers::*};
 use leo_errors::Result;
+use leo_package::network::NetworkName;
 
 use clap::Parser;
 use colored::Colorize;
@@ -36,12 +37,27 @@ pub struct CLI {
 
     #[clap(long, global = true, help = "Optional path to Leo program root folder")]
     path: Option<PathBuf>,
+
+    #[clap(
+        long,
+        global = true,
+        value_enum,
+        default_value_t,
+        help = "The format of errors and warnings written to stderr, or to `--sarif-output` for SARIF"
+    )]
+    message_format: MessageFormat,
+
+    #[clap(long, global = true, help = "The file that the SARIF log is written to, with `--message-format sarif`")]
+    sarif_output: Option<PathBuf>,
+
+    #[clap(long, global = true, help = "The network to target, instead of the `network` in `program.json`")]
+    network: Option<NetworkName>,
 }
 
 ///Leo compiler and package manager
 #[derive(Parser, Debug)]
 enum Commands {
-    #[clap(about = "Create a new Aleo account")]
+    #[clap(about = "Create, import and use Aleo accounts")]
     Account {
         #[clap(subcommand)]
         command: Account,
@@ -66,6 +82,21 @@ enum Commands {
         #[clap(flatten)]
         command: Clean,
     },
+    #[clap(about = "Decompile an Aleo program into Leo")]
+    Decompile {
+        #[clap(flatten)]
+        command: Decompile,
+    },
+    #[clap(about = "Generate typed Rust or TypeScript bindings of the current package")]
+    Bindgen {
+        #[clap(flatten)]
+        command: Bindgen,
+    },
+    #[clap(about = "Generate the API documentation of the current package")]
+    Doc {
+        #[clap(flatten)]
+        command: Doc,
+    },
     #[clap(about = "Run a program with input variables")]
     Run {
         #[clap(flatten)]
@@ -76,6 +107,16 @@ enum Commands {
         #[clap(flatten)]
         command: Execute,
     },
+    #[clap(about = "Explain an error or warning code")]
+    Explain {
+        #[clap(flatten)]
+        command: Explain,
+    },
+    #[clap(about = "Apply the fixes suggested by the compiler, e.g., to migrate deprecated syntax")]
+    Fix {
+        #[clap(flatten)]
+        command: Fix,
+    },
     #[clap(about = "Update the Leo CLI")]
     Update {
         #[clap(flatten)]
@@ -105,9 +146,11 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
 
     // Get custom root folder and create context for it.
     // If not specified, default context will be created in cwd.
-    let context = handle_error(Context::new(cli.path));
+    let context = handle_error(Context::new(cli.path, cli.message_format, cli.sarif_output, cli.network));
+    // Errors, and the SARIF log, are reported in the requested format once the command has finished.
+    let reporter = context.clone();
 
-    match cli.command {
+    let result = match cli.command {
         Commands::Account { command } => command.try_execute(context),
         Commands::New { command } => command.try_execute(context),
         Commands::Build { command } => {
@@ -128,8 +171,14 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
         }
         Commands::Clean { command } => command.try_execute(context),
         Commands::Example { command } => command.try_execute(context),
+        Commands::Decompile { command } => command.try_execute(context),
+        Commands::Doc { command } => command.try_execute(context),
+        Commands::Bindgen { command } => command.try_execute(context),
         Commands::Run { command } => command.try_execute(context),
         Commands::Execute { command } => command.try_execute(context),
+        Commands::Explain { command } => command.try_execute(context),
+        Commands::Fix { command } => command.try_execute(context),
         Commands::Update { command } => command.try_execute(context),
-    }
+    };
+    reporter.finish(result)
 }
@@ -16,9 +16,11 @@
 
 use super::*;
 use leo_package::root::Env;
-use snarkvm::prelude::{Address, PrivateKey, ViewKey};
+use snarkvm::prelude::{Address, Ciphertext, Field, PrivateKey, Record, Signature, ToFields, Value, ViewKey};
 
-use rand::SeedableRng;
+use std::str::FromStr;
+
+use rand::{CryptoRng, Rng, SeedableRng};
 use rand_chacha::ChaChaRng;
 
 /// Commands to manage Aleo accounts.
@@ -36,11 +38,51 @@ pub enum Account {
     /// Derive an Aleo account from a private key.
     Import {
         /// Private key plaintext
-        private_key: PrivateKey<CurrentNetwork>,
+        private_key: String,
         /// Write the private key to the .env file.
         #[clap(short = 'w', long)]
         write: bool,
     },
+    /// Sign a message with the private key in the .env file.
+    Sign {
+        /// The message, a value such as `1field` or `{ a: 1u8 }`, which is signed as in `signature::verify`.
+        message: String,
+        /// Sign the bytes of the message text instead of a value.
+        #[clap(long)]
+        raw: bool,
+        /// Sign with this private key instead of the private key in the .env file.
+        #[clap(long)]
+        private_key: Option<String>,
+        /// Print the signature as JSON.
+        #[clap(long)]
+        json: bool,
+    },
+    /// Verify a signature of a message by an address, as `signature::verify` does.
+    Verify {
+        /// The address of the signer.
+        address: String,
+        /// The signature.
+        signature: String,
+        /// The message that was signed.
+        message: String,
+        /// Verify a signature of the bytes of the message text instead of a value.
+        #[clap(long)]
+        raw: bool,
+        /// Print the result as JSON.
+        #[clap(long)]
+        json: bool,
+    },
+    /// Decrypt a record ciphertext with a view key.
+    Decrypt {
+        /// The record ciphertext.
+        ciphertext: String,
+        /// Decrypt with this view key instead of the view key of the private key in the .env file.
+        #[clap(long)]
+        view_key: Option<String>,
+        /// Print the record as JSON.
+        #[clap(long)]
+        json: bool,
+    },
 }
 
 impl Command for Account {
@@ -58,12 +100,19 @@ impl Command for Account {
     where
         Self: Sized,
     {
+        with_network!(ctx.network()?, N => self.manage::<N>(&ctx))
+    }
+}
+
+impl Account {
+    /// Manages an account on the network `N`.
+    fn manage<N: Network>(self, ctx: &Context) -> Result<()> {
         match self {
             Account::New { seed, write } => {
                 // Sample a new Aleo account.
                 let private_key = match seed {
                     // Recover the field element deterministically.
-                    Some(seed) => PrivateKey::new(&mut ChaChaRng::seed_from_u64(seed)),
+                    Some(seed) => PrivateKey::<N>::new(&mut ChaChaRng::seed_from_u64(seed)),
                     // Sample a random field element.
                     None => PrivateKey::new(&mut ChaChaRng::from_entropy()),
                 }
@@ -74,16 +123,92 @@ impl Command for Account {
 
                 // Save key data to .env file.
                 if write {
-                    write_to_env_file(private_key, &ctx)?;
+                    write_to_env_file(private_key, ctx)?;
                 }
             }
             Account::Import { private_key, write } => {
+                let private_key =
+                    PrivateKey::<N>::from_str(&private_key).map_err(CliError::failed_to_parse_private_key)?;
+
                 // Derive the view key and address and print to stdout.
                 print_keys(private_key)?;
 
                 // Save key data to .env file.
                 if write {
-                    write_to_env_file(private_key, &ctx)?;
+                    write_to_env_file(private_key, ctx)?;
+                }
+            }
+            Account::Sign { message, raw, private_key, json } => {
+                let private_key = match private_key {
+                    Some(private_key) => {
+                        PrivateKey::<N>::from_str(&private_key).map_err(CliError::failed_to_parse_private_key)?
+                    }
+                    None => Env::<N>::read_from(&ctx.dir()?)?.private_key()?,
+                };
+                let address = Address::try_from(&ViewKey::try_from(&private_key)?)?;
+                let signature = sign_message(&private_key, &message, raw, &mut ChaChaRng::from_entropy())?;
+
+                match json {
+                    true => print_json(serde_json::json!({
+                        "address": address.to_string(),
+                        "message": message,
+                        "signature": signature.to_string(),
+                    })),
+                    false => println!(
+                        "\n {:>12}  {address}\n {:>12}  {signature}\n",
+                        "Address".cyan().bold(),
+                        "Signature".cyan().bold()
+                    ),
+                }
+            }
+            Account::Verify { address, signature, message, raw, json } => {
+                let address = Address::<N>::from_str(&address).map_err(CliError::failed_to_parse_address)?;
+                let signature = Signature::<N>::from_str(&signature).map_err(CliError::failed_to_parse_signature)?;
+
+                let valid = match raw {
+                    true => signature.verify_bytes(&address, message.as_bytes()),
+                    false => signature.verify(&address, &message_fields::<N>(&message)?),
+                };
+
+                match json {
+                    true => print_json(serde_json::json!({
+                        "address": address.to_string(),
+                        "message": message,
+                        "signature": signature.to_string(),
+                        "valid": valid,
+                    })),
+                    false if valid => println!("\n ✅ The signature is valid.\n"),
+                    false => {}
+                }
+                // An invalid signature is an error, so that scripts can check the exit code.
+                if !valid {
+                    return Err(CliError::invalid_signature(address).into());
+                }
+            }
+            Account::Decrypt { ciphertext, view_key, json } => {
+                let view_key = match view_key {
+                    Some(view_key) => ViewKey::<N>::from_str(&view_key).map_err(CliError::failed_to_parse_view_key)?,
+                    None => ViewKey::try_from(&Env::<N>::read_from(&ctx.dir()?)?.private_key()?)?,
+                };
+                let record =
+                    Record::<N, Ciphertext<N>>::from_str(&ciphertext).map_err(CliError::failed_to_parse_record)?;
+
+                if !record.is_owner(&view_key) {
+                    return Err(CliError::failed_to_decrypt_record("the view key does not own the record").into());
+                }
+                let record = record.decrypt(&view_key).map_err(CliError::failed_to_decrypt_record)?;
+                let owner = Address::try_from(&view_key)?;
+
+                match json {
+                    true => print_json(serde_json::json!({
+                        "owner": owner.to_string(),
+                        "record": record.to_string(),
+                    })),
+                    false => println!(
+                        "\n {:>12}  {owner}\n {:>12}  {record}\n",
+                        "Owner".cyan().bold(),
+                        "Record".cyan().bold()
+                    ),
                 }
             }
         }
@@ -94,9 +219,9 @@ impl Command for Account {
 // Helper functions
 
 // Print keys as a formatted string without log level.
-fn print_keys(private_key: PrivateKey<CurrentNetwork>) -> Result<()> {
+fn print_keys<N: Network>(private_key: PrivateKey<N>) -> Result<()> {
     let view_key = ViewKey::try_from(&private_key)?;
-    let address = Address::<CurrentNetwork>::try_from(&view_key)?;
+    let address = Address::<N>::try_from(&view_key)?;
 
     println!(
         "\n {:>12}  {private_key}\n {:>12}  {view_key}\n {:>12}  {address}\n",
@@ -107,11 +232,94 @@ fn print_keys(private_key: PrivateKey<CurrentNetwork>) -> Result<()> {
     Ok(())
 }
 
+// Signs a message value, or the bytes of the message text if `raw` is set.
+fn sign_message<N: Network, R: Rng + CryptoRng>(
+    private_key: &PrivateKey<N>,
+    message: &str,
+    raw: bool,
+    rng: &mut R,
+) -> Result<Signature<N>> {
+    let signature = match raw {
+        true => private_key.sign_bytes(message.as_bytes(), rng),
+        false => private_key.sign(&message_fields::<N>(message)?, rng),
+    };
+    Ok(signature.map_err(CliError::failed_to_sign_message)?)
+}
+
+// Returns the fields of a message value, which `signature::verify` signs.
+fn message_fields<N: Network>(message: &str) -> Result<Vec<Field<N>>> {
+    let value = Value::<N>::from_str(message).map_err(CliError::failed_to_parse_message)?;
+    Ok(value.to_fields().map_err(CliError::failed_to_parse_message)?)
+}
+
+// Print a JSON value to stdout without log level.
+fn print_json(value: serde_json::Value) {
+    println!("{value:#}");
+}
+
 // Write the network and private key to the .env file in project directory.
-fn write_to_env_file(private_key: PrivateKey<CurrentNetwork>, ctx: &Context) -> Result<()> {
-    let data = format!("NETWORK=testnet3\nPRIVATE_KEY={private_key}\n");
+fn write_to_env_file<N: Network>(private_key: PrivateKey<N>, ctx: &Context) -> Result<()> {
+    let data = format!("NETWORK={}\nPRIVATE_KEY={private_key}\n", NetworkName::of::<N>()?);
     let program_dir = ctx.dir()?;
-    Env::<CurrentNetwork>::from(data).write_to(&program_dir)?;
+    Env::<N>::from(data).write_to(&program_dir)?;
     tracing::info!("✅ Private Key written to {}", program_dir.join(".env").display());
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_errors::LeoError;
+    use snarkvm::console::network::Testnet3;
+
+    /// Returns the private key and address of an account with the given seed.
+    fn account(seed: u64) -> (PrivateKey<Testnet3>, String) {
+        let private_key = PrivateKey::new(&mut ChaChaRng::seed_from_u64(seed)).unwrap();
+        let address = Address::try_from(&ViewKey::try_from(&private_key).unwrap()).unwrap();
+        (private_key, address.to_string())
+    }
+
+    /// Runs `leo account verify` for the signature of `message` by `address`.
+    fn verify(address: &str, signature: &Signature<Testnet3>, message: &str, raw: bool) -> Result<()> {
+        let context = Context::new(None, MessageFormat::Human, None, None).unwrap();
+        let verify = Account::Verify {
+            address: address.to_string(),
+            signature: signature.to_string(),
+            message: message.to_string(),
+            raw,
+            json: false,
+        };
+        verify.manage::<Testnet3>(&context)
+    }
+
+    /// Returns the code of the error that an invalid signature by `address` is reported with.
+    fn invalid_signature(address: &str) -> String {
+        LeoError::from(CliError::invalid_signature(address)).error_code()
+    }
+
+    #[test]
+    fn signatures_of_values_and_raw_messages_are_verified() {
+        let (private_key, address) = account(1);
+        let rng = &mut ChaChaRng::seed_from_u64(2);
+        for (message, raw) in [("{ a: 1u8, b: 2field }", false), ("Hello, Aleo!", true)] {
+            let signature = sign_message(&private_key, message, raw, rng).unwrap();
+            verify(&address, &signature, message, raw).unwrap();
+        }
+    }
+
+    #[test]
+    fn invalid_signatures_are_errors() {
+        let (private_key, address) = account(1);
+        let (_, other) = account(3);
+        let rng = &mut ChaChaRng::seed_from_u64(2);
+        let signature = sign_message(&private_key, "1field", false, rng).unwrap();
+
+        // The signature is of neither another message, another signer, nor the bytes of the message text.
+        let error = verify(&address, &signature, "2field", false).unwrap_err();
+        assert_eq!(error.error_code(), invalid_signature(&address));
+        let error = verify(&other, &signature, "1field", false).unwrap_err();
+        assert_eq!(error.error_code(), invalid_signature(&other));
+        let error = verify(&address, &signature, "1field", true).unwrap_err();
+        assert_eq!(error.error_code(), invalid_signature(&address));
+    }
+}
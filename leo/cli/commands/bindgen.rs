@@ -0,0 +1,605 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_compiler::abi::{AbiType, ProgramAbi, Visibility};
+
+use clap::ValueEnum;
+use std::{fs, path::PathBuf};
+
+/// The directory the bindings are written to by default, relative to the package.
+const BINDINGS_DIRECTORY_NAME: &str = "bindings/";
+
+/// Generates typed bindings of the program from its ABI, to call its transitions from other languages.
+#[derive(Parser, Debug)]
+pub struct Bindgen {
+    #[clap(long, value_enum, help = "The language of the bindings.")]
+    pub(crate) lang: BindgenLanguage,
+    #[clap(long, help = "The file to write the bindings to. Defaults to `bindings/<program>.<rs|ts>` in the package.")]
+    pub(crate) output: Option<PathBuf>,
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+/// The language of the generated bindings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum BindgenLanguage {
+    Rust,
+    Typescript,
+}
+
+impl Command for Bindgen {
+    type Input = <Build as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        (Build { options: self.compiler_options.clone() }).execute(context)
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        with_network!(context.network()?, N => self.bindgen::<N>(context))
+    }
+}
+
+impl Bindgen {
+    /// Generates the bindings from the ABI that `leo build` writes next to the bytecode.
+    fn bindgen<N: Network>(self, context: Context) -> Result<()> {
+        let package_path = context.dir()?;
+        let manifest = context.open_manifest::<N>()?;
+        let program_name = manifest.program_id().name().to_string();
+
+        let abi_path = BuildDirectory::open(&package_path)?.join(format!("{program_name}.abi.json"));
+        let abi = fs::read_to_string(&abi_path).map_err(|err| CliError::failed_to_read_abi(abi_path.display(), err))?;
+        let abi = serde_json::from_str::<ProgramAbi>(&abi)
+            .map_err(|err| CliError::failed_to_read_abi(abi_path.display(), err))?;
+
+        let (bindings, extension) = match self.lang {
+            BindgenLanguage::Rust => (rust_bindings(&abi), "rs"),
+            BindgenLanguage::Typescript => (typescript_bindings(&abi), "ts"),
+        };
+        let output = self
+            .output
+            .unwrap_or_else(|| package_path.join(BINDINGS_DIRECTORY_NAME).join(format!("{program_name}.{extension}")));
+        if let Some(directory) = output.parent().filter(|directory| !directory.as_os_str().is_empty()) {
+            fs::create_dir_all(directory)
+                .map_err(|err| PackageError::failed_to_create_directory(directory.display(), err))?;
+        }
+        fs::write(&output, bindings).map_err(CliError::failed_to_write_file)?;
+
+        tracing::info!("✅ Generated the bindings of '{}' in '{}'", abi.program, output.display());
+        Ok(())
+    }
+}
+
+/// The Rust declarations that every binding uses: the encoding of values as Aleo inputs, and the primitive types
+/// that Rust does not have. They are declared in a module named after the keyword `aleo`, which no struct or
+/// record of a Leo program can be named, so that they do not collide with the structs and records of the program.
+const RUST_PRELUDE: &str = r#"
+/// The encoding of values as Aleo inputs, and the primitive types that Rust does not have.
+pub mod aleo {
+    /// A value that can be given to a transition as an input.
+    pub trait AleoInput {
+        /// Returns the value in the Aleo plaintext format, e.g., `1u64` or `{ x: 1i8, y: 2i8 }`.
+        fn to_aleo_input(&self) -> String {
+            self.to_aleo_value("")
+        }
+
+        /// Returns the value in the Aleo plaintext format, with `visibility`, e.g., `.public`, on each of its
+        /// literals, as the members of records have, e.g., `{ x: 1i8.public, y: 2i8.public }`.
+        fn to_aleo_value(&self, visibility: &str) -> String;
+    }
+
+    macro_rules! integer_inputs {
+        ($($integer:ident),*) => {
+            $(impl AleoInput for $integer {
+                fn to_aleo_value(&self, visibility: &str) -> String {
+                    format!("{self}{}{visibility}", stringify!($integer))
+                }
+            })*
+        };
+    }
+
+    integer_inputs!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+    impl AleoInput for bool {
+        fn to_aleo_value(&self, visibility: &str) -> String {
+            format!("{self}{visibility}")
+        }
+    }
+
+    /// A value given as it is, such as a record of another program.
+    impl AleoInput for String {
+        fn to_aleo_value(&self, _visibility: &str) -> String {
+            self.clone()
+        }
+    }
+
+    impl<T: AleoInput, const N: usize> AleoInput for [T; N] {
+        fn to_aleo_value(&self, visibility: &str) -> String {
+            let elements = self.iter().map(|element| element.to_aleo_value(visibility)).collect::<Vec<_>>();
+            format!("[{}]", elements.join(", "))
+        }
+    }
+
+    macro_rules! string_inputs {
+        ($($(#[$doc:meta])* $name:ident => $suffix:literal),*) => {
+            $($(#[$doc])*
+            #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+            pub struct $name(pub String);
+
+            impl AleoInput for $name {
+                fn to_aleo_value(&self, visibility: &str) -> String {
+                    format!("{}{}{visibility}", self.0, $suffix)
+                }
+            })*
+        };
+    }
+
+    string_inputs!(
+        /// An address, e.g., `aleo1...`.
+        Address => "",
+        /// A field element, as a decimal number without the `field` suffix.
+        Field => "field",
+        /// A group element, as the decimal x-coordinate without the `group` suffix.
+        Group => "group",
+        /// A scalar, as a decimal number without the `scalar` suffix.
+        Scalar => "scalar",
+        /// A signature, e.g., `sign1...`.
+        Signature => ""
+    );
+}
+
+use aleo::AleoInput as _;
+"#;
+
+/// The TypeScript types of the primitive types that TypeScript does not have, given as strings.
+/// Like the Rust prelude, they are declared in a namespace named `aleo`.
+const TYPESCRIPT_PRELUDE: &str = r#"
+/** The primitive types that TypeScript does not have, given as strings. */
+export namespace aleo {
+  /** An address, e.g., `aleo1...`. */
+  export type Address = string;
+  /** A field element, as a decimal number without the `field` suffix. */
+  export type Field = string;
+  /** A group element, as the decimal x-coordinate without the `group` suffix. */
+  export type Group = string;
+  /** A scalar, as a decimal number without the `scalar` suffix. */
+  export type Scalar = string;
+  /** A signature, e.g., `sign1...`. */
+  export type Signature = string;
+}
+"#;
+
+/// Returns the Rust bindings of a program.
+fn rust_bindings(abi: &ProgramAbi) -> String {
+    let mut bindings = format!(
+        "//! The bindings of `{}`, generated by `leo bindgen` from the ABI of the program. Do not edit.\n\n\
+         #![allow(dead_code, non_camel_case_types, non_snake_case)]\n{RUST_PRELUDE}",
+        abi.program
+    );
+
+    for (struct_, is_record) in
+        abi.structs.iter().map(|struct_| (struct_, false)).chain(abi.records.iter().map(|record| (record, true)))
+    {
+        let name = rust_identifier(&struct_.name);
+        let kind = if is_record { "record" } else { "struct" };
+        bindings.push_str(&format!(
+            "\n/// The {kind} `{}`.\n#[derive(Clone, Debug, PartialEq, Eq)]\npub struct {name} {{\n",
+            struct_.name
+        ));
+        for field in struct_.fields.iter() {
+            bindings.push_str(&format!("    pub {}: {},\n", rust_identifier(&field.name), rust_type(&field.type_)));
+        }
+        if is_record {
+            bindings.push_str(
+                "    /// The nonce of the record, which defaults to `0group`.\n    pub _nonce: Option<aleo::Group>,\n",
+            );
+        }
+        bindings.push_str("}\n");
+
+        // The members of a record are followed by its nonce, and all of them have a visibility, which is on each
+        // of their literals. The members of a struct have the visibility of the record member that the struct is in.
+        let mut members = struct_.fields.iter().map(|field| format!("{}: {{}}", field.name)).collect::<Vec<_>>();
+        let mut values = struct_
+            .fields
+            .iter()
+            .map(|field| {
+                let visibility = match is_record {
+                    true => format!("{:?}", visibility_suffix(field.visibility)),
+                    false => "visibility".to_string(),
+                };
+                format!("self.{}.to_aleo_value({visibility})", rust_identifier(&field.name))
+            })
+            .collect::<Vec<_>>();
+        if is_record {
+            members.push("_nonce: {}".to_string());
+            values.push(
+                "self._nonce.as_ref().map_or_else(|| \"0group.public\".to_string(), \
+                 |nonce| nonce.to_aleo_value(\".public\"))"
+                    .to_string(),
+            );
+        }
+        let visibility = if is_record { "_visibility" } else { "visibility" };
+        bindings.push_str(&format!(
+            "\nimpl aleo::AleoInput for {name} {{\n    \
+             fn to_aleo_value(&self, {visibility}: &str) -> String {{\n        \
+             format!(\"{{{{ {} }}}}\", {})\n    \
+             }}\n}}\n",
+            members.join(", "),
+            values.join(", ")
+        ));
+    }
+
+    for transition in abi.transitions.iter() {
+        let parameters = transition
+            .inputs
+            .iter()
+            .map(|input| format!("{}: {}", rust_identifier(&input.name), rust_type(&input.type_)))
+            .collect::<Vec<_>>();
+        let mut arguments = vec![format!("{:?}.to_string()", transition.name)];
+        arguments
+            .extend(transition.inputs.iter().map(|input| format!("{}.to_aleo_input()", rust_identifier(&input.name))));
+        bindings.push_str(&format!(
+            "\n/// Returns the arguments of `leo run` and `leo execute` that call the transition `{}`.\n\
+             pub fn {}({}) -> Vec<String> {{\n    vec![{}]\n}}\n",
+            transition.name,
+            rust_identifier(&transition.name),
+            parameters.join(", "),
+            arguments.join(", ")
+        ));
+    }
+    bindings
+}
+
+/// Returns the TypeScript bindings of a program.
+fn typescript_bindings(abi: &ProgramAbi) -> String {
+    let mut bindings = format!(
+        "// The bindings of `{}`, generated by `leo bindgen` from the ABI of the program. Do not edit.\n\
+         {TYPESCRIPT_PRELUDE}",
+        abi.program
+    );
+
+    let structs =
+        abi.structs.iter().map(|struct_| (struct_, false)).chain(abi.records.iter().map(|record| (record, true)));
+    for (struct_, is_record) in structs {
+        let kind = if is_record { "record" } else { "struct" };
+        bindings.push_str(&format!("\n/** The {kind} `{}`. */\nexport interface {} {{\n", struct_.name, struct_.name));
+        for field in struct_.fields.iter() {
+            bindings.push_str(&format!("  {}: {};\n", field.name, typescript_type(&field.type_)));
+        }
+        if is_record {
+            bindings
+                .push_str("  /** The nonce of the record, which defaults to `0group`. */\n  _nonce?: aleo.Group;\n");
+        }
+        bindings.push_str("}\n");
+
+        // Like in Rust, the visibility of a record member is on each of its literals.
+        let members = struct_.fields.iter().map(|field| {
+            let visibility = match is_record {
+                true => Some(visibility_suffix(field.visibility)),
+                false => None,
+            };
+            let template = typescript_template(&field.type_, &format!("value.{}", field.name), 0, visibility);
+            format!("{}: {template}", field.name)
+        });
+        let mut members = members.collect::<Vec<_>>();
+        let parameters = match is_record {
+            true => format!("value: {}", struct_.name),
+            false => format!("value: {}, visibility: string = \"\"", struct_.name),
+        };
+        if is_record {
+            members.push("_nonce: ${value._nonce ?? \"0\"}group.public".to_string());
+        }
+        bindings.push_str(&format!(
+            "\n/** Returns the {kind} `{}` in the Aleo plaintext format. */\n\
+             export function {}({parameters}): string {{\n  return `{{ {} }}`;\n}}\n",
+            struct_.name,
+            typescript_encoder_name(&struct_.name),
+            members.join(", ")
+        ));
+    }
+
+    for transition in abi.transitions.iter() {
+        let parameters = transition
+            .inputs
+            .iter()
+            .map(|input| format!("{}: {}", typescript_identifier(&input.name), typescript_type(&input.type_)))
+            .collect::<Vec<_>>();
+        let mut arguments = vec![format!("{:?}", transition.name)];
+        arguments.extend(transition.inputs.iter().map(|input| {
+            format!("`{}`", typescript_template(&input.type_, &typescript_identifier(&input.name), 0, Some("")))
+        }));
+        bindings.push_str(&format!(
+            "\n/** Returns the arguments of `leo run` and `leo execute` that call the transition `{}`. */\n\
+             export function {}({}): string[] {{\n  return [{}];\n}}\n",
+            transition.name,
+            typescript_identifier(&transition.name),
+            parameters.join(", "),
+            arguments.join(", ")
+        ));
+    }
+    bindings
+}
+
+/// Returns the suffix of the literals of a value with the visibility, e.g., `.public`.
+fn visibility_suffix(visibility: Option<Visibility>) -> &'static str {
+    match visibility {
+        None => "",
+        Some(Visibility::Constant) => ".constant",
+        Some(Visibility::Private) => ".private",
+        Some(Visibility::Public) => ".public",
+    }
+}
+
+fn rust_type(type_: &AbiType) -> String {
+    match type_ {
+        AbiType::Primitive(primitive) => match primitive.as_str() {
+            "address" => "aleo::Address".to_string(),
+            "field" => "aleo::Field".to_string(),
+            "group" => "aleo::Group".to_string(),
+            "scalar" => "aleo::Scalar".to_string(),
+            "signature" => "aleo::Signature".to_string(),
+            "string" => "String".to_string(),
+            primitive => primitive.to_string(),
+        },
+        AbiType::Array { element, length } => format!("[{}; {length}]", rust_type(element)),
+        AbiType::Tuple(elements) => format!("({})", elements.iter().map(rust_type).collect::<Vec<_>>().join(", ")),
+        AbiType::Struct(name) | AbiType::Record(name) => rust_identifier(name),
+        // Records of other programs are given as their plaintext or ciphertext.
+        AbiType::ExternalRecord { .. } => "String".to_string(),
+    }
+}
+
+/// Returns the name in Rust, as a raw identifier if it is a keyword of Rust but not of Leo.
+fn rust_identifier(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "abstract", "as", "async", "await", "become", "box", "break", "continue", "do", "dyn", "enum", "extern",
+        "final", "fn", "impl", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+        "static", "trait", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+    ];
+    match KEYWORDS.contains(&name) {
+        true => format!("r#{name}"),
+        false => name.to_string(),
+    }
+}
+
+fn typescript_type(type_: &AbiType) -> String {
+    match type_ {
+        AbiType::Primitive(primitive) => match primitive.as_str() {
+            "address" => "aleo.Address".to_string(),
+            "bool" => "boolean".to_string(),
+            "field" => "aleo.Field".to_string(),
+            "group" => "aleo.Group".to_string(),
+            "scalar" => "aleo.Scalar".to_string(),
+            "signature" => "aleo.Signature".to_string(),
+            "string" => "string".to_string(),
+            // Integers of more than 32 bits do not fit in a `number`.
+            "u64" | "u128" | "i64" | "i128" => "bigint".to_string(),
+            _ => "number".to_string(),
+        },
+        AbiType::Array { element, .. } => format!("{}[]", typescript_type(element)),
+        AbiType::Tuple(elements) => {
+            format!("[{}]", elements.iter().map(typescript_type).collect::<Vec<_>>().join(", "))
+        }
+        AbiType::Struct(name) | AbiType::Record(name) => name.clone(),
+        // Records of other programs are given as their plaintext or ciphertext.
+        AbiType::ExternalRecord { .. } => "string".to_string(),
+    }
+}
+
+/// Returns the contents of a TypeScript template literal that encodes `value` of type `type_` in the Aleo
+/// plaintext format. The elements of nested arrays are named after their `depth`.
+/// Its literals have the `visibility` suffix, or the `visibility` parameter of the encoder of a struct if it is `None`.
+fn typescript_template(type_: &AbiType, value: &str, depth: usize, visibility: Option<&str>) -> String {
+    let suffix = visibility.unwrap_or("${visibility}");
+    match type_ {
+        AbiType::Primitive(primitive) => match primitive.as_str() {
+            "address" | "bool" | "signature" => format!("${{{value}}}{suffix}"),
+            "string" => format!("${{{value}}}"),
+            primitive => format!("${{{value}}}{primitive}{suffix}"),
+        },
+        AbiType::Array { element, .. } => {
+            let element_value = format!("e{depth}");
+            let template = typescript_template(element, &element_value, depth + 1, visibility);
+            format!("[${{{value}.map(({element_value}) => `{template}`).join(\", \")}}]")
+        }
+        AbiType::Tuple(_) | AbiType::ExternalRecord { .. } => format!("${{{value}}}"),
+        AbiType::Struct(name) => match visibility {
+            Some("") => format!("${{{}({value})}}", typescript_encoder_name(name)),
+            Some(visibility) => format!("${{{}({value}, \"{visibility}\")}}", typescript_encoder_name(name)),
+            None => format!("${{{}({value}, visibility)}}", typescript_encoder_name(name)),
+        },
+        AbiType::Record(name) => format!("${{{}({value})}}", typescript_encoder_name(name)),
+    }
+}
+
+/// Returns the name of the TypeScript function that encodes the struct or record `name`.
+fn typescript_encoder_name(name: &str) -> String {
+    format!("encode{name}")
+}
+
+/// Returns the name of a parameter in TypeScript, followed by `_` if it is a reserved word of TypeScript.
+fn typescript_identifier(name: &str) -> String {
+    const RESERVED_WORDS: &[&str] = &[
+        "break",
+        "case",
+        "catch",
+        "class",
+        "continue",
+        "debugger",
+        "default",
+        "delete",
+        "do",
+        "enum",
+        "export",
+        "extends",
+        "false",
+        "finally",
+        "function",
+        "implements",
+        "import",
+        "instanceof",
+        "interface",
+        "new",
+        "null",
+        "package",
+        "private",
+        "protected",
+        "public",
+        "super",
+        "switch",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "typeof",
+        "var",
+        "void",
+        "while",
+        "with",
+        "yield",
+        "let",
+        "static",
+        "await",
+    ];
+    match RESERVED_WORDS.contains(&name) {
+        true => format!("{name}_"),
+        false => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use leo_compiler::abi::{FieldAbi, OutputAbi, StructAbi, TransitionAbi};
+
+    fn primitive(name: &str) -> AbiType {
+        AbiType::Primitive(name.to_string())
+    }
+
+    fn field(name: &str, type_: AbiType, visibility: Option<Visibility>) -> FieldAbi {
+        FieldAbi { name: name.to_string(), type_, visibility }
+    }
+
+    /// The ABI of a program with a struct named like a primitive type, nested structs and arrays, a record,
+    /// and names that are keywords of Rust or TypeScript.
+    fn abi() -> ProgramAbi {
+        let array = |element: AbiType, length: usize| AbiType::Array { element: Box::new(element), length };
+        ProgramAbi {
+            program: "shapes.aleo".to_string(),
+            structs: vec![
+                StructAbi { name: "Field".to_string(), fields: vec![field("value", primitive("field"), None)] },
+                StructAbi {
+                    name: "Line".to_string(),
+                    fields: vec![
+                        field("points", array(AbiType::Struct("Field".to_string()), 2), None),
+                        field("move", array(array(primitive("u64"), 2), 2), None),
+                    ],
+                },
+            ],
+            records: vec![StructAbi {
+                name: "Shape".to_string(),
+                fields: vec![
+                    field("owner", primitive("address"), Some(Visibility::Private)),
+                    field("line", AbiType::Struct("Line".to_string()), Some(Visibility::Public)),
+                ],
+            }],
+            mappings: Vec::new(),
+            transitions: vec![TransitionAbi {
+                name: "delete".to_string(),
+                inputs: vec![
+                    field("shape", AbiType::Record("Shape".to_string()), Some(Visibility::Private)),
+                    field("class", primitive("group"), Some(Visibility::Public)),
+                    field("loop", primitive("bool"), Some(Visibility::Private)),
+                ],
+                outputs: vec![OutputAbi { type_: primitive("field"), visibility: Visibility::Private }],
+                finalize: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn rust_bindings_of_a_program() {
+        let bindings = rust_bindings(&abi());
+
+        // The primitive types are namespaced, so they do not collide with the struct `Field`.
+        assert!(bindings.contains("pub mod aleo {"));
+        assert!(bindings.contains("pub struct Field {\n    pub value: aleo::Field,\n}"));
+        assert!(bindings.contains("pub struct Line {\n    pub points: [Field; 2],\n    pub r#move: [[u64; 2]; 2],\n}"));
+        assert!(bindings.contains(
+            "fn to_aleo_value(&self, visibility: &str) -> String {\n        \
+             format!(\"{{ points: {}, move: {} }}\", self.points.to_aleo_value(visibility), \
+             self.r#move.to_aleo_value(visibility))"
+        ));
+
+        // The members of a record are followed by its nonce, and their visibilities are on each of their literals,
+        // so that the line is encoded as `{ points: [{ value: 1field.public }, ...], ... }`.
+        assert!(bindings.contains("pub owner: aleo::Address,\n    pub line: Line,\n"));
+        assert!(bindings.contains("pub _nonce: Option<aleo::Group>,\n}"));
+        assert!(bindings.contains(
+            "fn to_aleo_value(&self, _visibility: &str) -> String {\n        \
+             format!(\"{{ owner: {}, line: {}, _nonce: {} }}\", self.owner.to_aleo_value(\".private\"), \
+             self.line.to_aleo_value(\".public\"), \
+             self._nonce.as_ref().map_or_else(|| \"0group.public\".to_string(), \
+             |nonce| nonce.to_aleo_value(\".public\")))"
+        ));
+
+        assert!(bindings.contains(
+            "pub fn delete(shape: Shape, class: aleo::Group, r#loop: bool) -> Vec<String> {\n    \
+             vec![\"delete\".to_string(), shape.to_aleo_input(), class.to_aleo_input(), r#loop.to_aleo_input()]\n}"
+        ));
+    }
+
+    #[test]
+    fn typescript_bindings_of_a_program() {
+        let bindings = typescript_bindings(&abi());
+
+        // The primitive types are namespaced, so they do not collide with the interface `Field`.
+        assert!(bindings.contains("export namespace aleo {"));
+        assert!(bindings.contains("export interface Field {\n  value: aleo.Field;\n}"));
+        assert!(bindings.contains("export interface Line {\n  points: Field[];\n  move: bigint[][];\n}"));
+        assert!(bindings.contains(
+            "export function encodeField(value: Field, visibility: string = \"\"): string {\n  \
+             return `{ value: ${value.value}field${visibility} }`;\n}"
+        ));
+        assert!(bindings.contains(
+            "return `{ points: [${value.points.map((e0) => `${encodeField(e0, visibility)}`).join(\", \")}], \
+             move: [${value.move.map((e0) => `[${e0.map((e1) => `${e1}u64${visibility}`).join(\", \")}]`)\
+             .join(\", \")}] }`;"
+        ));
+
+        // The members of a record are followed by its nonce, and their visibilities are on each of their literals,
+        // so that the line is encoded as `{ points: [{ value: 1field.public }, ...], ... }`.
+        assert!(bindings.contains("  owner: aleo.Address;\n  line: Line;\n"));
+        assert!(bindings.contains("  _nonce?: aleo.Group;\n}"));
+        assert!(bindings.contains(
+            "export function encodeShape(value: Shape): string {\n  \
+             return `{ owner: ${value.owner}.private, line: ${encodeLine(value.line, \".public\")}, \
+             _nonce: ${value._nonce ?? \"0\"}group.public }`;\n}"
+        ));
+
+        assert!(bindings.contains(
+            "export function delete_(shape: Shape, class_: aleo.Group, loop: boolean): string[] {\n  \
+             return [\"delete\", `${encodeShape(shape)}`, `${class_}group`, `${loop}`];\n}"
+        ));
+    }
+}
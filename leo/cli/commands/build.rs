@@ -16,23 +16,28 @@
 
 use super::*;
 
-use leo_ast::{NodeBuilder, Struct};
-use leo_compiler::{Compiler, CompilerOptions, InputAst, OutputOptions};
+use leo_ast::{Function, JsonInput, NodeBuilder, Struct, Variant};
+use leo_compiler::{Compiler, CompilerOptions, OutputOptions, Snapshots};
+use leo_decompiler::Decompiler;
+use leo_errors::emitter::BufferEmitter;
 use leo_package::{
     build::BuildDirectory,
-    imports::ImportsDirectory,
+    imports::{ImportsDirectory, IMPORTS_DIRECTORY_NAME},
     inputs::InputFile,
-    outputs::OutputsDirectory,
+    outputs::{ChecksumFile, OutputsDirectory},
     source::SourceDirectory,
 };
-use leo_span::{symbol::with_session_globals, Symbol};
-
-use snarkvm::{
-    package::Package,
-    prelude::{ProgramID, Testnet3},
+use leo_span::{
+    symbol::{set_session_globals_then, with_session_globals},
+    RealFileSystem,
+    Symbol,
 };
 
+use snarkvm::{package::Package, prelude::ProgramID};
+
 use indexmap::IndexMap;
+use rayon::prelude::*;
+use sha2::{Digest, Sha256};
 use std::{
     io::Write,
     path::{Path, PathBuf},
@@ -40,36 +45,80 @@ use std::{
 
 impl From<BuildOptions> for CompilerOptions {
     fn from(options: BuildOptions) -> Self {
-        let mut out_options = Self {
-            build: leo_compiler::BuildOptions { dce_enabled: options.enable_dce },
+        Self {
+            build: leo_compiler::BuildOptions {
+                dce_enabled: options.enable_dce,
+                passes: options.passes,
+                skipped_passes: options.skip_passes,
+                validate_ir: options.validate_ir,
+            },
             output: OutputOptions {
                 symbol_table_spans_enabled: options.enable_symbol_table_spans,
-                initial_symbol_table: options.enable_initial_symbol_table_snapshot,
-                type_checked_symbol_table: options.enable_type_checked_symbol_table_snapshot,
-                unrolled_symbol_table: options.enable_unrolled_symbol_table_snapshot,
+                symbol_table_snapshots: snapshots(options.dump_symbol_table),
                 ast_spans_enabled: options.enable_ast_spans,
-                initial_input_ast: options.enable_initial_input_ast_snapshot,
-                initial_ast: options.enable_initial_ast_snapshot,
-                unrolled_ast: options.enable_unrolled_ast_snapshot,
-                ssa_ast: options.enable_ssa_ast_snapshot,
-                flattened_ast: options.enable_flattened_ast_snapshot,
-                destructured_ast: options.enable_destructured_ast_snapshot,
-                inlined_ast: options.enable_inlined_ast_snapshot,
-                dce_ast: options.enable_dce_ast_snapshot,
+                ast_snapshots: snapshots(options.dump_ast),
+                timings: options.timings,
             },
+        }
+    }
+}
+
+/// Returns the snapshots requested by name, where `all` requests every snapshot.
+fn snapshots(passes: Vec<String>) -> Snapshots {
+    match passes.is_empty() {
+        true => Snapshots::None,
+        false if passes.iter().any(|pass| pass == "all") => Snapshots::All,
+        false => Snapshots::Passes(passes),
+    }
+}
+
+/// The input files and declarations of a built package, which `leo run` and `leo execute` use to
+/// check and encode the inputs of a transition.
+pub struct BuildOutput {
+    /// The path to the `.in` input file, which may not exist.
+    pub input_file: PathBuf,
+    /// The path to the `.json` input file, which may not exist.
+    pub json_input_file: PathBuf,
+    /// The struct and record declarations of the package and its imports.
+    pub structs: IndexMap<Symbol, Struct>,
+    /// The transitions of the package, whose inputs and outputs are checked against their declarations.
+    pub transitions: IndexMap<Symbol, Function>,
+}
+
+impl BuildOutput {
+    /// Returns the values of an input case of `function` from the input files, checked against its inputs.
+    /// The `.in` file is used if it has a section for `function`, and the `.json` file otherwise.
+    pub fn program_inputs(&self, handler: &Handler, function: &str, case: Option<&str>) -> Result<Vec<String>> {
+        let Some(transition) = self.transition(function) else {
+            // snarkVM reports that the transition does not exist.
+            return Ok(Vec::new());
         };
-        if options.enable_all_ast_snapshots {
-            out_options.output.initial_input_ast = true;
-            out_options.output.initial_ast = true;
-            out_options.output.unrolled_ast = true;
-            out_options.output.ssa_ast = true;
-            out_options.output.flattened_ast = true;
-            out_options.output.destructured_ast = true;
-            out_options.output.inlined_ast = true;
-            out_options.output.dce_ast = true;
+
+        if self.input_file.exists() {
+            // Load the input file into the source map, so that errors point into it.
+            let input_sf = with_session_globals(|s| s.source_map.load_file(&self.input_file))
+                .map_err(|e| CompilerError::file_read_error(&self.input_file, e))?;
+            let input_ast =
+                leo_parser::parse_input(handler, &NodeBuilder::default(), &input_sf.src, input_sf.start_pos)?;
+            handler.last_err().map_err(|err| *err)?;
+
+            if input_ast.sections.iter().any(|section| section.name() == function) {
+                return input_ast.program_inputs(handler, function, case, &transition.input, &self.structs);
+            }
+        }
+
+        if self.json_input_file.exists() {
+            let json_input = std::fs::read_to_string(&self.json_input_file)
+                .map_err(|err| PackageError::failed_to_read_file(self.json_input_file.display(), err))?;
+            return JsonInput::parse(&json_input)?.program_inputs(function, case, &transition.input, &self.structs);
         }
 
-        out_options
+        Ok(Vec::new())
+    }
+
+    /// Returns the transition named `function`.
+    pub fn transition(&self, function: &str) -> Option<&Function> {
+        self.transitions.iter().find(|(name, _)| name.to_string() == function).map(|(_, transition)| transition)
     }
 }
 
@@ -82,7 +131,7 @@ pub struct Build {
 
 impl Command for Build {
     type Input = ();
-    type Output = (Option<InputAst>, IndexMap<Symbol, Struct>);
+    type Output = BuildOutput;
 
     fn log_span(&self) -> Span {
         tracing::span!(tracing::Level::INFO, "Leo")
@@ -93,11 +142,18 @@ impl Command for Build {
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        with_network!(context.network()?, N => self.build::<N>(context))
+    }
+}
+
+impl Build {
+    /// Builds the package for the network `N`.
+    fn build<N: Network>(self, context: Context) -> Result<<Self as Command>::Output> {
         // Get the package path.
         let package_path = context.dir()?;
 
         // Get the program id.
-        let manifest = context.open_manifest()?;
+        let manifest = context.open_manifest::<N>()?;
         let program_id = manifest.program_id();
 
         // Create the outputs directory.
@@ -107,7 +163,7 @@ impl Command for Build {
         let build_directory = BuildDirectory::open(&package_path)?;
 
         // Initialize error handler
-        let handler = Handler::default();
+        let handler = context.handler();
 
         // Initialize a node counter.
         let node_builder = NodeBuilder::default();
@@ -121,15 +177,19 @@ impl Command for Build {
         // Store all struct declarations made in the source files.
         let mut structs = IndexMap::new();
 
+        // The checksums decide which programs have changed since the last build.
+        let mut checksums = Checksums::new(&package_path, NetworkName::of::<N>()?, &self.options);
+
         // Compile all .leo files into .aleo files.
-        for file_path in source_files.into_iter() {
+        for file_path in source_files.iter() {
             structs.extend(compile_leo_file(
-                file_path,
+                file_path.clone(),
                 &package_path,
                 program_id,
                 &outputs_directory,
                 &build_directory,
                 &handler,
+                checksums.checksum(file_path)?,
                 self.options.clone(),
                 false,
             )?);
@@ -139,43 +199,67 @@ impl Command for Build {
             // Create Aleo build/imports/ directory.
             let build_imports_directory = ImportsDirectory::create(&build_directory)?;
 
-            // Fetch paths to all .leo files in the imports directory.
+            // Fetch paths to all .leo and .aleo files in the imports directory.
             let import_files = ImportsDirectory::files(&package_path)?;
 
+            // Aleo files are already compiled, so they are copied as they are.
+            let (aleo_files, leo_files): (Vec<_>, Vec<_>) = import_files
+                .into_iter()
+                .partition(|file_path| file_path.extension().map_or(false, |extension| extension == "aleo"));
+            for file_path in aleo_files {
+                structs.extend(copy_aleo_file(&file_path, &build_imports_directory)?);
+            }
+
             // Compile all .leo files into .aleo files.
-            for file_path in import_files.into_iter() {
-                structs.extend(compile_leo_file(
-                    file_path,
-                    &package_path,
-                    program_id,
-                    &outputs_directory,
-                    &build_imports_directory,
-                    &handler,
-                    self.options.clone(),
-                    true,
-                )?);
+            // Imports are compiled independently of each other, so they are compiled in parallel.
+            let leo_files = leo_files
+                .into_iter()
+                .map(|file_path| Ok((checksums.checksum(&file_path)?, file_path)))
+                .collect::<Result<Vec<_>>>()?;
+            let compiled = with_session_globals(|session_globals| {
+                leo_files
+                    .into_par_iter()
+                    .map(|(checksum, file_path)| {
+                        set_session_globals_then(session_globals, || {
+                            // Handlers are not thread-safe, so the errors and warnings of each import are
+                            // reported once all of them are compiled.
+                            let (import_handler, buffer) = Handler::new_with_buf();
+                            let result = compile_leo_file(
+                                file_path,
+                                &package_path,
+                                program_id,
+                                &outputs_directory,
+                                &build_imports_directory,
+                                &import_handler,
+                                checksum,
+                                self.options.clone(),
+                                true,
+                            );
+                            (result, buffer.extract_errs().into_inner(), buffer.extract_warnings().into_inner())
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            });
+            for (result, errors, warnings) in compiled {
+                warnings.into_iter().for_each(|warning| handler.emit_warning(warning));
+                errors.into_iter().for_each(|error| handler.emit_err(error));
+                structs.extend(result?);
             }
         }
 
-        // Load the input file at `package_name.in`
-        let input_file_path = InputFile::new(&manifest.program_id().name().to_string()).setup_file_path(&package_path);
-
-        // Parse the input file.
-        let input_ast = if input_file_path.exists() {
-            // Load the input file into the source map.
-            let input_sf = with_session_globals(|s| s.source_map.load_file(&input_file_path))
-                .map_err(|e| CompilerError::file_read_error(&input_file_path, e))?;
-
-            // TODO: This is a hack to notify the user that something is wrong with the input file. Redesign.
-            leo_parser::parse_input(&handler, &node_builder, &input_sf.src, input_sf.start_pos)
-                .map_err(|_e| println!("Warning: Failed to parse input file"))
-                .ok()
-        } else {
-            None
-        };
+        // The input files are parsed by the commands that use them.
+        let input_file = InputFile::new(&manifest.program_id().name().to_string());
+        let input_file_path = input_file.setup_file_path(&package_path).into_owned();
+        let json_input_file_path = input_file.setup_json_file_path(&package_path).into_owned();
+
+        // Collect the transitions, which program inputs and outputs are checked against.
+        let mut transitions = IndexMap::new();
+        for file_path in source_files.iter() {
+            transitions.extend(leo_file_transitions(file_path, &node_builder)?);
+        }
 
         // `Package::open` checks that the build directory and that `main.aleo` and all imported files are well-formed.
-        Package::<CurrentNetwork>::open(&build_directory).map_err(CliError::failed_to_execute_build)?;
+        Package::<N>::open(&build_directory).map_err(CliError::failed_to_execute_build)?;
 
         // // Unset the Leo panic hook.
         // let _ = std::panic::take_hook();
@@ -195,19 +279,20 @@ impl Command for Build {
         // // Log the result of the build
         // tracing::info!("{}", result);
 
-        Ok((input_ast, structs))
+        Ok(BuildOutput { input_file: input_file_path, json_input_file: json_input_file_path, structs, transitions })
     }
 }
 
 /// Compiles a Leo file in the `src/` directory.
 #[allow(clippy::too_many_arguments)]
-fn compile_leo_file(
+fn compile_leo_file<N: Network>(
     file_path: PathBuf,
     _package_path: &Path,
-    program_id: &ProgramID<Testnet3>,
+    program_id: &ProgramID<N>,
     outputs: &Path,
     build: &Path,
     handler: &Handler,
+    checksum: String,
     options: BuildOptions,
     is_import: bool,
 ) -> Result<IndexMap<Symbol, Struct>> {
@@ -229,25 +314,269 @@ fn compile_leo_file(
         false => format!("main.{}", program_id.network()),
     });
 
+    // The ABI of the program is written next to its bytecode.
+    let abi_file_name = format!("{program_name}.abi.json");
+
+    // Skip the program if neither its source, its imports, nor the compiler options have changed.
+    let options = CompilerOptions::from(options);
+    let checksum_file = ChecksumFile::new(&program_name);
+    if is_up_to_date(&options, &[&aleo_file_path, &build.join(&abi_file_name)], outputs, &checksum_file, &checksum)? {
+        tracing::info!("✅ '{}' is up to date", file_name);
+        return aleo_file_structs(&aleo_file_path);
+    }
+
     // Create a new instance of the Leo compiler.
     let mut compiler = Compiler::new(
-        program_name,
+        program_name.clone(),
         program_id.network().to_string(),
         handler,
         file_path.clone(),
         outputs.to_path_buf(),
-        Some(options.into()),
+        Some(options.clone()),
     );
 
     // Compile the Leo program into Aleo instructions.
-    let (symbol_table, instructions) = compiler.compile()?;
+    let warning_count = handler.warning_count();
+    let artifacts = compiler.compile()?;
+
+    // Write the requested snapshots, and print the timings of the compiler passes, if requested.
+    artifacts.write_snapshots(&RealFileSystem, outputs, &program_name, &options.output)?;
+    for timing in &artifacts.timings {
+        tracing::info!("{timing}");
+    }
 
     // Write the instructions.
     std::fs::File::create(&aleo_file_path)
         .map_err(CliError::failed_to_load_instructions)?
-        .write_all(instructions.as_bytes())
+        .write_all(artifacts.bytecode.as_bytes())
         .map_err(CliError::failed_to_load_instructions)?;
 
+    // Write the ABI.
+    compiler.abi(&artifacts.symbol_table).to_json_file(build.to_path_buf(), &abi_file_name)?;
+
+    // Record the checksum only once the instructions and the ABI are written.
+    // A program with warnings is compiled on every build, so that its warnings are reported again.
+    if handler.warning_count() == warning_count {
+        checksum_file.write_to(outputs, checksum)?;
+    } else {
+        checksum_file.remove(outputs)?;
+    }
+
     tracing::info!("✅ Compiled '{}' into Aleo instructions", file_name);
-    Ok(symbol_table.structs)
+    Ok(artifacts.symbol_table.structs)
+}
+
+/// Returns whether a program can be skipped, because its `artifacts` exist and were compiled from a source
+/// with the same `checksum`. Snapshots and timings are only produced by compiling, so requesting them
+/// always compiles the program.
+fn is_up_to_date(
+    options: &CompilerOptions,
+    artifacts: &[&Path],
+    outputs: &Path,
+    checksum_file: &ChecksumFile,
+    checksum: &str,
+) -> Result<bool> {
+    let output = &options.output;
+    if !matches!(output.ast_snapshots, Snapshots::None)
+        || !matches!(output.symbol_table_snapshots, Snapshots::None)
+        || output.timings
+    {
+        return Ok(false);
+    }
+    Ok(artifacts.iter().all(|artifact| artifact.exists())
+        && checksum_file.exists_at(outputs)
+        && checksum_file.read_from(outputs)? == checksum)
+}
+
+/// Returns the transitions of a compiled Leo program, by name.
+fn leo_file_transitions(file_path: &Path, node_builder: &NodeBuilder) -> Result<IndexMap<Symbol, Function>> {
+    let source_file = with_session_globals(|s| s.source_map.load_file(file_path))
+        .map_err(|e| CompilerError::file_read_error(file_path, e))?;
+    // The program has been compiled, so its warnings have already been reported.
+    let handler = Handler::new(Box::new(BufferEmitter::new()));
+    let program = leo_parser::parse(&handler, node_builder, &RealFileSystem, &source_file.src, source_file.start_pos)?;
+    Ok(program
+        .program_scopes
+        .values()
+        .flat_map(|scope| scope.functions.iter())
+        .filter(|(_, function)| function.variant == Variant::Transition)
+        .map(|(name, function)| (*name, function.clone()))
+        .collect())
+}
+
+/// Copies an Aleo file from the `imports/` directory into the build directory.
+fn copy_aleo_file(file_path: &Path, build: &Path) -> Result<IndexMap<Symbol, Struct>> {
+    // Construct the Aleo file name with extension `foo.aleo`.
+    let file_name =
+        file_path.file_name().and_then(|name| name.to_str()).ok_or_else(PackageError::failed_to_get_file_name)?;
+
+    std::fs::copy(file_path, build.join(file_name)).map_err(CliError::failed_to_write_file)?;
+
+    tracing::info!("✅ Imported '{}' as Aleo instructions", file_name);
+    aleo_file_structs(file_path)
+}
+
+/// Returns the struct and record declarations of a compiled program.
+fn aleo_file_structs(file_path: &Path) -> Result<IndexMap<Symbol, Struct>> {
+    let instructions = std::fs::read_to_string(file_path)
+        .map_err(|err| PackageError::failed_to_read_file(file_path.display(), err))?;
+    let stub = Decompiler::stub(&instructions, &NodeBuilder::default())?;
+    Ok(stub.structs.into_iter().collect())
+}
+
+/// Computes the checksums that decide whether a program must be recompiled.
+/// The checksum of a program covers its source, the compiler options, and the checksums of its imports,
+/// so that a change to an import invalidates every program that imports it, directly or not.
+struct Checksums {
+    /// The directory that imports are resolved against.
+    imports_directory: PathBuf,
+    /// The compiler version, network, and options, which every checksum depends on.
+    options: String,
+    /// The checksums computed so far, by file path.
+    checksums: IndexMap<PathBuf, String>,
+}
+
+impl Checksums {
+    fn new(package_path: &Path, network: NetworkName, options: &BuildOptions) -> Self {
+        // Only the options that change the compiled program are hashed, e.g. not `--dump-ast` or `--timings`.
+        let build = CompilerOptions::from(options.clone()).build;
+        Self {
+            imports_directory: package_path.join(IMPORTS_DIRECTORY_NAME),
+            options: format!("{} {network} {build:?}", env!("CARGO_PKG_VERSION")),
+            checksums: IndexMap::new(),
+        }
+    }
+
+    /// Returns the checksum of the Leo or Aleo file at `file_path`.
+    fn checksum(&mut self, file_path: &Path) -> Result<String> {
+        if let Some(checksum) = self.checksums.get(file_path) {
+            return Ok(checksum.clone());
+        }
+        // Guard against import cycles, which the compiler reports.
+        self.checksums.insert(file_path.to_path_buf(), String::new());
+
+        let source = std::fs::read_to_string(file_path)
+            .map_err(|err| PackageError::failed_to_read_file(file_path.display(), err))?;
+        let mut hasher = Sha256::new();
+        hasher.update(source.as_bytes());
+        // Aleo files are imported as they are, so only their contents matter.
+        if file_path.extension().map_or(false, |extension| extension == "leo") {
+            hasher.update(self.options.as_bytes());
+            for import in imports(&source) {
+                let import_path = self.imports_directory.join(import);
+                if import_path.exists() {
+                    hasher.update(self.checksum(&import_path)?.as_bytes());
+                }
+            }
+        }
+        let checksum = format!("{:x}", hasher.finalize());
+
+        self.checksums.insert(file_path.to_path_buf(), checksum.clone());
+        Ok(checksum)
+    }
+}
+
+/// Returns the file names of the programs imported by a Leo source, e.g. `foo.leo` for `import foo.leo;`.
+fn imports(source: &str) -> impl Iterator<Item = &str> {
+    source
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("import "))
+        .filter_map(|import| import.split(';').next())
+        .map(str::trim)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_dir::{DirBuilder, FileType, TestDir};
+
+    /// Creates a package whose program imports `bar.leo`, which imports `foo.leo`.
+    fn package() -> TestDir {
+        let dir = TestDir::temp().create("src", FileType::Dir).create("imports", FileType::Dir);
+        std::fs::write(dir.path("src/main.leo"), "import bar.leo;\nprogram main.aleo {}\n").unwrap();
+        std::fs::write(dir.path("imports/bar.leo"), "import foo.leo;\nprogram bar.aleo {}\n").unwrap();
+        std::fs::write(dir.path("imports/foo.leo"), "program foo.aleo {}\n").unwrap();
+        dir
+    }
+
+    fn checksum(dir: &TestDir, file: &str, options: &BuildOptions) -> String {
+        Checksums::new(dir.root(), NetworkName::Testnet3, options).checksum(&dir.path(file)).unwrap()
+    }
+
+    #[test]
+    fn imports_of_a_source() {
+        let source = "import foo.leo;\n  import bar.aleo ;\n\nprogram baz.aleo {}\n";
+        assert_eq!(imports(source).collect::<Vec<_>>(), ["foo.leo", "bar.aleo"]);
+    }
+
+    #[test]
+    fn checksum_covers_imports() {
+        let dir = package();
+        let options = BuildOptions::default();
+        let main = checksum(&dir, "src/main.leo", &options);
+        let bar = checksum(&dir, "imports/bar.leo", &options);
+        assert_eq!(checksum(&dir, "src/main.leo", &options), main);
+
+        // A change to an indirect import invalidates every program that depends on it.
+        std::fs::write(dir.path("imports/foo.leo"), "program foo.aleo { transition f() {} }\n").unwrap();
+        assert_ne!(checksum(&dir, "imports/bar.leo", &options), bar);
+        assert_ne!(checksum(&dir, "src/main.leo", &options), main);
+    }
+
+    #[test]
+    fn checksum_covers_compilation_options() {
+        let dir = package();
+        let main = checksum(&dir, "src/main.leo", &BuildOptions::default());
+
+        let output_options = [
+            BuildOptions { timings: true, ..Default::default() },
+            BuildOptions { dump_ast: vec!["all".to_string()], ..Default::default() },
+            BuildOptions { enable_symbol_table_spans: true, ..Default::default() },
+            BuildOptions { offline: true, ..Default::default() },
+        ];
+        for options in output_options {
+            assert_eq!(checksum(&dir, "src/main.leo", &options), main);
+        }
+
+        let compilation_options = [BuildOptions { enable_dce: true, ..Default::default() }, BuildOptions {
+            skip_passes: vec!["dead_code_elimination".to_string()],
+            ..Default::default()
+        }];
+        for options in compilation_options {
+            assert_ne!(checksum(&dir, "src/main.leo", &options), main);
+        }
+    }
+
+    #[test]
+    fn up_to_date_programs_are_skipped() {
+        let dir = TestDir::temp()
+            .create("build", FileType::Dir)
+            .create("build/main.aleo", FileType::EmptyFile)
+            .create("outputs", FileType::Dir);
+        let (artifact, outputs) = (dir.path("build/main.aleo"), dir.path("outputs"));
+        let checksum_file = ChecksumFile::new("main");
+        let up_to_date = |options: BuildOptions, checksum: &str| {
+            is_up_to_date(&CompilerOptions::from(options), &[&artifact], &outputs, &checksum_file, checksum).unwrap()
+        };
+
+        // A program that has never been compiled is not up to date.
+        assert!(!up_to_date(BuildOptions::default(), "checksum"));
+
+        checksum_file.write_to(&outputs, "checksum".to_string()).unwrap();
+        assert!(up_to_date(BuildOptions::default(), "checksum"));
+        assert!(!up_to_date(BuildOptions::default(), "other checksum"));
+
+        // Snapshots and timings are only produced by compiling the program.
+        assert!(!up_to_date(BuildOptions { dump_ast: vec!["all".to_string()], ..Default::default() }, "checksum"));
+        assert!(!up_to_date(
+            BuildOptions { dump_symbol_table: vec!["all".to_string()], ..Default::default() },
+            "checksum"
+        ));
+        assert!(!up_to_date(BuildOptions { timings: true, ..Default::default() }, "checksum"));
+
+        // A program whose artifacts were removed is compiled again.
+        std::fs::remove_file(&artifact).unwrap();
+        assert!(!up_to_date(BuildOptions::default(), "checksum"));
+    }
 }
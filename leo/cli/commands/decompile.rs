@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_decompiler::Decompiler;
+
+use std::{fs, path::PathBuf};
+
+/// Decompiles an Aleo program into Leo.
+#[derive(Parser, Debug)]
+pub struct Decompile {
+    #[clap(name = "FILE", help = "The path to the `.aleo` file to decompile.")]
+    pub(crate) file: PathBuf,
+
+    #[clap(long, help = "A directory containing the `.aleo` files of the imported programs.")]
+    pub(crate) imports: Option<PathBuf>,
+
+    #[clap(short, long, help = "Writes the Leo program to the given file instead of printing it.")]
+    pub(crate) output: Option<PathBuf>,
+}
+
+impl Command for Decompile {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, _: Context, _: Self::Input) -> Result<Self::Output> {
+        let mut decompiler = Decompiler::default();
+
+        // Load the imported programs, which are needed to resolve external calls.
+        if let Some(imports) = &self.imports {
+            let entries =
+                fs::read_dir(imports).map_err(|err| PackageError::failed_to_read_file(imports.display(), err))?;
+            for entry in entries {
+                let path = entry.map_err(|err| PackageError::failed_to_read_file(imports.display(), err))?.path();
+                if path.extension().map_or(false, |extension| extension == "aleo") {
+                    let import = fs::read_to_string(&path)
+                        .map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+                    decompiler.add_import(&import)?;
+                }
+            }
+        }
+
+        let bytecode = fs::read_to_string(&self.file)
+            .map_err(|err| PackageError::failed_to_read_file(self.file.display(), err))?;
+        let program = decompiler.decompile(&bytecode)?;
+
+        match &self.output {
+            Some(output) => {
+                fs::write(output, program.to_string()).map_err(CliError::failed_to_write_file)?;
+                tracing::info!("✅ Decompiled '{}' into '{}'", self.file.display(), output.display());
+            }
+            None => println!("{program}"),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,554 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_ast::{ConstDeclaration, Function, Mapping, Mode, NodeBuilder, Output, Program, Struct, Type, Variant};
+use leo_compiler::Compiler;
+use leo_package::source::SourceDirectory;
+use leo_span::{symbol::with_session_globals, RealFileSystem, Symbol};
+
+use clap::ValueEnum;
+use indexmap::IndexMap;
+use std::{fs, path::PathBuf};
+
+/// The directory the documentation is written to by default, relative to the package.
+const DOCS_DIRECTORY_NAME: &str = "docs/";
+
+/// Generates the API documentation of the package and its imports from their doc comments.
+#[derive(Parser, Debug)]
+pub struct Doc {
+    #[clap(long, value_enum, default_value_t, help = "The format of the documentation.")]
+    pub(crate) format: DocFormat,
+    #[clap(long, help = "The directory to write the documentation to. Defaults to `docs/` in the package.")]
+    pub(crate) output: Option<PathBuf>,
+}
+
+/// The format of the generated documentation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum DocFormat {
+    /// One Markdown file per program.
+    #[default]
+    Markdown,
+    /// One static HTML page per program.
+    Html,
+}
+
+impl DocFormat {
+    /// Returns the extension of the documentation files.
+    fn extension(self) -> &'static str {
+        match self {
+            DocFormat::Markdown => "md",
+            DocFormat::Html => "html",
+        }
+    }
+}
+
+impl Command for Doc {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+        let output_directory = self.output.unwrap_or_else(|| package_path.join(DOCS_DIRECTORY_NAME));
+
+        let handler = context.handler();
+        let node_builder = NodeBuilder::default();
+
+        // Parse the programs of the package, which includes their imports.
+        let mut pages = IndexMap::new();
+        for file_path in SourceDirectory::files(&package_path)? {
+            let source_file = with_session_globals(|s| s.source_map.load_file(&file_path))
+                .map_err(|e| CompilerError::file_read_error(&file_path, e))?;
+            let mut program =
+                leo_parser::parse(&handler, &node_builder, &RealFileSystem, &source_file.src, source_file.start_pos)?;
+            // The parser only leaves placeholders for the imported Aleo programs, which the compiler resolves.
+            Compiler::resolve_stubs(&RealFileSystem, &node_builder, &mut program)?;
+            document_program(&program, self.format, &mut pages);
+        }
+
+        fs::create_dir_all(&output_directory)
+            .map_err(|err| PackageError::failed_to_create_directory(output_directory.display(), err))?;
+        for (file_name, contents) in pages.iter() {
+            fs::write(output_directory.join(file_name), contents).map_err(CliError::failed_to_write_file)?;
+        }
+
+        tracing::info!("✅ Documented {} program(s) in '{}'", pages.len(), output_directory.display());
+        Ok(())
+    }
+}
+
+/// Adds the pages of the program scopes of `program` and of its imports to `pages`, by file name.
+fn document_program(program: &Program, format: DocFormat, pages: &mut IndexMap<String, String>) {
+    for (import, _) in program.imports.values() {
+        document_program(import, format, pages);
+    }
+
+    let links = struct_links(program, format);
+    let mut imports = program.imports.keys().chain(program.stubs.keys()).collect::<Vec<_>>();
+    imports.sort_by_key(|name| name.to_string());
+
+    // Aleo programs are only available as bytecode, so their pages only show their interface.
+    for (name, stub) in program.stubs.iter() {
+        let links = stub.structs.iter().map(|(name, _)| (*name, format!("#struct.{name}"))).collect();
+        let functions = stub.functions.iter().map(|(name, function)| (*name, Function::from(function.clone())));
+        let mut page = Page::new(format, links);
+        page.items(&[], &stub.structs, &stub.mappings, &functions.collect::<Vec<_>>());
+        pages.insert(page_name(*name, format), page.finish(&stub.stub_id.to_string()));
+    }
+
+    for scope in program.program_scopes.values() {
+        let mut page = Page::new(format, links.clone());
+        if !imports.is_empty() {
+            page.heading(2, None, "Imports");
+            let items = imports.iter().map(|name| page.link(&format!("{name}.aleo"), &page_name(**name, format)));
+            page.list(items.collect());
+        }
+        page.items(&scope.consts, &scope.structs, &scope.mappings, &scope.functions);
+        pages.insert(page_name(scope.program_id.name.name, format), page.finish(&scope.program_id.to_string()));
+    }
+}
+
+/// Returns the file name of the page of the program `name`.
+fn page_name(name: Symbol, format: DocFormat) -> String {
+    format!("{name}.{}", format.extension())
+}
+
+/// Returns the links to the declarations of the structs and records that `program` can use, by name.
+/// The structs of the program itself shadow the ones of its imports.
+fn struct_links(program: &Program, format: DocFormat) -> IndexMap<Symbol, String> {
+    let mut links = IndexMap::new();
+    for (import, _) in program.imports.values() {
+        links.extend(struct_links(import, format));
+        for scope in import.program_scopes.values() {
+            let page = page_name(scope.program_id.name.name, format);
+            links.extend(scope.structs.iter().map(|(name, _)| (*name, format!("{page}#struct.{name}"))));
+        }
+    }
+    for (stub_name, stub) in program.stubs.iter() {
+        let page = page_name(*stub_name, format);
+        links.extend(stub.structs.iter().map(|(name, _)| (*name, format!("{page}#struct.{name}"))));
+    }
+    for scope in program.program_scopes.values() {
+        links.extend(scope.structs.iter().map(|(name, _)| (*name, format!("#struct.{name}"))));
+    }
+    links
+}
+
+/// A page of documentation, written in Markdown or HTML.
+struct Page {
+    format: DocFormat,
+    /// The links to the declarations of the structs and records, by name.
+    links: IndexMap<Symbol, String>,
+    contents: String,
+}
+
+impl Page {
+    fn new(format: DocFormat, links: IndexMap<Symbol, String>) -> Self {
+        Self { format, links, contents: String::new() }
+    }
+
+    /// Documents the declarations of a program, grouped by their kind.
+    fn items(
+        &mut self,
+        consts: &[(Symbol, ConstDeclaration)],
+        structs: &[(Symbol, Struct)],
+        mappings: &[(Symbol, Mapping)],
+        functions: &[(Symbol, Function)],
+    ) {
+        if !consts.is_empty() {
+            self.heading(2, None, "Constants");
+        }
+        for (name, declaration) in consts {
+            self.heading(3, Some(&format!("const.{name}")), &name.to_string());
+            self.code(&declaration.to_string());
+            self.doc(&declaration.doc);
+        }
+
+        for (title, is_record) in [("Records", true), ("Structs", false)] {
+            let structs = structs.iter().filter(|(_, struct_)| struct_.is_record == is_record).collect::<Vec<_>>();
+            if !structs.is_empty() {
+                self.heading(2, None, title);
+            }
+            for (name, struct_) in structs {
+                self.struct_(*name, struct_);
+            }
+        }
+
+        if !mappings.is_empty() {
+            self.heading(2, None, "Mappings");
+        }
+        for (name, mapping) in mappings {
+            self.heading(3, Some(&format!("mapping.{name}")), &name.to_string());
+            self.code(&format!("{mapping};"));
+            self.doc(&mapping.doc);
+            self.table(&["Key", "Value"], vec![vec![self.type_(&mapping.key_type), self.type_(&mapping.value_type)]]);
+        }
+
+        for (title, variant) in [
+            ("Transitions", Variant::Transition),
+            ("Functions", Variant::Standard),
+            ("Inline functions", Variant::Inline),
+        ] {
+            let functions = functions.iter().filter(|(_, function)| function.variant == variant).collect::<Vec<_>>();
+            if !functions.is_empty() {
+                self.heading(2, None, title);
+            }
+            for (name, function) in functions {
+                self.function(*name, function);
+            }
+        }
+    }
+
+    fn struct_(&mut self, name: Symbol, struct_: &Struct) {
+        self.heading(3, Some(&format!("struct.{name}")), &name.to_string());
+        self.doc(&struct_.doc);
+        let rows = struct_.members.iter().map(|member| match struct_.is_record {
+            true => vec![self.text(&member.name().to_string()), visibility(member.mode), self.type_(&member.type_)],
+            false => vec![self.text(&member.name().to_string()), self.type_(&member.type_)],
+        });
+        let rows = rows.collect();
+        match struct_.is_record {
+            true => self.table(&["Field", "Visibility", "Type"], rows),
+            false => self.table(&["Field", "Type"], rows),
+        }
+    }
+
+    fn function(&mut self, name: Symbol, function: &Function) {
+        self.heading(3, Some(&format!("function.{name}")), &name.to_string());
+        self.code(&signature(function));
+        self.doc(&function.doc);
+
+        // Only the inputs and outputs of transitions have visibilities.
+        let is_transition = function.variant == Variant::Transition;
+        if !function.input.is_empty() {
+            self.heading(4, None, "Parameters");
+            let rows = function.input.iter().map(|input| {
+                let mut row = vec![self.text(&input.identifier().to_string())];
+                if is_transition {
+                    row.push(visibility(input.mode()));
+                }
+                row.push(self.type_(&input.type_()));
+                row
+            });
+            let rows = rows.collect();
+            match is_transition {
+                true => self.table(&["Name", "Visibility", "Type"], rows),
+                false => self.table(&["Name", "Type"], rows),
+            }
+        }
+        if !function.output.is_empty() {
+            self.heading(4, None, "Outputs");
+            let rows = function.output.iter().map(|output| {
+                let type_ = match output {
+                    Output::Internal(output) => self.type_(&output.type_),
+                    Output::External(external) => self.link(
+                        &format!("{}.leo/{}.record", external.program_name, external.record),
+                        &format!("{}#struct.{}", page_name(external.program_name.name, self.format), external.record),
+                    ),
+                };
+                match is_transition {
+                    true => vec![visibility(output.mode()), type_],
+                    false => vec![type_],
+                }
+            });
+            let rows = rows.collect();
+            match is_transition {
+                true => self.table(&["Visibility", "Type"], rows),
+                false => self.table(&["Type"], rows),
+            }
+        }
+        if let Some(finalize) = function.finalize.as_ref().filter(|finalize| !finalize.input.is_empty()) {
+            self.heading(4, None, "Finalize parameters");
+            let rows = finalize
+                .input
+                .iter()
+                .map(|input| vec![self.text(&input.identifier().to_string()), self.type_(&input.type_())])
+                .collect();
+            self.table(&["Name", "Type"], rows);
+        }
+    }
+
+    /// Returns a type, with links to the declarations of the structs and records in it.
+    fn type_(&self, type_: &Type) -> String {
+        match type_ {
+            Type::Identifier(identifier) => match self.links.get(&identifier.name) {
+                Some(link) => self.link(&identifier.name.to_string(), link),
+                None => self.text(&identifier.name.to_string()),
+            },
+            Type::Array(array) => {
+                format!("{}{}; {}{}", self.text("["), self.type_(array.element_type()), array.length(), self.text("]"))
+            }
+            Type::Tuple(tuple) => {
+                format!("({})", tuple.elements().iter().map(|type_| self.type_(type_)).collect::<Vec<_>>().join(", "))
+            }
+            _ => self.text(&type_.to_string()),
+        }
+    }
+
+    fn heading(&mut self, level: usize, anchor: Option<&str>, title: &str) {
+        let title = self.text(title);
+        match (self.format, anchor) {
+            (DocFormat::Markdown, None) => self.contents.push_str(&format!("{} {title}\n\n", "#".repeat(level))),
+            (DocFormat::Markdown, Some(anchor)) => {
+                self.contents.push_str(&format!("{} <a id=\"{anchor}\"></a>{title}\n\n", "#".repeat(level)))
+            }
+            (DocFormat::Html, None) => self.contents.push_str(&format!("<h{level}>{title}</h{level}>\n")),
+            (DocFormat::Html, Some(anchor)) => {
+                self.contents.push_str(&format!("<h{level} id=\"{anchor}\">{title}</h{level}>\n"))
+            }
+        }
+    }
+
+    /// Writes a doc comment, which is Markdown, as it is written. HTML pages keep its paragraphs.
+    fn doc(&mut self, doc: &Option<String>) {
+        let Some(doc) = doc else { return };
+        match self.format {
+            DocFormat::Markdown => self.contents.push_str(&format!("{doc}\n\n")),
+            DocFormat::Html => {
+                for paragraph in doc.split("\n\n").filter(|paragraph| !paragraph.trim().is_empty()) {
+                    self.contents.push_str(&format!("<p>{}</p>\n", self.text(paragraph.trim())));
+                }
+            }
+        }
+    }
+
+    fn code(&mut self, code: &str) {
+        match self.format {
+            DocFormat::Markdown => self.contents.push_str(&format!("```leo\n{code}\n```\n\n")),
+            DocFormat::Html => {
+                self.contents.push_str(&format!("<pre><code class=\"language-leo\">{}</code></pre>\n", self.text(code)))
+            }
+        }
+    }
+
+    fn list(&mut self, items: Vec<String>) {
+        match self.format {
+            DocFormat::Markdown => {
+                items.iter().for_each(|item| self.contents.push_str(&format!("- {item}\n")));
+                self.contents.push('\n');
+            }
+            DocFormat::Html => {
+                self.contents.push_str("<ul>\n");
+                items.iter().for_each(|item| self.contents.push_str(&format!("<li>{item}</li>\n")));
+                self.contents.push_str("</ul>\n");
+            }
+        }
+    }
+
+    /// Writes a table, whose cells are already formatted.
+    fn table(&mut self, headers: &[&str], rows: Vec<Vec<String>>) {
+        match self.format {
+            DocFormat::Markdown => {
+                self.contents.push_str(&format!("| {} |\n", headers.join(" | ")));
+                self.contents.push_str(&format!("|{}\n", " --- |".repeat(headers.len())));
+                for row in rows {
+                    self.contents.push_str(&format!("| {} |\n", row.join(" | ")));
+                }
+                self.contents.push('\n');
+            }
+            DocFormat::Html => {
+                self.contents.push_str("<table>\n<tr>");
+                headers.iter().for_each(|header| self.contents.push_str(&format!("<th>{header}</th>")));
+                self.contents.push_str("</tr>\n");
+                for row in rows {
+                    self.contents.push_str("<tr>");
+                    row.iter().for_each(|cell| self.contents.push_str(&format!("<td>{cell}</td>")));
+                    self.contents.push_str("</tr>\n");
+                }
+                self.contents.push_str("</table>\n");
+            }
+        }
+    }
+
+    fn link(&self, text: &str, link: &str) -> String {
+        match self.format {
+            DocFormat::Markdown => format!("[{}]({link})", self.text(text)),
+            DocFormat::Html => format!("<a href=\"{link}\">{}</a>", self.text(text)),
+        }
+    }
+
+    /// Escapes text that is not Markdown or HTML.
+    fn text(&self, text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for c in text.chars() {
+            match (self.format, c) {
+                (DocFormat::Markdown, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '#') => {
+                    escaped.push('\\');
+                    escaped.push(c);
+                }
+                (DocFormat::Html, '&') => escaped.push_str("&amp;"),
+                (DocFormat::Html, '<') => escaped.push_str("&lt;"),
+                (DocFormat::Html, '>') => escaped.push_str("&gt;"),
+                (DocFormat::Html, '"') => escaped.push_str("&quot;"),
+                _ => escaped.push(c),
+            }
+        }
+        escaped
+    }
+
+    /// Returns the page, titled with the program id.
+    fn finish(self, title: &str) -> String {
+        let title = self.text(title);
+        match self.format {
+            DocFormat::Markdown => format!("# {title}\n\n{}", self.contents),
+            DocFormat::Html => format!(
+                "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n</head>\n<body>\n<h1>{title}</h1>\n{}</body>\n</html>\n",
+                self.contents
+            ),
+        }
+    }
+}
+
+/// Returns the visibility of a record member, an input or an output, which is private unless stated otherwise.
+fn visibility(mode: Mode) -> String {
+    match mode {
+        Mode::None => Mode::Private.to_string(),
+        mode => mode.to_string(),
+    }
+}
+
+/// Returns the signature of a function, with its annotations and the signature of its finalize block.
+fn signature(function: &Function) -> String {
+    let mut signature = String::new();
+    for annotation in function.annotations.iter() {
+        signature.push_str(&format!("{annotation}\n"));
+    }
+    let variant = match function.variant {
+        Variant::Inline => "inline",
+        Variant::Standard => "function",
+        Variant::Transition => "transition",
+    };
+    let input = function.input.iter().map(|input| input.to_string()).collect::<Vec<_>>().join(", ");
+    signature.push_str(&format!("{variant} {}({input})", function.identifier));
+    match function.output.len() {
+        0 => {}
+        1 => signature.push_str(&format!(" -> {}", function.output[0])),
+        _ => signature.push_str(&format!(
+            " -> ({})",
+            function.output.iter().map(|output| output.to_string()).collect::<Vec<_>>().join(", ")
+        )),
+    }
+    if let Some(finalize) = &function.finalize {
+        let input = finalize.input.iter().map(|input| input.to_string()).collect::<Vec<_>>().join(", ");
+        signature.push_str(&format!("\nfinalize {}({input})", finalize.identifier));
+    }
+    signature
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use leo_compiler::MemoryFileSystem;
+    use leo_span::{source_map::FileName, symbol::create_session_if_not_set_then};
+
+    const MAIN: &str = r#"import token.aleo;
+
+program main.aleo {
+    transition decimals(metadata: Metadata) -> u8 {
+        return metadata.decimals;
+    }
+}
+"#;
+
+    const TOKEN: &str = r#"program token.aleo;
+
+struct Metadata:
+    decimals as u8;
+
+record Token:
+    owner as address.private;
+    amount as u64.private;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as Token.record;
+    output r2 as Token.record;
+"#;
+
+    /// Documents `MAIN`, which imports the Aleo program `TOKEN`, and returns its pages.
+    fn document(format: DocFormat) -> IndexMap<String, String> {
+        create_session_if_not_set_then(|_| {
+            let file_system = MemoryFileSystem::new();
+            file_system.insert("imports/token.aleo", TOKEN);
+
+            let handler = Handler::default();
+            let node_builder = NodeBuilder::default();
+            let source_file =
+                with_session_globals(|s| s.source_map.new_source(MAIN, FileName::Custom("main.leo".to_string())));
+            let mut program =
+                leo_parser::parse(&handler, &node_builder, &file_system, &source_file.src, source_file.start_pos)
+                    .unwrap();
+            Compiler::resolve_stubs(&file_system, &node_builder, &mut program).unwrap();
+
+            let mut pages = IndexMap::new();
+            document_program(&program, format, &mut pages);
+            pages
+        })
+    }
+
+    #[test]
+    fn markdown_pages_document_aleo_imports() {
+        let pages = document(DocFormat::Markdown);
+        assert_eq!(pages.keys().collect::<Vec<_>>(), ["token.md", "main.md"]);
+
+        let token = &pages["token.md"];
+        assert!(token.starts_with("# token.aleo\n\n"));
+        assert!(token.contains("## Records\n\n### <a id=\"struct.Token\"></a>Token\n\n"));
+        assert!(token.contains("## Structs\n\n### <a id=\"struct.Metadata\"></a>Metadata\n\n"));
+        assert!(token.contains("## Mappings\n\n### <a id=\"mapping.balances\"></a>balances\n\n"));
+        assert!(token.contains("### <a id=\"function.mint\"></a>mint\n\n"));
+        assert!(token.contains("| [Token](#struct.Token) |"));
+
+        let main = &pages["main.md"];
+        assert!(main.contains("## Imports\n\n- [token.aleo](token.md)\n\n"));
+        assert!(main.contains("| metadata | private | [Metadata](token.md#struct.Metadata) |"));
+    }
+
+    #[test]
+    fn html_pages_document_aleo_imports() {
+        let pages = document(DocFormat::Html);
+        assert_eq!(pages.keys().collect::<Vec<_>>(), ["token.html", "main.html"]);
+
+        let token = &pages["token.html"];
+        assert!(token.contains("<title>token.aleo</title>"));
+        assert!(token.contains("<h2>Records</h2>\n<h3 id=\"struct.Token\">Token</h3>\n"));
+        assert!(token.contains("<h2>Structs</h2>\n<h3 id=\"struct.Metadata\">Metadata</h3>\n"));
+        assert!(token.contains("<h2>Mappings</h2>\n<h3 id=\"mapping.balances\">balances</h3>\n"));
+        assert!(token.contains("<h3 id=\"function.mint\">mint</h3>\n"));
+        assert!(token.contains("<td><a href=\"#struct.Token\">Token</a></td>"));
+
+        let main = &pages["main.html"];
+        assert!(main.contains("<h2>Imports</h2>\n<ul>\n<li><a href=\"token.html\">token.aleo</a></li>\n</ul>\n"));
+        assert!(main.contains(
+            "<tr><td>metadata</td><td>private</td><td><a href=\"token.html#struct.Metadata\">Metadata</a></td></tr>"
+        ));
+    }
+}
@@ -27,6 +27,9 @@ pub struct Execute {
     #[clap(name = "INPUTS", help = "The inputs to the program. If none are provided, the input file is used.")]
     inputs: Vec<String>,
 
+    #[clap(long, help = "The input case to use, e.g. `large` for the section `[main.large]` of the input file.")]
+    case: Option<String>,
+
     #[clap(
         name = "ENDPOINT",
         help = "The specified network endpoint.",
@@ -55,10 +58,7 @@ impl Command for Execute {
         // If input values are provided, then run the program with those inputs.
         // Otherwise, use the input file.
         let mut inputs = match self.inputs.is_empty() {
-            true => match input {
-                (Some(input_ast), circuits) => input_ast.program_inputs(&self.name, circuits),
-                _ => Vec::new(),
-            },
+            true => input.program_inputs(&context.handler(), &self.name, self.case.as_deref())?,
             false => self.inputs,
         };
 
@@ -0,0 +1,242 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_ast::NodeBuilder;
+use leo_errors::emitter::Location;
+use leo_package::{imports::ImportsDirectory, source::SourceDirectory};
+use leo_span::{source_map::FileName, symbol::with_session_globals, RealFileSystem};
+
+use std::{fs, ops::Range, path::Path};
+
+/// The maximum number of times a file is parsed and fixed.
+/// Parsing stops at the first error, so fixing an error may uncover further errors.
+const MAX_ROUNDS: usize = 100;
+
+/// Applies the fixes suggested by the compiler to the Leo files of the package, e.g., to migrate deprecated syntax.
+#[derive(Parser, Debug)]
+pub struct Fix {
+    #[clap(long, help = "Print the fixes without applying them.")]
+    pub(crate) dry_run: bool,
+}
+
+impl Command for Fix {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let path = context.dir()?;
+
+        // Fetch paths to all .leo files in the source and imports directories.
+        let mut file_paths = SourceDirectory::files(&path)?;
+        if !ImportsDirectory::is_empty(&path)? {
+            file_paths.extend(
+                ImportsDirectory::files(&path)?
+                    .into_iter()
+                    .filter(|file_path| file_path.extension().map_or(false, |extension| extension == "leo")),
+            );
+        }
+
+        let mut count = 0;
+        for file_path in file_paths.iter() {
+            count += fix_file(file_path, self.dry_run)?;
+        }
+
+        match (count, self.dry_run) {
+            (0, _) => tracing::info!("✅ Found nothing to fix."),
+            (count, true) => tracing::info!("✅ Found {count} fix(es), run without `--dry-run` to apply them."),
+            (count, false) => tracing::info!("✅ Applied {count} fix(es)."),
+        }
+        Ok(())
+    }
+}
+
+/// A fix of a file, replacing the source code in `range`, a range of bytes from the start of the file.
+struct FileFix {
+    /// The range of bytes to replace.
+    range: Range<usize>,
+    /// The source code to replace it with.
+    replacement: String,
+    /// The location of the replaced source code, for reporting the fix.
+    location: Location,
+}
+
+/// Applies the fixes to the file at `path`, and returns the number of fixes.
+/// The fixed file is only written if `dry_run` is not set.
+fn fix_file(path: &Path, dry_run: bool) -> Result<usize> {
+    let original = fs::read_to_string(path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+
+    let mut source = original.clone();
+    let mut count = 0;
+    for _ in 0..MAX_ROUNDS {
+        let fixes = find_fixes(&source, path);
+        if fixes.is_empty() {
+            break;
+        }
+        for fix in fixes.iter() {
+            tracing::info!(
+                "🔧 {}:{}:{}: `{}` -> `{}`",
+                path.display(),
+                fix.location.line_start,
+                fix.location.col_start,
+                &source[fix.range.clone()],
+                fix.replacement
+            );
+        }
+        count += fixes.len();
+        // The fixes do not overlap, so applying them from the end of the file keeps the other ranges valid.
+        for fix in fixes.into_iter().rev() {
+            source.replace_range(fix.range, &fix.replacement);
+        }
+    }
+
+    if !dry_run && source != original {
+        fs::write(path, source).map_err(CliError::failed_to_write_file)?;
+    }
+    Ok(count)
+}
+
+/// Returns the fixes of the errors and warnings reported when parsing `source`, in the order of their ranges.
+/// Fixes that overlap an earlier fix are left for the next round.
+fn find_fixes(source: &str, path: &Path) -> Vec<FileFix> {
+    let file = with_session_globals(|s| s.source_map.new_source(source, FileName::Real(path.to_path_buf())));
+    let (handler, buffer) = Handler::new_with_buf();
+    // Parsing stops at the first error, which is returned rather than emitted.
+    let result = leo_parser::parse_ast(&handler, &NodeBuilder::default(), &RealFileSystem, &file.src, file.start_pos);
+
+    let errors = buffer.extract_errs().into_inner().into_iter().chain(result.err());
+    let mut diagnostics = errors.filter_map(|error| error.diagnostic()).collect::<Vec<_>>();
+    diagnostics.extend(buffer.extract_warnings().into_inner().iter().map(|warning| warning.diagnostic()));
+
+    let mut fixes = diagnostics
+        .into_iter()
+        .flat_map(|diagnostic| diagnostic.fixes)
+        .map(|fix| FileFix {
+            range: (fix.span.lo - file.start_pos).0 as usize..(fix.span.hi - file.start_pos).0 as usize,
+            replacement: fix.replacement,
+            location: fix.location,
+        })
+        .collect::<Vec<_>>();
+    fixes.sort_by_key(|fix| fix.range.start);
+
+    let mut end = 0;
+    fixes.retain(|fix| {
+        let overlaps = fix.range.start < end;
+        end = end.max(fix.range.end);
+        !overlaps
+    });
+    fixes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use leo_span::symbol::create_session_if_not_set_then;
+    use std::path::PathBuf;
+
+    use test_dir::{DirBuilder, FileType, TestDir};
+
+    const DEPRECATED: &str = r#"program test.aleo {
+    mapping balances: address => u64;
+
+    transition mint(amount: u64) {
+        finalize(self.caller, amount);
+    }
+
+    finalize mint(receiver: address, amount: u64) {
+        increment(balances, receiver, amount);
+    }
+}
+"#;
+
+    const FIXED: &str = r#"program test.aleo {
+    mapping balances: address => u64;
+
+    transition mint(amount: u64) {
+        return then finalize(self.caller, amount);
+    }
+
+    finalize mint(receiver: address, amount: u64) {
+        Mapping::set(balances, receiver, Mapping::get_or_use(balances, receiver, 0u64) + amount);
+    }
+}
+"#;
+
+    /// Writes `source` to a Leo file in a new temporary directory.
+    fn leo_file(source: &str) -> (TestDir, PathBuf) {
+        let dir = TestDir::temp().create("main.leo", FileType::EmptyFile);
+        let path = dir.path("main.leo");
+        fs::write(&path, source).unwrap();
+        (dir, path)
+    }
+
+    #[test]
+    fn fixes_are_applied_until_none_are_left() {
+        create_session_if_not_set_then(|_| {
+            let (_dir, path) = leo_file(DEPRECATED);
+
+            // The `finalize` statement stops parsing, so the `increment` is only fixed in the second round.
+            assert_eq!(fix_file(&path, true).unwrap(), 2);
+            assert_eq!(fs::read_to_string(&path).unwrap(), DEPRECATED);
+
+            assert_eq!(fix_file(&path, false).unwrap(), 2);
+            assert_eq!(fs::read_to_string(&path).unwrap(), FIXED);
+            assert_eq!(fix_file(&path, false).unwrap(), 0);
+        })
+    }
+
+    #[test]
+    fn fixes_that_are_not_machine_applicable_are_skipped() {
+        create_session_if_not_set_then(|_| {
+            let source =
+                DEPRECATED.replace("finalize(self.caller, amount);", "finalize(self.caller, amount);\n        return;");
+            let (_dir, path) = leo_file(&source);
+
+            assert_eq!(fix_file(&path, false).unwrap(), 0);
+            assert_eq!(fs::read_to_string(&path).unwrap(), source);
+        })
+    }
+
+    #[test]
+    fn fixes_are_ordered_and_do_not_overlap() {
+        create_session_if_not_set_then(|_| {
+            let source = "program test.aleo {
+    mapping m: u8 => u8;
+    transition t() {}
+    finalize t() {
+        decrement(m, 0u8, 1u8);
+        increment(m, 0u8, 1u8);
+    }
+}
+";
+            let fixes = find_fixes(source, Path::new("main.leo"));
+            assert_eq!(fixes.len(), 2);
+            assert!(fixes[0].range.end <= fixes[1].range.start);
+            assert_eq!(&source[fixes[0].range.clone()], "decrement(m, 0u8, 1u8)");
+            assert_eq!(fixes[1].location.line_start, 6);
+        })
+    }
+}
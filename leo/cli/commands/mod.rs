@@ -17,18 +17,33 @@
 pub mod account;
 pub use account::Account;
 
+pub mod bindgen;
+pub use bindgen::Bindgen;
+
 pub mod build;
 pub use build::Build;
 
 pub mod clean;
 pub use clean::Clean;
 
+pub mod decompile;
+pub use decompile::Decompile;
+
+pub mod doc;
+pub use doc::Doc;
+
 pub mod example;
 pub use example::Example;
 
 pub mod execute;
 pub use execute::Execute;
 
+pub mod explain;
+pub use explain::Explain;
+
+pub mod fix;
+pub use fix::Fix;
+
 // pub mod deploy;
 // pub use deploy::Deploy;
 
@@ -47,7 +62,9 @@ pub use update::Update;
 use super::*;
 use crate::cli::helpers::context::*;
 use leo_errors::{emitter::Handler, CliError, CompilerError, PackageError, Result};
-use leo_package::{build::*, outputs::OutputsDirectory, package::*};
+use leo_package::{build::*, network::NetworkName, outputs::OutputsDirectory, package::*, with_network};
+
+use snarkvm::prelude::Network;
 
 use clap::Parser;
 use colored::Colorize;
@@ -121,34 +138,22 @@ pub trait Command {
 pub struct BuildOptions {
     #[clap(long, help = "Enables offline mode.")]
     pub offline: bool,
-    #[clap(long, help = "Enable spans in AST snapshots.")]
-    pub enable_symbol_table_spans: bool,
     #[clap(long, help = "Enables dead code elimination in the compiler.")]
-    pub enable_initial_symbol_table_snapshot: bool,
-    #[clap(long, help = "Writes symbol table snapshot of the type checked symbol table.")]
-    pub enable_type_checked_symbol_table_snapshot: bool,
-    #[clap(long, help = "Writes symbol table snapshot of the unrolled symbol table.")]
-    pub enable_unrolled_symbol_table_snapshot: bool,
+    pub enable_dce: bool,
+    #[clap(long, value_delimiter = ',', help = "Runs only the given compiler passes, in the given order.")]
+    pub passes: Vec<String>,
+    #[clap(long = "skip-pass", help = "Skips the given compiler pass. May be repeated.")]
+    pub skip_passes: Vec<String>,
+    #[clap(long, help = "Checks the invariants of the AST after each compiler pass.")]
+    pub validate_ir: bool,
+    #[clap(long, help = "Prints the wall time and AST node count of each compiler pass.")]
+    pub timings: bool,
     #[clap(long, help = "Enable spans in AST snapshots.")]
     pub enable_ast_spans: bool,
+    #[clap(long, value_delimiter = ',', help = "Writes AST snapshots after the given passes, `parsing`, or `all`.")]
+    pub dump_ast: Vec<String>,
     #[clap(long, help = "Enable spans in symbol table snapshots.")]
-    pub enable_dce: bool,
-    #[clap(long, help = "Writes all AST snapshots for the different compiler phases.")]
-    pub enable_all_ast_snapshots: bool,
-    #[clap(long, help = "Writes Input AST snapshot of the initial parse.")]
-    pub enable_initial_input_ast_snapshot: bool,
-    #[clap(long, help = "Writes AST snapshot of the initial parse.")]
-    pub enable_initial_ast_snapshot: bool,
-    #[clap(long, help = "Writes AST snapshot of the unrolled AST.")]
-    pub enable_unrolled_ast_snapshot: bool,
-    #[clap(long, help = "Writes AST snapshot of the SSA AST.")]
-    pub enable_ssa_ast_snapshot: bool,
-    #[clap(long, help = "Writes AST snapshot of the flattened AST.")]
-    pub enable_flattened_ast_snapshot: bool,
-    #[clap(long, help = "Writes AST snapshot of the destructured AST.")]
-    pub enable_destructured_ast_snapshot: bool,
-    #[clap(long, help = "Writes AST snapshot of the inlined AST.")]
-    pub enable_inlined_ast_snapshot: bool,
-    #[clap(long, help = "Writes AST snapshot of the dead code eliminated (DCE) AST.")]
-    pub enable_dce_ast_snapshot: bool,
+    pub enable_symbol_table_spans: bool,
+    #[clap(long, value_delimiter = ',', help = "Writes symbol table snapshots after the given passes, or `all`.")]
+    pub dump_symbol_table: Vec<String>,
 }
@@ -16,7 +16,12 @@
 
 use super::*;
 
-use snarkvm::{cli::New as SnarkVMNew, file::AleoFile};
+use snarkvm::{
+    cli::New as SnarkVMNew,
+    file::{AleoFile, Manifest},
+};
+
+use std::path::Path;
 
 /// Create new Leo project
 #[derive(Parser, Debug)]
@@ -38,6 +43,13 @@ impl Command for New {
     }
 
     fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        with_network!(context.network()?, N => self.create::<N>(context))
+    }
+}
+
+impl New {
+    /// Creates the package for the network `N`.
+    fn create<N: Network>(self, context: Context) -> Result<()> {
         // Call the `aleo new` command from the Aleo SDK.
         let command =
             SnarkVMNew::try_parse_from([SNARKVM_COMMAND, &self.name]).map_err(CliError::failed_to_parse_new)?;
@@ -51,14 +63,17 @@ impl Command for New {
         package_path.push(&self.name);
 
         // Initialize the Leo package in the directory created by `aleo new`.
-        Package::<CurrentNetwork>::initialize(&self.name, &package_path)?;
+        Package::<N>::initialize(&self.name, &package_path)?;
+
+        // Record the network in the program manifest, so that later commands target it.
+        write_network::<N>(&package_path)?;
 
         // Change the cwd to the Leo package directory to compile aleo files.
         std::env::set_current_dir(&package_path)
             .map_err(|err| PackageError::failed_to_set_cwd(package_path.display(), err))?;
 
         // Open the program manifest.
-        let manifest = context.open_manifest()?;
+        let manifest = context.open_manifest::<N>()?;
 
         // Create a path to the build directory.
         let mut build_directory = package_path.clone();
@@ -81,7 +96,7 @@ impl Command for New {
             .map_err(PackageError::failed_to_open_aleo_file)?;
 
         let mut aleo_file_path = package_path.clone();
-        aleo_file_path.push(AleoFile::<CurrentNetwork>::main_file_name());
+        aleo_file_path.push(AleoFile::<N>::main_file_name());
 
         // Remove the Aleo file from the package directory.
         aleo_file.remove(&aleo_file_path).map_err(PackageError::failed_to_remove_aleo_file)?;
@@ -89,3 +104,17 @@ impl Command for New {
         Ok(())
     }
 }
+
+/// Adds the network `N` to the `program.json` file in the package directory.
+fn write_network<N: Network>(package_path: &Path) -> Result<()> {
+    let path = package_path.join(Manifest::<N>::file_name());
+    let manifest = std::fs::read_to_string(&path).map_err(PackageError::failed_to_open_manifest)?;
+    let mut manifest: serde_json::Value =
+        serde_json::from_str(&manifest).map_err(PackageError::failed_to_open_manifest)?;
+    if let Some(manifest) = manifest.as_object_mut() {
+        manifest.insert("network".to_string(), serde_json::Value::String(NetworkName::of::<N>()?.to_string()));
+    }
+    let manifest = serde_json::to_string_pretty(&manifest).map_err(PackageError::failed_to_open_manifest)?;
+    std::fs::write(&path, format!("{manifest}\n")).map_err(PackageError::failed_to_open_manifest)?;
+    Ok(())
+}
@@ -15,8 +15,16 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::cli::commands::build::BuildOutput;
+use leo_ast::ProgramOutput;
+use leo_package::root::Env;
 
-use snarkvm::cli::Run as SnarkVMRun;
+use snarkvm::{
+    circuit::Aleo,
+    package::Package,
+    prelude::{Ciphertext, Identifier, PrivateKey, Record, Value, ViewKey},
+};
+use std::str::FromStr;
 
 /// Build, Prove and Run Leo program with inputs
 #[derive(Parser, Debug)]
@@ -27,6 +35,12 @@ pub struct Run {
     #[clap(name = "INPUTS", help = "The inputs to the program. If none are provided, the input file is used.")]
     inputs: Vec<String>,
 
+    #[clap(long, help = "The input case to use, e.g. `large` for the section `[main.large]` of the input file.")]
+    case: Option<String>,
+
+    #[clap(long, help = "Print the outputs to stdout as a JSON array, in the format of JSON input files.")]
+    json: bool,
+
     #[clap(flatten)]
     pub(crate) compiler_options: BuildOptions,
 }
@@ -46,39 +60,99 @@ impl Command for Run {
     fn apply(self, context: Context, input: Self::Input) -> Result<Self::Output> {
         // If input values are provided, then run the program with those inputs.
         // Otherwise, use the input file.
-        let mut inputs = match self.inputs.is_empty() {
-            true => match input {
-                (Some(input_ast), circuits) => input_ast.program_inputs(&self.name, circuits),
-                _ => Vec::new(),
-            },
-            false => self.inputs,
+        let inputs = match self.inputs.is_empty() {
+            true => input.program_inputs(&context.handler(), &self.name, self.case.as_deref())?,
+            false => self.inputs.clone(),
         };
 
-        // Compose the `run` command.
-        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name];
+        with_network!(context.network()?, N, A => self.run::<N, A>(&context, &input, &inputs))
+    }
+}
 
-        // Add the program inputs to the arguments.
-        arguments.append(&mut inputs);
+impl Run {
+    /// Runs the transition as the account in `.env`, and logs its outputs with their Leo types.
+    fn run<N: Network, A: Aleo<Network = N, BaseField = N::Field>>(
+        &self,
+        context: &Context,
+        build: &BuildOutput,
+        inputs: &[String],
+    ) -> Result<()> {
+        let path = context.dir()?;
+        let private_key = Env::<N>::read_from(&path)?.private_key()?;
+
+        let function = Identifier::<N>::from_str(&self.name).map_err(CliError::failed_to_parse_run)?;
+        let inputs = inputs
+            .iter()
+            .map(|input| Value::<N>::from_str(input))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(CliError::failed_to_parse_run)?;
 
         // Open the Leo build/ directory
-        let path = context.dir()?;
         let build_directory = BuildDirectory::open(&path)?;
-
-        // Change the cwd to the Leo build/ directory to compile aleo files.
-        std::env::set_current_dir(&build_directory)
-            .map_err(|err| PackageError::failed_to_set_cwd(build_directory.display(), err))?;
+        let package = Package::<N>::open(&build_directory).map_err(CliError::failed_to_execute_run)?;
 
         // Unset the Leo panic hook
         let _ = std::panic::take_hook();
 
-        // Call the `run` command.
-        println!();
-        let command = SnarkVMRun::try_parse_from(&arguments).map_err(CliError::failed_to_parse_run)?;
-        let res = command.parse().map_err(CliError::failed_to_execute_run)?;
+        let (response, metrics) = package
+            .run::<A, _>(&private_key, function, &inputs, &mut rand::thread_rng())
+            .map_err(CliError::failed_to_execute_run)?;
+
+        for metric in metrics {
+            tracing::info!(
+                "'{}/{}' - {} constraints",
+                metric.program_id,
+                metric.function_name,
+                metric.num_function_constraints
+            );
+        }
+
+        let outputs = response
+            .outputs()
+            .iter()
+            .map(|output| decrypt_record(&private_key, output.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        // The outputs are decoded with the types of the transition, unless it is not in the Leo program.
+        let outputs = match build.transition(&self.name) {
+            Some(transition) => outputs
+                .iter()
+                .zip(&transition.output)
+                .enumerate()
+                .map(|(index, (output, declared))| {
+                    ProgramOutput::decode(index, output, &declared.type_(), &build.structs)
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => outputs.into_iter().map(ProgramOutput::Literal).collect(),
+        };
+
+        match self.json {
+            true => {
+                let outputs = serde_json::Value::Array(outputs.iter().map(ProgramOutput::to_json).collect());
+                println!("{outputs:#}");
+            }
+            false => {
+                for output in outputs {
+                    tracing::info!("• {}", output.to_string().replace('\n', "\n  "));
+                }
+            }
+        }
 
-        // Log the output of the `run` command.
-        tracing::info!("{}", res);
+        tracing::info!("Finished '{}/{}'", package.program_id(), self.name);
 
         Ok(())
     }
 }
+
+/// Decrypts an output that is a record encrypted to the account of `private_key`.
+/// Other outputs, including records of other accounts, are returned as they are.
+fn decrypt_record<N: Network>(private_key: &PrivateKey<N>, output: String) -> Result<String> {
+    if !output.starts_with("record1") {
+        return Ok(output);
+    }
+    let view_key = ViewKey::try_from(private_key).map_err(CliError::failed_to_execute_run)?;
+    let record = Record::<N, Ciphertext<N>>::from_str(&output).map_err(CliError::failed_to_execute_run)?;
+    match record.is_owner(&view_key) {
+        true => Ok(record.decrypt(&view_key).map_err(CliError::failed_to_execute_run)?.to_string()),
+        false => Ok(output),
+    }
+}
@@ -15,11 +15,22 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use leo_errors::{CliError, PackageError, Result};
-use leo_package::build::{BuildDirectory, BUILD_DIRECTORY_NAME};
+use leo_errors::{
+    emitter::{Handler, JsonEmitter, SarifEmitter},
+    CliError,
+    LeoError,
+    PackageError,
+    Result,
+};
+use leo_package::{
+    build::{BuildDirectory, BUILD_DIRECTORY_NAME},
+    network::NetworkName,
+    with_network,
+};
 
-use snarkvm::file::Manifest;
+use snarkvm::{file::Manifest, prelude::Network};
 
+use clap::ValueEnum;
 use std::{
     env::current_dir,
     fs::File,
@@ -27,17 +38,72 @@ use std::{
     path::{Path, PathBuf},
 };
 
+/// The format in which errors and warnings are reported.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum MessageFormat {
+    /// Human-readable messages with source snippets.
+    #[default]
+    Human,
+    /// One JSON object per message.
+    Json,
+    /// A SARIF log of all messages, written to the `--sarif-output` file once the command has finished.
+    Sarif,
+}
+
 /// Project context, manifest, current directory etc
 /// All the info that is relevant in most of the commands
 #[derive(Clone)]
 pub struct Context {
     /// Path at which the command is called, None when default
     pub path: Option<PathBuf>,
+    /// The format of errors and warnings.
+    pub message_format: MessageFormat,
+    /// The file that the SARIF log is written to, if the format is SARIF.
+    pub sarif_output: Option<PathBuf>,
+    /// The network selected with `--network`, which takes precedence over the manifest.
+    pub network: Option<NetworkName>,
+    /// Collects the messages of every handler when the format is SARIF.
+    sarif: SarifEmitter,
 }
 
 impl Context {
-    pub fn new(path: Option<PathBuf>) -> Result<Context> {
-        Ok(Context { path })
+    pub fn new(
+        path: Option<PathBuf>,
+        message_format: MessageFormat,
+        sarif_output: Option<PathBuf>,
+        network: Option<NetworkName>,
+    ) -> Result<Context> {
+        // The SARIF log is kept apart from the logs and messages on stdout and stderr.
+        if message_format == MessageFormat::Sarif && sarif_output.is_none() {
+            return Err(CliError::sarif_output_required().into());
+        }
+        Ok(Context { path, message_format, sarif_output, network, sarif: SarifEmitter::default() })
+    }
+
+    /// Returns a handler that emits errors and warnings in the requested format.
+    pub fn handler(&self) -> Handler {
+        match self.message_format {
+            MessageFormat::Human => Handler::default(),
+            MessageFormat::Json => Handler::new(Box::<JsonEmitter>::default()),
+            MessageFormat::Sarif => Handler::new(Box::new(self.sarif.clone())),
+        }
+    }
+
+    /// Reports the error a command failed with in the requested format, and writes the SARIF log if requested.
+    /// Human-readable errors are left to the caller, which prints them before exiting.
+    pub fn finish<T>(&self, result: Result<T>) -> Result<T> {
+        if self.message_format == MessageFormat::Human {
+            return result;
+        }
+        let result = result.map_err(|err| {
+            let code = err.exit_code();
+            self.handler().emit_err(err);
+            LeoError::LastErrorCode(code)
+        });
+        if let (MessageFormat::Sarif, Some(path)) = (self.message_format, &self.sarif_output) {
+            std::fs::write(path, format!("{:#}", self.sarif.to_sarif())).map_err(CliError::failed_to_write_file)?;
+        }
+        result
     }
 
     /// Returns the path to the Leo package.
@@ -48,12 +114,33 @@ impl Context {
         }
     }
 
+    /// Returns the network that the command targets.
+    /// This is the network given with `--network`, or else the `network` of `program.json`, or else testnet3.
+    pub fn network(&self) -> Result<NetworkName> {
+        if let Some(network) = self.network {
+            return Ok(network);
+        }
+
+        // Read the network from the manifest file, if there is one.
+        let path = self.dir()?.join(with_network!(NetworkName::default(), N => Manifest::<N>::file_name()));
+        if !path.exists() {
+            return Ok(NetworkName::default());
+        }
+        let manifest = std::fs::read_to_string(&path).map_err(PackageError::failed_to_open_manifest)?;
+        let manifest: serde_json::Value =
+            serde_json::from_str(&manifest).map_err(PackageError::failed_to_open_manifest)?;
+        match manifest.get("network").and_then(|network| network.as_str()) {
+            Some(network) => network.parse(),
+            None => Ok(NetworkName::default()),
+        }
+    }
+
     /// Returns the package name as a String.
     /// Opens the manifest file `program.json` and creates the build directory if it doesn't exist.
-    pub fn open_manifest(&self) -> Result<Manifest<CurrentNetwork>> {
+    pub fn open_manifest<N: Network>(&self) -> Result<Manifest<N>> {
         // Open the manifest file.
         let path = self.dir()?;
-        let manifest = Manifest::<CurrentNetwork>::open(&path).map_err(PackageError::failed_to_open_manifest)?;
+        let manifest = Manifest::<N>::open(&path).map_err(PackageError::failed_to_open_manifest)?;
 
         // Lookup the program id.
         // let program_id = manifest.program_id();
@@ -71,7 +158,7 @@ impl Context {
             std::fs::read_to_string(manifest.path()).map_err(PackageError::failed_to_open_manifest)?;
 
         // Construct the file path.
-        let build_manifest_path = build_path.join(Manifest::<CurrentNetwork>::file_name());
+        let build_manifest_path = build_path.join(Manifest::<N>::file_name());
 
         // Write the file.
         File::create(build_manifest_path)
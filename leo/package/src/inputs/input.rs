@@ -32,6 +32,7 @@ use std::{
 };
 
 pub static INPUT_FILE_EXTENSION: &str = ".in";
+pub static JSON_INPUT_FILE_EXTENSION: &str = ".json";
 
 #[derive(Deserialize)]
 pub struct InputFile {
@@ -82,12 +83,21 @@ b: u32 = 2u32;
     }
 
     pub fn setup_file_path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        self.setup_path(path, INPUT_FILE_EXTENSION)
+    }
+
+    /// Returns the path to the JSON input file, which holds the same inputs as the `.in` file, keyed by name.
+    pub fn setup_json_file_path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        self.setup_path(path, JSON_INPUT_FILE_EXTENSION)
+    }
+
+    fn setup_path<'a>(&self, path: &'a Path, extension: &str) -> Cow<'a, Path> {
         let mut path = Cow::from(path);
         if path.is_dir() {
             if !path.ends_with(INPUTS_DIRECTORY_NAME) {
                 path.to_mut().push(INPUTS_DIRECTORY_NAME);
             }
-            path.to_mut().push(format!("{}{INPUT_FILE_EXTENSION}", self.package_name));
+            path.to_mut().push(format!("{}{extension}", self.package_name));
         }
         path
     }
@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The networks a Leo package can target.
+
+use leo_errors::{PackageError, Result};
+use snarkvm::console::network::Network;
+
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+#[doc(hidden)]
+pub use snarkvm::{circuit::AleoV0, console::network::Testnet3};
+
+/// Evaluates `$body` with the type `$N` bound to the snarkVM network named by `$network`,
+/// and optionally `$A` bound to the circuit of the network, which is needed to execute programs.
+///
+/// This is the only place that maps network names to snarkVM types: supporting a new network
+/// means adding a variant to [`NetworkName`] and an arm here, while commands stay generic over `N`.
+#[macro_export]
+macro_rules! with_network {
+    ($network:expr, $N:ident => $body:expr) => {
+        $crate::with_network!($network, $N, _A => $body)
+    };
+    ($network:expr, $N:ident, $A:ident => $body:expr) => {
+        match $network {
+            $crate::network::NetworkName::Testnet3 => {
+                type $N = $crate::network::Testnet3;
+                #[allow(dead_code)]
+                type $A = $crate::network::AleoV0;
+                $body
+            }
+        }
+    };
+}
+
+/// The name of a snarkVM network, as written in `program.json`, `.env`, and the `--network` flag.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkName {
+    #[default]
+    Testnet3,
+}
+
+impl NetworkName {
+    /// Every network that Leo supports.
+    pub const ALL: &'static [NetworkName] = &[NetworkName::Testnet3];
+
+    /// Returns the name of the network with the snarkVM type `N`.
+    pub fn of<N: Network>() -> Result<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|network| with_network!(*network, M => M::ID) == N::ID)
+            .ok_or_else(|| PackageError::unknown_network(N::NAME, Self::supported()).into())
+    }
+
+    /// Returns the supported network names, separated by commas.
+    fn supported() -> String {
+        Self::ALL.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl fmt::Display for NetworkName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Testnet3 => write!(f, "testnet3"),
+        }
+    }
+}
+
+impl FromStr for NetworkName {
+    type Err = leo_errors::LeoError;
+
+    fn from_str(name: &str) -> Result<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|network| network.to_string() == name)
+            .ok_or_else(|| PackageError::unknown_network(name, Self::supported()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn network_names_round_trip() {
+        for network in NetworkName::ALL {
+            assert_eq!(network.to_string().parse::<NetworkName>().unwrap(), *network);
+        }
+        assert!("mainnet".parse::<NetworkName>().is_err());
+    }
+
+    #[test]
+    fn network_of_type() {
+        assert_eq!(NetworkName::of::<Testnet3>().unwrap(), NetworkName::Testnet3);
+    }
+}
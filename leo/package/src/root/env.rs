@@ -15,11 +15,12 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 //! The `.env` file.
+use crate::network::NetworkName;
 use leo_errors::{PackageError, Result};
 use snarkvm::console::{account::PrivateKey, prelude::Network};
 
 use serde::Deserialize;
-use std::{borrow::Cow, fs::File, io::Write, marker::PhantomData, path::Path};
+use std::{borrow::Cow, fs::File, io::Write, marker::PhantomData, path::Path, str::FromStr};
 
 pub static ENV_FILENAME: &str = ".env";
 
@@ -38,6 +39,29 @@ impl<N: Network> Env<N> {
         Self { data, _phantom: PhantomData }
     }
 
+    /// Reads the `.env` file in the directory `path`.
+    pub fn read_from(path: &Path) -> Result<Self> {
+        let mut path = Cow::from(path);
+        if path.is_dir() {
+            path.to_mut().push(ENV_FILENAME);
+        }
+
+        let data = std::fs::read_to_string(&path).map_err(PackageError::io_error_env_file)?;
+        Ok(Self::from(data))
+    }
+
+    /// Returns the private key set by `PRIVATE_KEY`.
+    pub fn private_key(&self) -> Result<PrivateKey<N>> {
+        let private_key = self
+            .data
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .find(|(key, _)| key.trim() == "PRIVATE_KEY")
+            .map(|(_, value)| value.trim())
+            .ok_or_else(|| PackageError::invalid_env_private_key("`PRIVATE_KEY` is not set"))?;
+        Ok(PrivateKey::from_str(private_key).map_err(PackageError::invalid_env_private_key)?)
+    }
+
     pub fn exists_at(path: &Path) -> bool {
         let mut path = Cow::from(path);
         if path.is_dir() {
@@ -64,6 +88,6 @@ impl<N: Network> Env<N> {
         // Initialize a new development private key.
         let private_key = PrivateKey::<N>::new(rng)?;
 
-        Ok(format!("NETWORK=testnet3\nPRIVATE_KEY={private_key}\n"))
+        Ok(format!("NETWORK={}\nPRIVATE_KEY={private_key}\n", NetworkName::of::<N>()?))
     }
 }
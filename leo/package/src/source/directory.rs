@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::parse_file_paths;
+use crate::{parse_file_paths, LEO_FILE_EXTENSION};
 
 use leo_errors::{PackageError, Result};
 
@@ -51,7 +51,7 @@ impl SourceDirectory {
         let directory = fs::read_dir(&path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
         let mut file_paths = Vec::new();
 
-        parse_file_paths(directory, &mut file_paths)?;
+        parse_file_paths(directory, &mut file_paths, &[LEO_FILE_EXTENSION])?;
 
         Ok(file_paths)
     }
@@ -16,7 +16,7 @@
 
 //! This file contains tools for benchmarking the Leo compiler and its stages.
 
-use leo_compiler::{BuildOptions, Compiler, CompilerOptions, OutputOptions};
+use leo_compiler::{BuildOptions, Compiler, CompilerOptions, OutputOptions, Snapshots};
 use leo_errors::emitter::{Emitter, Handler};
 use leo_span::{source_map::FileName, symbol::SESSION_GLOBALS};
 use leo_test_framework::get_benches;
@@ -35,6 +35,8 @@ enum BenchMode {
     Symbol,
     /// Benchmarks type checking.
     Type,
+    /// Benchmarks pruning.
+    Prune,
     /// Benchmarks loop unrolling.
     Unroll,
     /// Benchmarks static single assignment.
@@ -45,6 +47,8 @@ enum BenchMode {
     Destructure,
     /// Benchmarks function inlining.
     Inline,
+    /// Benchmarks common subexpression elimination.
+    Cse,
     /// Benchmarks dead code elimination.
     Dce,
     /// Benchmarks code generation.
@@ -88,21 +92,13 @@ fn new_compiler(handler: &Handler) -> Compiler<'_> {
         PathBuf::from(String::new()),
         PathBuf::from(String::new()),
         Some(CompilerOptions {
-            build: BuildOptions { dce_enabled: true },
+            build: BuildOptions { dce_enabled: true, ..Default::default() },
             output: OutputOptions {
                 symbol_table_spans_enabled: false,
-                initial_symbol_table: false,
-                type_checked_symbol_table: false,
-                unrolled_symbol_table: false,
+                symbol_table_snapshots: Snapshots::None,
                 ast_spans_enabled: false,
-                initial_ast: false,
-                initial_input_ast: false,
-                unrolled_ast: false,
-                ssa_ast: false,
-                flattened_ast: false,
-                destructured_ast: false,
-                inlined_ast: false,
-                dce_ast: false,
+                ast_snapshots: Snapshots::None,
+                timings: false,
             },
         }),
     )
@@ -125,11 +121,13 @@ impl Sample {
             BenchMode::Parse => self.bench_parse(c),
             BenchMode::Symbol => self.bench_symbol_table(c),
             BenchMode::Type => self.bench_type_checker(c),
+            BenchMode::Prune => self.bench_pruner(c),
             BenchMode::Unroll => self.bench_loop_unroller(c),
             BenchMode::Ssa => self.bench_ssa(c),
             BenchMode::Flatten => self.bench_flattener(c),
             BenchMode::Destructure => self.bench_destructurer(c),
             BenchMode::Inline => self.bench_inline(c),
+            BenchMode::Cse => self.bench_cse(c),
             BenchMode::Dce => self.bench_dce(c),
             BenchMode::Codegen => self.bench_codegen(c),
             BenchMode::Full => self.bench_full(c),
@@ -171,7 +169,7 @@ impl Sample {
     }
 
     fn bench_symbol_table(&self, c: &mut Criterion) {
-        self.bencher_after_parse(c, "symbol table pass", |compiler| {
+        self.bencher_after_parse(c, "symbol table pass", |mut compiler| {
             let start = Instant::now();
             let out = compiler.symbol_table_pass();
             let time = start.elapsed();
@@ -181,7 +179,7 @@ impl Sample {
     }
 
     fn bench_type_checker(&self, c: &mut Criterion) {
-        self.bencher_after_parse(c, "type checker pass", |compiler| {
+        self.bencher_after_parse(c, "type checker pass", |mut compiler| {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
             let start = Instant::now();
             let out = compiler.type_checker_pass(symbol_table);
@@ -191,11 +189,27 @@ impl Sample {
         });
     }
 
+    fn bench_pruner(&self, c: &mut Criterion) {
+        self.bencher_after_parse(c, "pruning pass", |mut compiler| {
+            let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
+            let (mut symbol_table, mut struct_graph, mut call_graph) =
+                compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            let start = Instant::now();
+            let out = compiler.pruning_pass(&mut symbol_table, &mut struct_graph, &mut call_graph);
+            let time = start.elapsed();
+            out.expect("failed to run pruning pass");
+            time
+        });
+    }
+
     fn bench_loop_unroller(&self, c: &mut Criterion) {
         self.bencher_after_parse(c, "loop unrolling pass", |mut compiler| {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
-            let (symbol_table, _struct_graph, _call_graph) =
+            let (mut symbol_table, mut struct_graph, mut call_graph) =
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler
+                .pruning_pass(&mut symbol_table, &mut struct_graph, &mut call_graph)
+                .expect("failed to run pruning pass");
             let start = Instant::now();
             let out = compiler.loop_unrolling_pass(symbol_table);
             let time = start.elapsed();
@@ -207,8 +221,11 @@ impl Sample {
     fn bench_ssa(&self, c: &mut Criterion) {
         self.bencher_after_parse(c, "full", |mut compiler| {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
-            let (symbol_table, _struct_graph, _call_graph) =
+            let (mut symbol_table, mut struct_graph, mut call_graph) =
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler
+                .pruning_pass(&mut symbol_table, &mut struct_graph, &mut call_graph)
+                .expect("failed to run pruning pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             let start = Instant::now();
             let out = compiler.static_single_assignment_pass(&symbol_table);
@@ -221,8 +238,11 @@ impl Sample {
     fn bench_flattener(&self, c: &mut Criterion) {
         self.bencher_after_parse(c, "flattener pass", |mut compiler| {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
-            let (symbol_table, _struct_graph, _call_graph) =
+            let (mut symbol_table, mut struct_graph, mut call_graph) =
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler
+                .pruning_pass(&mut symbol_table, &mut struct_graph, &mut call_graph)
+                .expect("failed to run pruning pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             let start = Instant::now();
@@ -236,8 +256,11 @@ impl Sample {
     fn bench_destructurer(&self, c: &mut Criterion) {
         self.bencher_after_parse(c, "destructurer pass", |mut compiler| {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
-            let (symbol_table, _struct_graph, _call_graph) =
+            let (mut symbol_table, mut struct_graph, mut call_graph) =
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler
+                .pruning_pass(&mut symbol_table, &mut struct_graph, &mut call_graph)
+                .expect("failed to run pruning pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
@@ -252,8 +275,11 @@ impl Sample {
     fn bench_inline(&self, c: &mut Criterion) {
         self.bencher_after_parse(c, "inliner pass", |mut compiler| {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
-            let (symbol_table, _struct_graph, call_graph) =
+            let (mut symbol_table, mut struct_graph, mut call_graph) =
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler
+                .pruning_pass(&mut symbol_table, &mut struct_graph, &mut call_graph)
+                .expect("failed to run pruning pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
@@ -266,16 +292,41 @@ impl Sample {
         });
     }
 
+    fn bench_cse(&self, c: &mut Criterion) {
+        self.bencher_after_parse(c, "cse pass", |mut compiler| {
+            let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
+            let (mut symbol_table, mut struct_graph, mut call_graph) =
+                compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler
+                .pruning_pass(&mut symbol_table, &mut struct_graph, &mut call_graph)
+                .expect("failed to run pruning pass");
+            let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
+            compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
+            compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
+            compiler.destructuring_pass().expect("failed to run destructurer pass");
+            compiler.function_inlining_pass(&call_graph).expect("failed to run inliner pass");
+            let start = Instant::now();
+            let out = compiler.common_subexpression_elimination_pass(&symbol_table);
+            let time = start.elapsed();
+            out.expect("failed to run cse pass");
+            time
+        });
+    }
+
     fn bench_dce(&self, c: &mut Criterion) {
-        self.bencher_after_parse(c, "inliner pass", |mut compiler| {
+        self.bencher_after_parse(c, "dce pass", |mut compiler| {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
-            let (symbol_table, _struct_graph, call_graph) =
+            let (mut symbol_table, mut struct_graph, mut call_graph) =
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler
+                .pruning_pass(&mut symbol_table, &mut struct_graph, &mut call_graph)
+                .expect("failed to run pruning pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
             compiler.destructuring_pass().expect("failed to run destructurer pass");
             compiler.function_inlining_pass(&call_graph).expect("failed to run inliner pass");
+            compiler.common_subexpression_elimination_pass(&symbol_table).expect("failed to run cse pass");
             let start = Instant::now();
             let out = compiler.dead_code_elimination_pass();
             let time = start.elapsed();
@@ -285,15 +336,19 @@ impl Sample {
     }
 
     fn bench_codegen(&self, c: &mut Criterion) {
-        self.bencher_after_parse(c, "inliner pass", |mut compiler| {
+        self.bencher_after_parse(c, "codegen pass", |mut compiler| {
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
-            let (symbol_table, struct_graph, call_graph) =
+            let (mut symbol_table, mut struct_graph, mut call_graph) =
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler
+                .pruning_pass(&mut symbol_table, &mut struct_graph, &mut call_graph)
+                .expect("failed to run pruning pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
             compiler.destructuring_pass().expect("failed to run destructurer pass");
             compiler.function_inlining_pass(&call_graph).expect("failed to run inliner pass");
+            compiler.common_subexpression_elimination_pass(&symbol_table).expect("failed to run cse pass");
             compiler.dead_code_elimination_pass().expect("failed to run dce pass");
             let start = Instant::now();
             let out = compiler.code_generation_pass(&symbol_table, &struct_graph, &call_graph);
@@ -309,13 +364,17 @@ impl Sample {
             let start = Instant::now();
             compiler.parse_program_from_string(input, name).expect("Failed to parse program");
             let symbol_table = compiler.symbol_table_pass().expect("failed to generate symbol table");
-            let (symbol_table, struct_graph, call_graph) =
+            let (mut symbol_table, mut struct_graph, mut call_graph) =
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
+            compiler
+                .pruning_pass(&mut symbol_table, &mut struct_graph, &mut call_graph)
+                .expect("failed to run pruning pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattening pass");
             compiler.destructuring_pass().expect("failed to run destructuring pass");
             compiler.function_inlining_pass(&call_graph).expect("failed to run function inlining pass");
+            compiler.common_subexpression_elimination_pass(&symbol_table).expect("failed to run cse pass");
             compiler.dead_code_elimination_pass().expect("failed to run dce pass");
             compiler
                 .code_generation_pass(&symbol_table, &struct_graph, &call_graph)
@@ -336,11 +395,13 @@ macro_rules! bench {
 bench!(bench_parse, BenchMode::Parse);
 bench!(bench_symbol, BenchMode::Symbol);
 bench!(bench_type, BenchMode::Type);
+bench!(bench_prune, BenchMode::Prune);
 bench!(bench_unroll, BenchMode::Unroll);
 bench!(bench_ssa, BenchMode::Ssa);
 bench!(bench_flatten, BenchMode::Flatten);
 bench!(bench_destructure, BenchMode::Destructure);
 bench!(bench_inline, BenchMode::Inline);
+bench!(bench_cse, BenchMode::Cse);
 bench!(bench_dce, BenchMode::Dce);
 bench!(bench_codegen, BenchMode::Codegen);
 bench!(bench_full, BenchMode::Full);
@@ -352,11 +413,13 @@ criterion_group!(
         bench_parse,
         bench_symbol,
         bench_type,
+        bench_prune,
         bench_unroll,
         bench_ssa,
         bench_flatten,
         bench_destructure,
         bench_inline,
+        bench_cse,
         bench_dce,
         bench_codegen,
         bench_full